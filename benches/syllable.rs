@@ -0,0 +1,28 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use jp_inflections::{syllable::Syllable, PoliteLevel, Verb, VerbType, Word};
+
+fn get_info(c: &mut Criterion) {
+    let syllables: Vec<Syllable> = "あわをんカヲ".chars().map(Syllable::from).collect();
+
+    c.bench_function("get_info", |b| {
+        b.iter(|| {
+            for syllable in &syllables {
+                black_box(black_box(syllable).get_info());
+            }
+        })
+    });
+}
+
+fn conjugate(c: &mut Criterion) {
+    let verb = Verb::new(Word::new("ならう", Some("習う")), VerbType::Godan);
+
+    c.bench_function("conjugate", |b| {
+        b.iter(|| {
+            black_box(black_box(&verb).negative(PoliteLevel::Polite)).unwrap();
+            black_box(black_box(&verb).te_form()).unwrap();
+        })
+    });
+}
+
+criterion_group!(benches, get_info, conjugate);
+criterion_main!(benches);
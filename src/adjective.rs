@@ -0,0 +1,320 @@
+use crate::{
+    attributive::Attributive, copula::Copula, error::Error, inflection::Inflection, JapaneseResult,
+    PoliteLevel, Word,
+};
+use std::ops::Deref;
+
+/// Represents a Japanese adjective
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Adjective {
+    pub word: Word,
+    pub adjective_type: AdjectiveType,
+}
+
+impl Deref for Adjective {
+    type Target = Word;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.word
+    }
+}
+
+/// Represents a type of adjective
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AdjectiveType {
+    /// 高い,早い,...
+    I,
+    /// 静か,綺麗,...
+    Na,
+}
+
+impl Adjective {
+    /// Returns a new adjective
+    #[inline]
+    pub fn new(word: Word, adjective_type: AdjectiveType) -> Self {
+        Self {
+            word,
+            adjective_type,
+        }
+    }
+
+    /// Returns the adjective as a plain predicate. Na-adjectives take the copula だ
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, AdjectiveType};
+    ///
+    /// let adjective = Word::new("しずか", Some("静か")).into_adjective(AdjectiveType::Na).unwrap();
+    /// assert_eq!(adjective.predicate().unwrap().kanji.unwrap(), String::from("静かだ"));
+    ///
+    /// let adjective = Word::new("たかい", Some("高い")).into_adjective(AdjectiveType::I).unwrap();
+    /// assert_eq!(adjective.predicate().unwrap().kanji.unwrap(), String::from("高い"));
+    /// ```
+    pub fn predicate(&self) -> JapaneseResult<Word> {
+        self.stem()?;
+
+        Ok(match self.adjective_type {
+            AdjectiveType::I => self.word.clone(),
+            AdjectiveType::Na => self.copula().present(PoliteLevel::Plain),
+        })
+    }
+
+    /// Returns the attributive form of the adjective, used in front of nouns. Na-adjectives take
+    /// な, i-adjectives stay unchanged
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, AdjectiveType};
+    ///
+    /// let adjective = Word::new("しずか", Some("静か")).into_adjective(AdjectiveType::Na).unwrap();
+    /// assert_eq!(adjective.attributive().unwrap().kana, String::from("しずかな"));
+    /// assert_eq!(adjective.attributive().unwrap().kanji.unwrap(), String::from("静かな"));
+    /// ```
+    pub fn attributive(&self) -> JapaneseResult<Word> {
+        self.stem()?;
+
+        let mut word = self.word.clone();
+        if self.adjective_type == AdjectiveType::Na {
+            word.push('な');
+        }
+        Ok(word)
+    }
+
+    /// Returns the negative form of the adjective. Na-adjectives use じゃない instead of ではない
+    /// if `casual` is `true`, i-adjectives ignore it
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, AdjectiveType};
+    ///
+    /// let adjective = Word::new("たかい", Some("高い")).into_adjective(AdjectiveType::I).unwrap();
+    /// assert_eq!(adjective.negative(false).unwrap().kana, String::from("たかくない"));
+    /// assert_eq!(adjective.negative(false).unwrap().kanji.unwrap(), String::from("高くない"));
+    ///
+    /// let adjective = Word::new("しずか", Some("静か")).into_adjective(AdjectiveType::Na).unwrap();
+    /// assert_eq!(adjective.negative(false).unwrap().kanji.unwrap(), String::from("静かではない"));
+    /// assert_eq!(adjective.negative(true).unwrap().kanji.unwrap(), String::from("静かじゃない"));
+    /// ```
+    pub fn negative(&self, casual: bool) -> JapaneseResult<Word> {
+        let negative = match self.adjective_type {
+            AdjectiveType::I => self.stem()?.push_str("くない").to_owned(),
+            AdjectiveType::Na => self.copula().negative(PoliteLevel::Plain, casual),
+        };
+        Ok(self.inflect(negative, &[Inflection::Negative]))
+    }
+
+    /// Returns the past form of the adjective
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, AdjectiveType};
+    ///
+    /// let adjective = Word::new("たかい", Some("高い")).into_adjective(AdjectiveType::I).unwrap();
+    /// assert_eq!(adjective.past().unwrap().kana, String::from("たかかった"));
+    /// assert_eq!(adjective.past().unwrap().kanji.unwrap(), String::from("高かった"));
+    ///
+    /// let adjective = Word::new("しずか", Some("静か")).into_adjective(AdjectiveType::Na).unwrap();
+    /// assert_eq!(adjective.past().unwrap().kanji.unwrap(), String::from("静かだった"));
+    /// ```
+    pub fn past(&self) -> JapaneseResult<Word> {
+        let past = match self.adjective_type {
+            AdjectiveType::I => self.stem()?.push_str("かった").to_owned(),
+            AdjectiveType::Na => self.copula().past(PoliteLevel::Plain),
+        };
+        Ok(self.inflect(past, &[Inflection::Past]))
+    }
+
+    /// Returns the negative past form of the adjective. Na-adjectives use じゃなかった instead of
+    /// ではなかった if `casual` is `true`, i-adjectives ignore it
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, AdjectiveType};
+    ///
+    /// let adjective = Word::new("たかい", Some("高い")).into_adjective(AdjectiveType::I).unwrap();
+    /// assert_eq!(adjective.negative_past(false).unwrap().kana, String::from("たかくなかった"));
+    /// assert_eq!(adjective.negative_past(false).unwrap().kanji.unwrap(), String::from("高くなかった"));
+    ///
+    /// let adjective = Word::new("しずか", Some("静か")).into_adjective(AdjectiveType::Na).unwrap();
+    /// assert_eq!(adjective.negative_past(true).unwrap().kanji.unwrap(), String::from("静かじゃなかった"));
+    /// ```
+    pub fn negative_past(&self, casual: bool) -> JapaneseResult<Word> {
+        let mut negative = self.negative(casual)?.strip_end(1);
+        negative.push_str("かった");
+        Ok(self.inflect(negative, &[Inflection::Negative, Inflection::Past]))
+    }
+
+    /// Returns the adjective in its て form
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, AdjectiveType};
+    ///
+    /// let adjective = Word::new("たかい", Some("高い")).into_adjective(AdjectiveType::I).unwrap();
+    /// assert_eq!(adjective.te().unwrap().kana, String::from("たかくて"));
+    /// assert_eq!(adjective.te().unwrap().kanji.unwrap(), String::from("高くて"));
+    ///
+    /// let adjective = Word::new("しずか", Some("静か")).into_adjective(AdjectiveType::Na).unwrap();
+    /// assert_eq!(adjective.te().unwrap().kanji.unwrap(), String::from("静かで"));
+    /// ```
+    pub fn te(&self) -> JapaneseResult<Word> {
+        let te = match self.adjective_type {
+            AdjectiveType::I => self.stem()?.push_str("くて").to_owned(),
+            AdjectiveType::Na => self.copula().te(),
+        };
+        Ok(self.inflect(te, &[Inflection::Te]))
+    }
+
+    /// Returns the adverbial form of the adjective. I-adjectives replace their い with く,
+    /// na-adjectives take に. Returns [`Error::NotAnAdjective`] for i-adjectives not ending in い
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, AdjectiveType};
+    ///
+    /// let adjective = Word::new("はやい", Some("速い")).into_adjective(AdjectiveType::I).unwrap();
+    /// assert_eq!(adjective.adverb().unwrap().kana, String::from("はやく"));
+    /// assert_eq!(adjective.adverb().unwrap().kanji.unwrap(), String::from("速く"));
+    ///
+    /// let adjective = Word::new("しずか", Some("静か")).into_adjective(AdjectiveType::Na).unwrap();
+    /// assert_eq!(adjective.adverb().unwrap().kanji.unwrap(), String::from("静かに"));
+    /// ```
+    pub fn adverb(&self) -> JapaneseResult<Word> {
+        let mut stem = self.stem()?;
+        match self.adjective_type {
+            AdjectiveType::I => stem.push('く'),
+            AdjectiveType::Na => stem.push('に'),
+        };
+        Ok(stem)
+    }
+
+    /// Returns the adjective in the ba form. Na-adjectives use なら
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, AdjectiveType};
+    ///
+    /// let adjective = Word::new("たかい", Some("高い")).into_adjective(AdjectiveType::I).unwrap();
+    /// assert_eq!(adjective.ba().unwrap().kana, String::from("たかければ"));
+    /// assert_eq!(adjective.ba().unwrap().kanji.unwrap(), String::from("高ければ"));
+    ///
+    /// let adjective = Word::new("しずか", Some("静か")).into_adjective(AdjectiveType::Na).unwrap();
+    /// assert_eq!(adjective.ba().unwrap().kanji.unwrap(), String::from("静かなら"));
+    /// ```
+    pub fn ba(&self) -> JapaneseResult<Word> {
+        let ba = match self.adjective_type {
+            AdjectiveType::I => self.stem()?.push_str("ければ").to_owned(),
+            AdjectiveType::Na => self.copula().conditional(),
+        };
+        Ok(self.inflect(ba, &[Inflection::Ba]))
+    }
+
+    /// Returns the adjective in the すぎる form ("too much")
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, AdjectiveType, PoliteLevel};
+    ///
+    /// let adjective = Word::new("たかい", Some("高い")).into_adjective(AdjectiveType::I).unwrap();
+    /// assert_eq!(adjective.sugiru(PoliteLevel::Plain).unwrap().kana, String::from("たかすぎる"));
+    /// assert_eq!(adjective.sugiru(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("高すぎます"));
+    ///
+    /// let adjective = Word::new("しずか", Some("静か")).into_adjective(AdjectiveType::Na).unwrap();
+    /// assert_eq!(adjective.sugiru(PoliteLevel::Plain).unwrap().kanji.unwrap(), String::from("静かすぎる"));
+    /// ```
+    pub fn sugiru(&self, form: PoliteLevel) -> JapaneseResult<Word> {
+        let mut stem = self.stem()?;
+        stem.push_sugiru(form);
+        Ok(stem)
+    }
+
+    /// Returns the さ nominalization of the adjective, expressing its degree
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, AdjectiveType};
+    ///
+    /// let adjective = Word::new("たかい", Some("高い")).into_adjective(AdjectiveType::I).unwrap();
+    /// assert_eq!(adjective.noun_sa().unwrap().kana, String::from("たかさ"));
+    /// assert_eq!(adjective.noun_sa().unwrap().kanji.unwrap(), String::from("高さ"));
+    ///
+    /// let adjective = Word::new("しずか", Some("静か")).into_adjective(AdjectiveType::Na).unwrap();
+    /// assert_eq!(adjective.noun_sa().unwrap().kanji.unwrap(), String::from("静かさ"));
+    /// ```
+    pub fn noun_sa(&self) -> JapaneseResult<Word> {
+        let mut stem = self.stem()?;
+        stem.push('さ');
+        Ok(stem)
+    }
+
+    /// Returns the adjective without its い. いい (and compounds like かっこいい) use the stem
+    /// of よい instead. Na-adjectives are returned unchanged
+    fn stem(&self) -> JapaneseResult<Word> {
+        if self.adjective_type == AdjectiveType::Na {
+            return Ok(self.word.clone());
+        }
+
+        if !self.word.kana.ends_with('い') {
+            return Err(Error::NotAnAdjective);
+        }
+
+        if !self.is_ii() {
+            return Ok(self.word.clone().strip_end(1));
+        }
+
+        let kanji = self.word.kanji.as_ref().map(|kanji| {
+            let mut kanji = kanji.clone();
+            // 良い keeps its kanji, only いい written in kana gets replaced
+            if kanji.ends_with("いい") {
+                kanji.truncate(kanji.len() - "いい".len());
+                kanji.push('よ');
+            } else {
+                kanji.pop();
+            }
+            kanji
+        });
+
+        let mut kana = self.word.kana.clone();
+        kana.truncate(kana.len() - "いい".len());
+        kana.push('よ');
+
+        Ok(Word {
+            kana,
+            kanji,
+            inflections: self.word.inflections.clone(),
+        })
+    }
+
+    /// Returns `true` if the adjective is いい or a compound of it
+    fn is_ii(&self) -> bool {
+        let kana = self.word.kana.as_str();
+        let kanji = self.word.kanji.as_deref().unwrap_or_default();
+
+        kana == "いい"
+            || kana.ends_with("かっこいい")
+            || (kana.ends_with("いい") && (kanji.ends_with("良い") || kanji.ends_with("好い")))
+    }
+
+    /// Returns the adjective followed by the copula. Used to conjugate na-adjectives
+    fn copula(&self) -> Copula {
+        Copula::new(self.word.clone())
+    }
+
+    /// Returns `word` with its inflections set to the ones of the adjective followed by
+    /// `inflections`
+    fn inflect(&self, mut word: Word, inflections: &[Inflection]) -> Word {
+        word.inflections = self.word.inflections.clone();
+        word.inflections.extend_from_slice(inflections);
+        word
+    }
+}
+
+impl Attributive for Adjective {
+    #[inline]
+    fn attributive(&self) -> JapaneseResult<Word> {
+        Adjective::attributive(self)
+    }
+}
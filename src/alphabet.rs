@@ -1,6 +1,6 @@
 use crate::{syllable::Row, umlaut::Umlaut};
 
-/// All (single) hiragana syllables
+/// All (single) hiragana syllables, including the small っ, ゃ, ゅ and ょ
 pub const HIRAGANA_SYLLABLES: &[(Row, &[(char, Umlaut)])] = &[
     (
         Row::Umlauts,
@@ -60,6 +60,7 @@ pub const HIRAGANA_SYLLABLES: &[(Row, &[(char, Umlaut)])] = &[
             ('ち', Umlaut::I),
             ('と', Umlaut::O),
             ('つ', Umlaut::U),
+            ('っ', Umlaut::U),
         ],
     ),
     (
@@ -134,7 +135,161 @@ pub const HIRAGANA_SYLLABLES: &[(Row, &[(char, Umlaut)])] = &[
     ),
     (
         Row::Y,
-        &[('や', Umlaut::A), ('よ', Umlaut::O), ('ゆ', Umlaut::U)],
+        &[
+            ('や', Umlaut::A),
+            ('よ', Umlaut::O),
+            ('ゆ', Umlaut::U),
+            ('ゃ', Umlaut::A),
+            ('ょ', Umlaut::O),
+            ('ゅ', Umlaut::U),
+        ],
+    ),
+    (Row::W, &[('わ', Umlaut::A), ('を', Umlaut::O)]),
+];
+
+/// All (single) katakana syllables, including the small ッ, ャ, ュ and ョ
+pub const KATAKANA_SYLLABLES: &[(Row, &[(char, Umlaut)])] = &[
+    (
+        Row::Umlauts,
+        &[
+            ('ア', Umlaut::A),
+            ('エ', Umlaut::E),
+            ('イ', Umlaut::I),
+            ('オ', Umlaut::O),
+            ('ウ', Umlaut::U),
+        ],
+    ),
+    (
+        Row::K,
+        &[
+            ('カ', Umlaut::A),
+            ('ケ', Umlaut::E),
+            ('キ', Umlaut::I),
+            ('コ', Umlaut::O),
+            ('ク', Umlaut::U),
+        ],
+    ),
+    (
+        Row::G,
+        &[
+            ('ガ', Umlaut::A),
+            ('ゲ', Umlaut::E),
+            ('ギ', Umlaut::I),
+            ('ゴ', Umlaut::O),
+            ('グ', Umlaut::U),
+        ],
+    ),
+    (
+        Row::S,
+        &[
+            ('サ', Umlaut::A),
+            ('セ', Umlaut::E),
+            ('シ', Umlaut::I),
+            ('ソ', Umlaut::O),
+            ('ス', Umlaut::U),
+        ],
+    ),
+    (
+        Row::Z,
+        &[
+            ('ザ', Umlaut::A),
+            ('ゼ', Umlaut::E),
+            ('ジ', Umlaut::I),
+            ('ゾ', Umlaut::O),
+            ('ズ', Umlaut::U),
+        ],
+    ),
+    (
+        Row::T,
+        &[
+            ('タ', Umlaut::A),
+            ('テ', Umlaut::E),
+            ('チ', Umlaut::I),
+            ('ト', Umlaut::O),
+            ('ツ', Umlaut::U),
+            ('ッ', Umlaut::U),
+        ],
+    ),
+    (
+        Row::D,
+        &[
+            ('ダ', Umlaut::A),
+            ('デ', Umlaut::E),
+            ('ヂ', Umlaut::I),
+            ('ド', Umlaut::O),
+            ('ヅ', Umlaut::U),
+        ],
+    ),
+    (
+        Row::N,
+        &[
+            ('ナ', Umlaut::A),
+            ('ネ', Umlaut::E),
+            ('ニ', Umlaut::I),
+            ('ノ', Umlaut::O),
+            ('ヌ', Umlaut::U),
+        ],
+    ),
+    (
+        Row::H,
+        &[
+            ('ハ', Umlaut::A),
+            ('ヘ', Umlaut::E),
+            ('ヒ', Umlaut::I),
+            ('ホ', Umlaut::O),
+            ('フ', Umlaut::U),
+        ],
+    ),
+    (
+        Row::B,
+        &[
+            ('バ', Umlaut::A),
+            ('ベ', Umlaut::E),
+            ('ビ', Umlaut::I),
+            ('ボ', Umlaut::O),
+            ('ブ', Umlaut::U),
+        ],
+    ),
+    (
+        Row::P,
+        &[
+            ('パ', Umlaut::A),
+            ('ペ', Umlaut::E),
+            ('ピ', Umlaut::I),
+            ('ポ', Umlaut::O),
+            ('プ', Umlaut::U),
+        ],
+    ),
+    (
+        Row::M,
+        &[
+            ('マ', Umlaut::A),
+            ('メ', Umlaut::E),
+            ('ミ', Umlaut::I),
+            ('モ', Umlaut::O),
+            ('ム', Umlaut::U),
+        ],
+    ),
+    (
+        Row::R,
+        &[
+            ('ラ', Umlaut::A),
+            ('レ', Umlaut::E),
+            ('リ', Umlaut::I),
+            ('ロ', Umlaut::O),
+            ('ル', Umlaut::U),
+        ],
+    ),
+    (
+        Row::Y,
+        &[
+            ('ヤ', Umlaut::A),
+            ('ヨ', Umlaut::O),
+            ('ユ', Umlaut::U),
+            ('ャ', Umlaut::A),
+            ('ョ', Umlaut::O),
+            ('ュ', Umlaut::U),
+        ],
     ),
-    (Row::W, &[('わ', Umlaut::A), ('わ', Umlaut::O)]),
+    (Row::W, &[('ワ', Umlaut::A), ('ヲ', Umlaut::O)]),
 ];
@@ -0,0 +1,23 @@
+use crate::{JapaneseResult, Word};
+
+/// Words which can modify a noun (連体形): verbs (習う人), adjectives (静かな部屋) and nouns
+/// followed by the copula (学生の本)
+///
+/// # Example
+/// ```
+/// use jp_inflections::{attributive::Attributive, AdjectiveType, VerbType, Word};
+///
+/// fn modify<A: Attributive>(modifier: &A, noun: &str) -> String {
+///     format!("{}{}", modifier.attributive().unwrap(), noun)
+/// }
+///
+/// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
+/// assert_eq!(modify(&verb, "人"), "習う人");
+///
+/// let adjective = Word::new("しずか", Some("静か")).into_adjective(AdjectiveType::Na).unwrap();
+/// assert_eq!(modify(&adjective, "部屋"), "静かな部屋");
+/// ```
+pub trait Attributive {
+    /// Returns the attributive form of the word
+    fn attributive(&self) -> JapaneseResult<Word>;
+}
@@ -0,0 +1,64 @@
+use crate::{error::Error, JapaneseResult, Verb, VerbType, Word};
+
+/// Builder for a [`Verb`]. The kanji reading defaults to `None` and the [`VerbType`] gets guessed
+/// by [`Word::detect_verb_type`] if not set
+///
+/// # Example
+/// ```
+/// use jp_inflections::{VerbBuilder, VerbType};
+///
+/// let verb = VerbBuilder::new().kana("たべる").kanji("食べる").build().unwrap();
+/// assert_eq!(verb.verb_type(), VerbType::Ichidan);
+/// assert_eq!(verb.word.kanji.as_deref(), Some("食べる"));
+///
+/// let verb = VerbBuilder::new().kana("かえる").verb_type(VerbType::Godan).build().unwrap();
+/// assert_eq!(verb.word.kanji, None);
+///
+/// assert!(VerbBuilder::new().kana("えいご").build().is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VerbBuilder {
+    kana: Option<String>,
+    kanji: Option<String>,
+    verb_type: Option<VerbType>,
+}
+
+impl VerbBuilder {
+    /// Returns a new empty builder
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the kana reading of the verb
+    #[inline]
+    pub fn kana<S: AsRef<str>>(mut self, kana: S) -> Self {
+        self.kana = Some(kana.as_ref().to_owned());
+        self
+    }
+
+    /// Sets the kanji reading of the verb
+    #[inline]
+    pub fn kanji<S: AsRef<str>>(mut self, kanji: S) -> Self {
+        self.kanji = Some(kanji.as_ref().to_owned());
+        self
+    }
+
+    /// Sets the type of the verb instead of guessing it
+    #[inline]
+    pub fn verb_type(mut self, verb_type: VerbType) -> Self {
+        self.verb_type = Some(verb_type);
+        self
+    }
+
+    /// Builds the verb. Returns `Error::NotAVerb` if no kana reading was set or the word isn't a
+    /// verb in the dictionary form
+    pub fn build(self) -> JapaneseResult<Verb> {
+        let word = Word::new(self.kana.ok_or(Error::NotAVerb)?, self.kanji);
+
+        match self.verb_type {
+            Some(verb_type) => word.into_verb(verb_type),
+            None => word.into_verb_auto(),
+        }
+    }
+}
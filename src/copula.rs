@@ -0,0 +1,185 @@
+use crate::{attributive::Attributive, inflection::Inflection, JapaneseResult, PoliteLevel, Word};
+use std::ops::Deref;
+
+/// A noun (or na-adjective) followed by the copula だ
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Copula {
+    pub word: Word,
+}
+
+impl Deref for Copula {
+    type Target = Word;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.word
+    }
+}
+
+impl Copula {
+    /// Returns a new copula predicate for `word`
+    #[inline]
+    pub fn new(word: Word) -> Self {
+        Self { word }
+    }
+
+    /// Returns the predicate in the present form
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, PoliteLevel};
+    ///
+    /// let copula = Word::new("がくせい", Some("学生")).with_copula();
+    /// assert_eq!(copula.present(PoliteLevel::Plain).kanji.unwrap(), String::from("学生だ"));
+    /// assert_eq!(copula.present(PoliteLevel::Polite).kanji.unwrap(), String::from("学生です"));
+    /// ```
+    pub fn present(&self, form: PoliteLevel) -> Word {
+        match form {
+            PoliteLevel::Plain => self.with_suffix("だ", &[]),
+            PoliteLevel::Polite => self.with_suffix("です", &[Inflection::Polite]),
+        }
+    }
+
+    /// Returns the predicate in the past form
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, PoliteLevel};
+    ///
+    /// let copula = Word::new("がくせい", Some("学生")).with_copula();
+    /// assert_eq!(copula.past(PoliteLevel::Plain).kanji.unwrap(), String::from("学生だった"));
+    /// assert_eq!(copula.past(PoliteLevel::Polite).kanji.unwrap(), String::from("学生でした"));
+    /// ```
+    pub fn past(&self, form: PoliteLevel) -> Word {
+        match form {
+            PoliteLevel::Plain => self.with_suffix("だった", &[Inflection::Past]),
+            PoliteLevel::Polite => {
+                self.with_suffix("でした", &[Inflection::Polite, Inflection::Past])
+            }
+        }
+    }
+
+    /// Returns the predicate in the negative form. Uses じゃ instead of では if `casual` is `true`
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, PoliteLevel};
+    ///
+    /// let copula = Word::new("がくせい", Some("学生")).with_copula();
+    /// assert_eq!(copula.negative(PoliteLevel::Plain, false).kanji.unwrap(), String::from("学生ではない"));
+    /// assert_eq!(copula.negative(PoliteLevel::Plain, true).kanji.unwrap(), String::from("学生じゃない"));
+    /// assert_eq!(copula.negative(PoliteLevel::Polite, false).kanji.unwrap(), String::from("学生ではありません"));
+    /// ```
+    pub fn negative(&self, form: PoliteLevel, casual: bool) -> Word {
+        let particle = if casual { "じゃ" } else { "では" };
+
+        match form {
+            PoliteLevel::Plain => {
+                self.with_suffix(&format!("{particle}ない"), &[Inflection::Negative])
+            }
+            PoliteLevel::Polite => self.with_suffix(
+                &format!("{particle}ありません"),
+                &[Inflection::Polite, Inflection::Negative],
+            ),
+        }
+    }
+
+    /// Returns the predicate in the negative past form. Uses じゃ instead of では if `casual` is
+    /// `true`
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, PoliteLevel};
+    ///
+    /// let copula = Word::new("がくせい", Some("学生")).with_copula();
+    /// assert_eq!(copula.negative_past(PoliteLevel::Plain, false).kanji.unwrap(), String::from("学生ではなかった"));
+    /// assert_eq!(copula.negative_past(PoliteLevel::Polite, true).kanji.unwrap(), String::from("学生じゃありませんでした"));
+    /// ```
+    pub fn negative_past(&self, form: PoliteLevel, casual: bool) -> Word {
+        let particle = if casual { "じゃ" } else { "では" };
+
+        match form {
+            PoliteLevel::Plain => self.with_suffix(
+                &format!("{particle}なかった"),
+                &[Inflection::Negative, Inflection::Past],
+            ),
+            PoliteLevel::Polite => self.with_suffix(
+                &format!("{particle}ありませんでした"),
+                &[Inflection::Polite, Inflection::Negative, Inflection::Past],
+            ),
+        }
+    }
+
+    /// Returns the predicate in the presumptive form
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, PoliteLevel};
+    ///
+    /// let copula = Word::new("がくせい", Some("学生")).with_copula();
+    /// assert_eq!(copula.presumptive(PoliteLevel::Plain).kanji.unwrap(), String::from("学生だろう"));
+    /// assert_eq!(copula.presumptive(PoliteLevel::Polite).kanji.unwrap(), String::from("学生でしょう"));
+    /// ```
+    pub fn presumptive(&self, form: PoliteLevel) -> Word {
+        match form {
+            PoliteLevel::Plain => self.with_suffix("だろう", &[]),
+            PoliteLevel::Polite => self.with_suffix("でしょう", &[Inflection::Polite]),
+        }
+    }
+
+    /// Returns the predicate in its て form
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::Word;
+    ///
+    /// let copula = Word::new("がくせい", Some("学生")).with_copula();
+    /// assert_eq!(copula.te().kanji.unwrap(), String::from("学生で"));
+    /// ```
+    pub fn te(&self) -> Word {
+        self.with_suffix("で", &[Inflection::Te])
+    }
+
+    /// Returns the predicate in the なら conditional form
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::Word;
+    ///
+    /// let copula = Word::new("がくせい", Some("学生")).with_copula();
+    /// assert_eq!(copula.conditional().kanji.unwrap(), String::from("学生なら"));
+    /// ```
+    pub fn conditional(&self) -> Word {
+        self.with_suffix("なら", &[])
+    }
+
+    /// Returns the attributive form used in front of nouns, which takes の instead of だ (学生の本).
+    /// Use [`Adjective::attributive`](crate::Adjective::attributive) for the な of na-adjectives
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::Word;
+    ///
+    /// let copula = Word::new("がくせい", Some("学生")).with_copula();
+    /// assert_eq!(copula.attributive().kanji.unwrap(), String::from("学生の"));
+    /// ```
+    pub fn attributive(&self) -> Word {
+        self.with_suffix("の", &[])
+    }
+
+    /// Returns the word with `suffix` appended and `inflections` added to its inflections
+    fn with_suffix(&self, suffix: &str, inflections: &[Inflection]) -> Word {
+        let mut word = self.word.clone();
+        word.push_str(suffix);
+        word.inflections.extend_from_slice(inflections);
+        word
+    }
+}
+
+impl Attributive for Copula {
+    #[inline]
+    fn attributive(&self) -> JapaneseResult<Word> {
+        Ok(Copula::attributive(self))
+    }
+}
@@ -0,0 +1,394 @@
+use std::{collections::HashSet, sync::OnceLock};
+
+use crate::{inflection::Inflection, verb::VerbType, Word};
+
+/// A possible dictionary form of an inflected word
+#[derive(Debug, Clone, PartialEq)]
+pub struct Deinflection {
+    /// The candidate dictionary form
+    pub word: String,
+    pub verb_type: VerbType,
+    /// The inflections that were removed, starting with the one closest to the stem
+    pub inflections: Vec<Inflection>,
+}
+
+/// A word in dictionary form of an ichidan verb
+const V1: u8 = 1;
+/// A word in dictionary form of a godan verb
+const V5: u8 = 1 << 1;
+/// A word in dictionary form of a する verb
+const VS: u8 = 1 << 2;
+/// A word in dictionary form of a 来る verb
+const VK: u8 = 1 << 3;
+/// A word ending in the い-adjective-like ない/たい
+const ADJ_I: u8 = 1 << 4;
+/// A word ending in ます
+const MASU: u8 = 1 << 5;
+/// A form which can't be inflected any further
+const TERMINAL: u8 = 1 << 6;
+
+const ANY: u8 = V1 | V5 | VS | VK | ADJ_I | MASU | TERMINAL;
+const VERB: u8 = V1 | V5 | VS | VK;
+
+/// Inflections changing the voice of a verb, each of them can appear only once in a chain
+const VOICES: &[Inflection] = &[
+    Inflection::Passive,
+    Inflection::Potential,
+    Inflection::Causative,
+    Inflection::CausativePassive,
+];
+
+/// The godan endings in the order う, あ, い, え, お
+const GODAN_ROWS: &[[char; 5]] = &[
+    ['う', 'わ', 'い', 'え', 'お'],
+    ['く', 'か', 'き', 'け', 'こ'],
+    ['ぐ', 'が', 'ぎ', 'げ', 'ご'],
+    ['す', 'さ', 'し', 'せ', 'そ'],
+    ['つ', 'た', 'ち', 'て', 'と'],
+    ['ぬ', 'な', 'に', 'ね', 'の'],
+    ['ぶ', 'ば', 'び', 'べ', 'ぼ'],
+    ['む', 'ま', 'み', 'め', 'も'],
+    ['る', 'ら', 'り', 'れ', 'ろ'],
+];
+
+/// Godan endings and the sound they change to in the て and た forms
+const GODAN_ONBIN: &[(char, &str)] = &[
+    ('う', "っ"),
+    ('つ', "っ"),
+    ('る', "っ"),
+    ('く', "い"),
+    ('ぐ', "い"),
+    ('す', "し"),
+    ('ぬ', "ん"),
+    ('ぶ', "ん"),
+    ('む', "ん"),
+];
+
+/// The column of the godan row a suffix attaches to
+#[derive(Clone, Copy)]
+enum Column {
+    A = 1,
+    I = 2,
+    E = 3,
+    O = 4,
+}
+
+/// Replaces the suffix `from` of a word of kind `kind_in` with `to`, turning it into a word of
+/// kind `kind_out`
+#[derive(Debug, Clone)]
+pub(crate) struct Rule {
+    from: String,
+    to: String,
+    kind_in: u8,
+    kind_out: u8,
+    pub(crate) inflections: &'static [Inflection],
+}
+
+impl Rule {
+    fn new(from: impl Into<String>, to: &str, kind_in: u8, kind_out: u8) -> Self {
+        Self {
+            from: from.into(),
+            to: to.to_owned(),
+            kind_in,
+            kind_out,
+            inflections: &[],
+        }
+    }
+
+    /// Applies the rule to `word` of kind `kind` or returns `None` if it doesn't match
+    pub(crate) fn apply(&self, word: &str, kind: u8) -> Option<String> {
+        if kind & self.kind_in == 0 {
+            return None;
+        }
+
+        let prefix = word.strip_suffix(self.from.as_str())?;
+
+        // Only rules replacing a whole word (する, 来る, 行く) may consume all of it
+        if prefix.is_empty() && self.to.chars().count() < 2 {
+            return None;
+        }
+
+        // An ichidan stem never ends in a sound changed godan ending like いっ or よん
+        if self.kind_out == V1 && prefix.ends_with(['っ', 'ん']) {
+            return None;
+        }
+
+        Some(format!("{}{}", prefix, self.to))
+    }
+}
+
+/// Collects the rules for one verb form
+struct RuleSet<'a> {
+    rules: &'a mut Vec<Rule>,
+    kind_in: u8,
+    inflections: &'static [Inflection],
+}
+
+impl<'a> RuleSet<'a> {
+    fn new(rules: &'a mut Vec<Rule>, kind_in: u8, inflections: &'static [Inflection]) -> Self {
+        Self {
+            rules,
+            kind_in,
+            inflections,
+        }
+    }
+
+    fn push(&mut self, from: impl Into<String>, to: &str, kind_out: u8) -> &mut Self {
+        let mut rule = Rule::new(from, to, self.kind_in, kind_out);
+        rule.inflections = self.inflections;
+        self.rules.push(rule);
+        self
+    }
+
+    /// Adds the rules for a suffix attached to a regular verb stem
+    fn stem(&mut self, ichidan: &str, column: Column, godan: &str) -> &mut Self {
+        self.push(ichidan, "る", V1);
+        for row in GODAN_ROWS {
+            self.push(
+                format!("{}{}", row[column as usize], godan),
+                &row[0].to_string(),
+                V5,
+            );
+        }
+        self
+    }
+
+    /// Adds the rules for the irregular verbs する and 来る
+    fn irregular(&mut self, suru: &str, kuru: &str) -> &mut Self {
+        self.push(suru, "する", VS);
+        self.push(kuru, "くる", VK);
+        self.push(kuru.replacen(|_: char| true, "来", 1), "来る", VK)
+    }
+
+    /// Adds the rules for the forms built on the godan sound change (た, て, たら)
+    fn onbin(&mut self, unvoiced: &str, voiced: &str) -> &mut Self {
+        self.push(unvoiced, "る", V1);
+        for (ending, onbin) in GODAN_ONBIN {
+            let suffix = match ending {
+                'ぐ' | 'ぬ' | 'ぶ' | 'む' => voiced,
+                _ => unvoiced,
+            };
+            self.push(format!("{}{}", onbin, suffix), &ending.to_string(), V5);
+        }
+        self.push(format!("いっ{}", unvoiced), "いく", V5);
+        self.push(format!("行っ{}", unvoiced), "行く", V5);
+        self.irregular(&format!("し{}", unvoiced), &format!("き{}", unvoiced))
+    }
+}
+
+/// Returns all deinflection rules. The rules get built once on first access
+pub(crate) fn rules() -> &'static [Rule] {
+    static RULES: OnceLock<Vec<Rule>> = OnceLock::new();
+
+    RULES.get_or_init(build_rules)
+}
+
+/// Builds all deinflection rules
+fn build_rules() -> Vec<Rule> {
+    let mut rules = Vec::new();
+    let rules_ref = &mut rules;
+
+    // Forms which are ichidan verbs themselves
+    RuleSet::new(rules_ref, V1, &[Inflection::Passive])
+        .stem("られる", Column::A, "れる")
+        .irregular("される", "こられる");
+    RuleSet::new(rules_ref, V1, &[Inflection::Potential])
+        .stem("られる", Column::E, "る")
+        .irregular("できる", "こられる");
+    RuleSet::new(rules_ref, V1, &[Inflection::Causative])
+        .stem("させる", Column::A, "せる")
+        .irregular("させる", "こさせる");
+    RuleSet::new(rules_ref, V1, &[Inflection::CausativePassive])
+        .stem("させられる", Column::A, "される")
+        .irregular("させられる", "こさせられる");
+
+    // Forms which conjugate like い-adjectives
+    RuleSet::new(rules_ref, ADJ_I, &[Inflection::Negative])
+        .stem("ない", Column::A, "ない")
+        .irregular("しない", "こない");
+    RuleSet::new(rules_ref, ADJ_I, &[Inflection::Desiderative])
+        .stem("たい", Column::I, "たい")
+        .irregular("したい", "きたい");
+
+    RuleSet::new(rules_ref, MASU, &[Inflection::Polite])
+        .stem("ます", Column::I, "ます")
+        .irregular("します", "きます");
+
+    // Forms which can't be inflected any further
+    RuleSet::new(rules_ref, TERMINAL, &[Inflection::Past]).onbin("た", "だ");
+    RuleSet::new(rules_ref, TERMINAL, &[Inflection::Te]).onbin("て", "で");
+    RuleSet::new(rules_ref, TERMINAL, &[Inflection::Tara]).onbin("たら", "だら");
+    RuleSet::new(rules_ref, TERMINAL, &[Inflection::Ba])
+        .stem("れば", Column::E, "ば")
+        .irregular("すれば", "くれば");
+    RuleSet::new(rules_ref, TERMINAL, &[Inflection::Volitional])
+        .stem("よう", Column::O, "う")
+        .irregular("しよう", "こよう");
+    RuleSet::new(rules_ref, TERMINAL, &[Inflection::Imperative])
+        .stem("ろ", Column::E, "")
+        .irregular("しろ", "こい");
+
+    // Inflections of the い-adjective-like forms
+    RuleSet::new(rules_ref, TERMINAL, &[Inflection::Past]).push("かった", "い", ADJ_I);
+    RuleSet::new(rules_ref, TERMINAL, &[Inflection::Te]).push("くて", "い", ADJ_I);
+    RuleSet::new(rules_ref, TERMINAL, &[Inflection::Tara]).push("かったら", "い", ADJ_I);
+    RuleSet::new(rules_ref, TERMINAL, &[Inflection::Ba]).push("ければ", "い", ADJ_I);
+
+    // Inflections of ます
+    RuleSet::new(rules_ref, TERMINAL, &[Inflection::Past]).push("ました", "ます", MASU);
+    RuleSet::new(rules_ref, TERMINAL, &[Inflection::Te]).push("まして", "ます", MASU);
+    RuleSet::new(rules_ref, TERMINAL, &[Inflection::Negative]).push("ません", "ます", MASU);
+    RuleSet::new(
+        rules_ref,
+        TERMINAL,
+        &[Inflection::Negative, Inflection::Past],
+    )
+    .push("ませんでした", "ます", MASU);
+    RuleSet::new(rules_ref, TERMINAL, &[Inflection::Volitional]).push("ましょう", "ます", MASU);
+
+    rules
+}
+
+/// Returns all possible dictionary forms of `surface` with the inflections that were removed to
+/// get there. Since a suffix often belongs to multiple inflections this over-generates
+/// candidates, ranking them is up to the caller
+///
+/// # Example
+/// ```
+/// use jp_inflections::{deinflection::deinflect, Inflection, VerbType};
+///
+/// let candidates = deinflect("食べられなかった");
+/// assert!(candidates.iter().any(|i| i.word == "食べる"
+///     && i.verb_type == VerbType::Ichidan
+///     && i.inflections == [Inflection::Potential, Inflection::Negative, Inflection::Past]));
+/// ```
+pub fn deinflect(surface: &str) -> Vec<Deinflection> {
+    let rules = rules();
+
+    let mut result = Vec::new();
+    let mut seen = HashSet::new();
+    let mut queue = vec![(surface.to_owned(), ANY, Vec::new())];
+
+    if let Some(verb_type) = Word::new(surface, None).detect_verb_type() {
+        result.push(Deinflection {
+            word: surface.to_owned(),
+            verb_type,
+            inflections: Vec::new(),
+        });
+    }
+
+    while let Some((word, kind, inflections)) = queue.pop() {
+        for rule in rules {
+            let repeats_voice = rule
+                .inflections
+                .iter()
+                .any(|i| VOICES.contains(i) && inflections.contains(i));
+            if repeats_voice {
+                continue;
+            }
+
+            let new_word = match rule.apply(&word, kind) {
+                Some(new_word) => new_word,
+                None => continue,
+            };
+
+            let mut new_inflections = rule.inflections.to_vec();
+            new_inflections.extend_from_slice(&inflections);
+
+            if !seen.insert((new_word.clone(), rule.kind_out, new_inflections.clone())) {
+                continue;
+            }
+
+            if rule.kind_out & VERB != 0 {
+                result.push(Deinflection {
+                    word: new_word.clone(),
+                    verb_type: verb_type(rule.kind_out),
+                    inflections: new_inflections.clone(),
+                });
+            }
+
+            queue.push((new_word, rule.kind_out, new_inflections));
+        }
+    }
+
+    result
+}
+
+/// Removes the ます of a polite form, returning all possible plain forms
+///
+/// # Example
+/// ```
+/// use jp_inflections::deinflection::strip_masu;
+///
+/// assert!(strip_masu("ならいます").contains(&String::from("ならう")));
+/// ```
+pub fn strip_masu(surface: &str) -> Vec<String> {
+    strip(surface, Inflection::Polite)
+}
+
+/// Removes the ない of a negative form, returning all possible plain forms
+///
+/// # Example
+/// ```
+/// use jp_inflections::deinflection::strip_nai;
+///
+/// assert!(strip_nai("かかない").contains(&String::from("かく")));
+/// ```
+pub fn strip_nai(surface: &str) -> Vec<String> {
+    strip(surface, Inflection::Negative)
+}
+
+/// Removes the た of a past form, returning all possible plain forms
+///
+/// # Example
+/// ```
+/// use jp_inflections::deinflection::strip_ta;
+///
+/// assert!(strip_ta("よんだ").contains(&String::from("よむ")));
+/// assert!(strip_ta("たべなかった").contains(&String::from("たべない")));
+/// ```
+pub fn strip_ta(surface: &str) -> Vec<String> {
+    strip(surface, Inflection::Past)
+}
+
+/// Removes the て of a て form, returning all possible plain forms
+///
+/// # Example
+/// ```
+/// use jp_inflections::deinflection::strip_te;
+///
+/// let bases = strip_te("よんで");
+/// assert!(bases.contains(&String::from("よむ")));
+/// assert!(bases.contains(&String::from("よぶ")));
+/// ```
+pub fn strip_te(surface: &str) -> Vec<String> {
+    strip(surface, Inflection::Te)
+}
+
+/// Applies all rules removing exactly `inflection` from `surface`
+fn strip(surface: &str, inflection: Inflection) -> Vec<String> {
+    let mut result: Vec<String> = Vec::new();
+
+    for rule in rules() {
+        if rule.inflections != [inflection] {
+            continue;
+        }
+
+        if let Some(word) = rule.apply(surface, ANY) {
+            if !result.contains(&word) {
+                result.push(word);
+            }
+        }
+    }
+
+    result
+}
+
+/// Returns the [`VerbType`] of a verb kind
+fn verb_type(kind: u8) -> VerbType {
+    match kind {
+        V1 => VerbType::Ichidan,
+        V5 => VerbType::Godan,
+        _ => VerbType::Exception,
+    }
+}
@@ -1,5 +1,41 @@
+use std::fmt::Display;
+
 #[derive(Debug, PartialEq)]
 pub enum Error {
     NotAVerb,
-    UnexpectedEnding,
+    /// The word isn't an adjective of the given type
+    NotAnAdjective,
+    /// The verb ends in a kana which can't be conjugated
+    UnexpectedEnding {
+        ending: char,
+    },
+    /// The verb can't be conjugated into the requested form
+    UnsupportedForm,
+    /// The string doesn't name a [`VerbType`](crate::VerbType)
+    InvalidVerbType,
+    /// The verb type can't be determined automatically
+    AmbiguousVerbType,
+    /// The string isn't in the 漢字(かな) notation
+    InvalidNotation,
+    /// The ending of the verb doesn't allow the given [`VerbType`](crate::VerbType)
+    IncompatibleVerbType,
 }
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::NotAVerb => write!(f, "word is not a verb in the dictionary form"),
+            Error::NotAnAdjective => write!(f, "word is not an adjective of the given type"),
+            Error::UnexpectedEnding { ending } => {
+                write!(f, "verb ends in '{}' which isn't conjugable", ending)
+            }
+            Error::UnsupportedForm => write!(f, "verb can't be conjugated into this form"),
+            Error::InvalidVerbType => write!(f, "invalid verb type"),
+            Error::AmbiguousVerbType => write!(f, "verb type can't be determined automatically"),
+            Error::InvalidNotation => write!(f, "word is not in the 漢字(かな) notation"),
+            Error::IncompatibleVerbType => write!(f, "verb can't be of the given verb type"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
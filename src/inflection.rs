@@ -1,4 +1,9 @@
-#[derive(Debug, Clone, Copy, PartialEq)]
+use std::fmt::Display;
+
+use crate::{JapaneseResult, PoliteLevel, Verb, Word};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Inflection {
     Stem,
     StemPotential,
@@ -13,4 +18,186 @@ pub enum Inflection {
     Causative,
     CausativePassive,
     Imperative,
+    Potential,
+    Volitional,
+    Tara,
+    Ba,
+    Desiderative,
+    Zu,
+    Progressive,
+    Shimau,
+    Teoku,
+    Honorific,
+    Humble,
+    Presumptive,
+    Explanatory,
+    Excessive,
+    Appearance,
+    Hearsay,
+    Nasai,
+    To,
+    Nara,
+    Attempt,
+    Intention,
+    KansaiNegative,
+    Nu,
+    Zaru,
+    Zuni,
+    Obligation,
+    Prohibition,
+    Permission,
+    PotentialKoto,
+    PotentialUru,
+    Naidesu,
+    Naide,
+    Invitation,
+    Yasui,
+    Nikui,
+    DesiderativeOther,
+    TewaContracted,
+}
+
+impl Inflection {
+    /// Returns a lowercase english label of the inflection which is also used by its [`Display`]
+    /// implementation. Use [`Word::describe_with`](crate::Word::describe_with) to render
+    /// inflections with custom labels
+    ///
+    /// | Inflection          | Label                |
+    /// |---------------------|----------------------|
+    /// | `Stem`              | "stem"               |
+    /// | `StemPotential`     | "potential stem"     |
+    /// | `StemBa`            | "ba stem"            |
+    /// | `Positive`          | "positive"           |
+    /// | `Negative`          | "negative"           |
+    /// | `Past`              | "past"               |
+    /// | `Present`           | "present"            |
+    /// | `Polite`            | "polite"             |
+    /// | `Te`                | "te"                 |
+    /// | `Passive`           | "passive"            |
+    /// | `Causative`         | "causative"          |
+    /// | `CausativePassive`  | "causative passive"  |
+    /// | `Imperative`        | "imperative"         |
+    /// | `Potential`         | "potential"          |
+    /// | `Volitional`        | "volitional"         |
+    /// | `Tara`              | "tara"               |
+    /// | `Ba`                | "ba"                 |
+    /// | `Desiderative`      | "desiderative"       |
+    /// | `Zu`                | "zu"                 |
+    /// | `Progressive`       | "progressive"        |
+    /// | `Shimau`            | "shimau"             |
+    /// | `Teoku`             | "teoku"              |
+    /// | `Honorific`         | "honorific"          |
+    /// | `Humble`            | "humble"             |
+    /// | `Presumptive`       | "presumptive"        |
+    /// | `Explanatory`       | "explanatory"        |
+    /// | `Excessive`         | "excessive"          |
+    /// | `Appearance`        | "appearance"         |
+    /// | `Hearsay`           | "hearsay"            |
+    /// | `Nasai`             | "nasai"              |
+    /// | `To`                | "to"                 |
+    /// | `Nara`              | "nara"               |
+    /// | `Attempt`           | "attempt"            |
+    /// | `Intention`         | "intention"          |
+    /// | `KansaiNegative`    | "kansai negative"    |
+    /// | `Nu`                | "nu"                 |
+    /// | `Zaru`              | "zaru"               |
+    /// | `Zuni`              | "zuni"               |
+    /// | `Obligation`        | "obligation"         |
+    /// | `Prohibition`       | "prohibition"        |
+    /// | `Permission`        | "permission"         |
+    /// | `PotentialKoto`     | "koto potential"     |
+    /// | `PotentialUru`      | "uru potential"      |
+    /// | `Naidesu`           | "naidesu"            |
+    /// | `Naide`             | "naide"              |
+    /// | `Invitation`        | "invitation"         |
+    /// | `Yasui`             | "yasui"              |
+    /// | `Nikui`             | "nikui"              |
+    /// | `DesiderativeOther` | "desiderative other" |
+    /// | `TewaContracted`    | "contracted tewa"    |
+    pub fn label(&self) -> &'static str {
+        match self {
+            Inflection::Stem => "stem",
+            Inflection::StemPotential => "potential stem",
+            Inflection::StemBa => "ba stem",
+            Inflection::Positive => "positive",
+            Inflection::Negative => "negative",
+            Inflection::Past => "past",
+            Inflection::Present => "present",
+            Inflection::Polite => "polite",
+            Inflection::Te => "te",
+            Inflection::Passive => "passive",
+            Inflection::Causative => "causative",
+            Inflection::CausativePassive => "causative passive",
+            Inflection::Imperative => "imperative",
+            Inflection::Potential => "potential",
+            Inflection::Volitional => "volitional",
+            Inflection::Tara => "tara",
+            Inflection::Ba => "ba",
+            Inflection::Desiderative => "desiderative",
+            Inflection::Zu => "zu",
+            Inflection::Progressive => "progressive",
+            Inflection::Shimau => "shimau",
+            Inflection::Teoku => "teoku",
+            Inflection::Honorific => "honorific",
+            Inflection::Humble => "humble",
+            Inflection::Presumptive => "presumptive",
+            Inflection::Explanatory => "explanatory",
+            Inflection::Excessive => "excessive",
+            Inflection::Appearance => "appearance",
+            Inflection::Hearsay => "hearsay",
+            Inflection::Nasai => "nasai",
+            Inflection::To => "to",
+            Inflection::Nara => "nara",
+            Inflection::Attempt => "attempt",
+            Inflection::Intention => "intention",
+            Inflection::KansaiNegative => "kansai negative",
+            Inflection::Nu => "nu",
+            Inflection::Zaru => "zaru",
+            Inflection::Zuni => "zuni",
+            Inflection::Obligation => "obligation",
+            Inflection::Prohibition => "prohibition",
+            Inflection::Permission => "permission",
+            Inflection::PotentialKoto => "koto potential",
+            Inflection::PotentialUru => "uru potential",
+            Inflection::Naidesu => "naidesu",
+            Inflection::Naide => "naide",
+            Inflection::Invitation => "invitation",
+            Inflection::Yasui => "yasui",
+            Inflection::Nikui => "nikui",
+            Inflection::DesiderativeOther => "desiderative other",
+            Inflection::TewaContracted => "contracted tewa",
+        }
+    }
+}
+
+impl Display for Inflection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
+
+/// Conjugates all `verbs` into the given [`Inflection`] using [`Verb::conjugate`]. The results
+/// are in the same order as `verbs` and an error of one verb doesn't affect the others
+///
+/// # Example
+/// ```
+/// use jp_inflections::{inflection, Inflection, PoliteLevel, VerbType, Word};
+///
+/// let verbs = vec![
+///     Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap(),
+///     Word::new("いく", Some("行く")).into_verb(VerbType::Godan).unwrap(),
+/// ];
+/// let past = inflection::conjugate_all(&verbs, Inflection::Past, PoliteLevel::Plain);
+/// assert_eq!(past[0].as_ref().unwrap().kana, String::from("たべた"));
+/// assert_eq!(past[1].as_ref().unwrap().kanji.as_deref(), Some("行った"));
+/// ```
+pub fn conjugate_all(
+    verbs: &[Verb],
+    inflection: Inflection,
+    form: PoliteLevel,
+) -> Vec<JapaneseResult<Word>> {
+    verbs
+        .iter()
+        .map(|verb| verb.conjugate(inflection, form))
+        .collect()
 }
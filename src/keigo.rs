@@ -0,0 +1,257 @@
+use crate::{Verb, VerbType, Word};
+
+/// A verb in dictionary form which can be replaced by a suppletive keigo verb
+struct Entry {
+    kana: &'static str,
+    /// Kanji readings of the verb. Empty if the verb is usually written in kana
+    kanji: &'static [&'static str],
+    verb_type: VerbType,
+    /// The replacing verb as (kana, kanji, verb type)
+    replacement: (&'static str, &'static str, VerbType),
+}
+
+impl Entry {
+    const fn new(
+        kana: &'static str,
+        kanji: &'static [&'static str],
+        verb_type: VerbType,
+        replacement: (&'static str, &'static str, VerbType),
+    ) -> Self {
+        Self {
+            kana,
+            kanji,
+            verb_type,
+            replacement,
+        }
+    }
+
+    fn matches(&self, verb: &Verb) -> bool {
+        if verb.kana != self.kana || verb.verb_type() != self.verb_type {
+            return false;
+        }
+
+        match verb.kanji {
+            Some(ref kanji) => kanji == self.kana || self.kanji.contains(&kanji.as_str()),
+            None => true,
+        }
+    }
+}
+
+/// Verbs with an honorific (尊敬語) counterpart
+const HONORIFIC: &[Entry] = &[
+    Entry::new(
+        "する",
+        &["為る"],
+        VerbType::Exception,
+        ("なさる", "為さる", VerbType::Godan),
+    ),
+    Entry::new(
+        "いく",
+        &["行く", "逝く"],
+        VerbType::Godan,
+        ("いらっしゃる", "いらっしゃる", VerbType::Godan),
+    ),
+    Entry::new(
+        "くる",
+        &["来る"],
+        VerbType::Exception,
+        ("いらっしゃる", "いらっしゃる", VerbType::Godan),
+    ),
+    Entry::new(
+        "いる",
+        &["居る"],
+        VerbType::Ichidan,
+        ("いらっしゃる", "いらっしゃる", VerbType::Godan),
+    ),
+    Entry::new(
+        "いう",
+        &["言う", "云う"],
+        VerbType::Godan,
+        ("おっしゃる", "仰る", VerbType::Godan),
+    ),
+    Entry::new(
+        "たべる",
+        &["食べる"],
+        VerbType::Ichidan,
+        ("めしあがる", "召し上がる", VerbType::Godan),
+    ),
+    Entry::new(
+        "のむ",
+        &["飲む"],
+        VerbType::Godan,
+        ("めしあがる", "召し上がる", VerbType::Godan),
+    ),
+    Entry::new(
+        "みる",
+        &["見る", "観る"],
+        VerbType::Ichidan,
+        ("ごらんになる", "ご覧になる", VerbType::Godan),
+    ),
+    Entry::new(
+        "くれる",
+        &["呉れる"],
+        VerbType::Ichidan,
+        ("くださる", "下さる", VerbType::Godan),
+    ),
+    Entry::new(
+        "ねる",
+        &["寝る"],
+        VerbType::Ichidan,
+        ("おやすみになる", "お休みになる", VerbType::Godan),
+    ),
+    Entry::new(
+        "きる",
+        &["着る"],
+        VerbType::Ichidan,
+        ("おめしになる", "お召しになる", VerbType::Godan),
+    ),
+];
+
+/// Verbs with a humble (謙譲語) counterpart
+const HUMBLE: &[Entry] = &[
+    Entry::new(
+        "する",
+        &["為る"],
+        VerbType::Exception,
+        ("いたす", "致す", VerbType::Godan),
+    ),
+    Entry::new(
+        "いく",
+        &["行く"],
+        VerbType::Godan,
+        ("まいる", "参る", VerbType::Godan),
+    ),
+    Entry::new(
+        "くる",
+        &["来る"],
+        VerbType::Exception,
+        ("まいる", "参る", VerbType::Godan),
+    ),
+    Entry::new(
+        "いる",
+        &["居る"],
+        VerbType::Ichidan,
+        ("おる", "居る", VerbType::Godan),
+    ),
+    Entry::new(
+        "いう",
+        &["言う", "云う"],
+        VerbType::Godan,
+        ("もうす", "申す", VerbType::Godan),
+    ),
+    Entry::new(
+        "たべる",
+        &["食べる"],
+        VerbType::Ichidan,
+        ("いただく", "頂く", VerbType::Godan),
+    ),
+    Entry::new(
+        "のむ",
+        &["飲む"],
+        VerbType::Godan,
+        ("いただく", "頂く", VerbType::Godan),
+    ),
+    Entry::new(
+        "もらう",
+        &["貰う"],
+        VerbType::Godan,
+        ("いただく", "頂く", VerbType::Godan),
+    ),
+    Entry::new(
+        "みる",
+        &["見る"],
+        VerbType::Ichidan,
+        ("はいけんする", "拝見する", VerbType::Exception),
+    ),
+    Entry::new(
+        "あう",
+        &["会う", "逢う"],
+        VerbType::Godan,
+        ("おめにかかる", "お目にかかる", VerbType::Godan),
+    ),
+    Entry::new(
+        "しる",
+        &["知る"],
+        VerbType::Godan,
+        ("ぞんじる", "存じる", VerbType::Ichidan),
+    ),
+    Entry::new(
+        "おもう",
+        &["思う"],
+        VerbType::Godan,
+        ("ぞんじる", "存じる", VerbType::Ichidan),
+    ),
+    Entry::new(
+        "あげる",
+        &["上げる", "挙げる"],
+        VerbType::Ichidan,
+        ("さしあげる", "差し上げる", VerbType::Ichidan),
+    ),
+    Entry::new(
+        "きく",
+        &["聞く", "聴く"],
+        VerbType::Godan,
+        ("うかがう", "伺う", VerbType::Godan),
+    ),
+    Entry::new(
+        "たずねる",
+        &["訪ねる"],
+        VerbType::Ichidan,
+        ("うかがう", "伺う", VerbType::Godan),
+    ),
+];
+
+/// Returns the suppletive honorific (尊敬語) verb of `verb` in dictionary form, eg. なさる for する
+/// or 召し上がる for 食べる. Compound する verbs use なさる (勉強なさる). Returns `None` if the verb
+/// has no lexical honorific form, in which case [`Verb::honorific`] can be used instead
+///
+/// # Example
+/// ```
+/// use jp_inflections::{keigo, PoliteLevel, VerbType, Word};
+///
+/// let verb = Word::new("いう", Some("言う")).into_verb(VerbType::Godan).unwrap();
+/// let honorific = keigo::honorific_lexical(&verb).unwrap();
+/// assert_eq!(honorific.word.kanji.as_deref(), Some("仰る"));
+/// assert_eq!(honorific.dictionary(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("仰います"));
+///
+/// let verb = Word::new("よむ", Some("読む")).into_verb(VerbType::Godan).unwrap();
+/// assert!(keigo::honorific_lexical(&verb).is_none());
+/// ```
+pub fn honorific_lexical(verb: &Verb) -> Option<Verb> {
+    lookup(verb, HONORIFIC, "なさる")
+}
+
+/// Returns the suppletive humble (謙譲語) verb of `verb` in dictionary form, eg. いたす for する or
+/// 参る for 行く. Compound する verbs use いたす (勉強いたす). Returns `None` if the verb has no
+/// lexical humble form, in which case [`Verb::humble`] can be used instead
+///
+/// # Example
+/// ```
+/// use jp_inflections::{keigo, PoliteLevel, VerbType, Word};
+///
+/// let verb = Word::new("いく", Some("行く")).into_verb(VerbType::Godan).unwrap();
+/// let humble = keigo::humble_lexical(&verb).unwrap();
+/// assert_eq!(humble.word.kanji.as_deref(), Some("参る"));
+/// assert_eq!(humble.dictionary(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("参ります"));
+/// ```
+pub fn humble_lexical(verb: &Verb) -> Option<Verb> {
+    lookup(verb, HUMBLE, "いたす")
+}
+
+/// Looks `verb` up in `table`. Compound する verbs get their する replaced by `suru`
+fn lookup(verb: &Verb, table: &[Entry], suru: &str) -> Option<Verb> {
+    if let Some(entry) = table.iter().find(|entry| entry.matches(verb)) {
+        let (kana, kanji, verb_type) = entry.replacement;
+        let kanji = verb.kanji.as_ref().map(|_| kanji);
+        return Some(Verb::new(Word::new(kana, kanji), verb_type));
+    }
+
+    if verb.verb_type() == VerbType::Exception && verb.kana.len() > "する".len() {
+        let word = verb
+            .word
+            .new_with_suffix_replaced("する", Some("する"), suru, Some(suru))?;
+        return Some(Verb::new(Word::new(word.kana, word.kanji), VerbType::Godan));
+    }
+
+    None
+}
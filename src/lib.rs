@@ -1,15 +1,34 @@
+pub mod adjective;
 pub mod alphabet;
+pub mod attributive;
+pub mod builder;
+pub mod copula;
+pub mod deinflection;
 pub mod error;
 pub mod inflection;
+pub mod keigo;
+pub mod romaji;
 pub mod special_verbs;
 pub mod syllable;
+pub mod table;
+pub mod transitivity;
 pub mod umlaut;
 pub mod verb;
 pub mod word;
 
+pub use adjective::Adjective;
+pub use adjective::AdjectiveType;
+pub use attributive::Attributive;
+pub use builder::VerbBuilder;
+pub use copula::Copula;
+pub use inflection::Inflection;
+pub use table::ConjugationTable;
+pub use verb::NegativeConnective;
 pub use verb::Verb;
 pub use verb::VerbType;
+pub use word::PoliteLevel;
 pub use word::Word;
+#[allow(deprecated)]
 pub use word::WordForm;
 
 pub type JapaneseResult<T> = Result<T, error::Error>;
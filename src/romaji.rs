@@ -0,0 +1,329 @@
+use crate::syllable::Syllable;
+
+/// Hiragana syllables and their (modified) hepburn romanization. Syllables consisting of two
+/// kana have to be listed as well since they can't be romanized one by one
+pub const ROMAJI_SYLLABLES: &[(&str, &str)] = &[
+    ("きゃ", "kya"),
+    ("きゅ", "kyu"),
+    ("きょ", "kyo"),
+    ("ぎゃ", "gya"),
+    ("ぎゅ", "gyu"),
+    ("ぎょ", "gyo"),
+    ("しゃ", "sha"),
+    ("しゅ", "shu"),
+    ("しぇ", "she"),
+    ("しょ", "sho"),
+    ("じゃ", "ja"),
+    ("じゅ", "ju"),
+    ("じぇ", "je"),
+    ("じょ", "jo"),
+    ("ちゃ", "cha"),
+    ("ちゅ", "chu"),
+    ("ちぇ", "che"),
+    ("ちょ", "cho"),
+    ("ぢゃ", "ja"),
+    ("ぢゅ", "ju"),
+    ("ぢょ", "jo"),
+    ("てぃ", "ti"),
+    ("でぃ", "di"),
+    ("にゃ", "nya"),
+    ("にゅ", "nyu"),
+    ("にょ", "nyo"),
+    ("ひゃ", "hya"),
+    ("ひゅ", "hyu"),
+    ("ひょ", "hyo"),
+    ("びゃ", "bya"),
+    ("びゅ", "byu"),
+    ("びょ", "byo"),
+    ("ぴゃ", "pya"),
+    ("ぴゅ", "pyu"),
+    ("ぴょ", "pyo"),
+    ("ふぁ", "fa"),
+    ("ふぃ", "fi"),
+    ("ふぇ", "fe"),
+    ("ふぉ", "fo"),
+    ("みゃ", "mya"),
+    ("みゅ", "myu"),
+    ("みょ", "myo"),
+    ("りゃ", "rya"),
+    ("りゅ", "ryu"),
+    ("りょ", "ryo"),
+    ("うぃ", "wi"),
+    ("うぇ", "we"),
+    ("あ", "a"),
+    ("い", "i"),
+    ("う", "u"),
+    ("え", "e"),
+    ("お", "o"),
+    ("か", "ka"),
+    ("き", "ki"),
+    ("く", "ku"),
+    ("け", "ke"),
+    ("こ", "ko"),
+    ("が", "ga"),
+    ("ぎ", "gi"),
+    ("ぐ", "gu"),
+    ("げ", "ge"),
+    ("ご", "go"),
+    ("さ", "sa"),
+    ("し", "shi"),
+    ("す", "su"),
+    ("せ", "se"),
+    ("そ", "so"),
+    ("ざ", "za"),
+    ("じ", "ji"),
+    ("ず", "zu"),
+    ("ぜ", "ze"),
+    ("ぞ", "zo"),
+    ("た", "ta"),
+    ("ち", "chi"),
+    ("つ", "tsu"),
+    ("て", "te"),
+    ("と", "to"),
+    ("だ", "da"),
+    ("ぢ", "ji"),
+    ("づ", "zu"),
+    ("で", "de"),
+    ("ど", "do"),
+    ("な", "na"),
+    ("に", "ni"),
+    ("ぬ", "nu"),
+    ("ね", "ne"),
+    ("の", "no"),
+    ("は", "ha"),
+    ("ひ", "hi"),
+    ("ふ", "fu"),
+    ("へ", "he"),
+    ("ほ", "ho"),
+    ("ば", "ba"),
+    ("び", "bi"),
+    ("ぶ", "bu"),
+    ("べ", "be"),
+    ("ぼ", "bo"),
+    ("ぱ", "pa"),
+    ("ぴ", "pi"),
+    ("ぷ", "pu"),
+    ("ぺ", "pe"),
+    ("ぽ", "po"),
+    ("ま", "ma"),
+    ("み", "mi"),
+    ("む", "mu"),
+    ("め", "me"),
+    ("も", "mo"),
+    ("や", "ya"),
+    ("ゆ", "yu"),
+    ("よ", "yo"),
+    ("ら", "ra"),
+    ("り", "ri"),
+    ("る", "ru"),
+    ("れ", "re"),
+    ("ろ", "ro"),
+    ("わ", "wa"),
+    ("を", "o"),
+    ("ゔ", "vu"),
+    ("ぁ", "a"),
+    ("ぃ", "i"),
+    ("ぅ", "u"),
+    ("ぇ", "e"),
+    ("ぉ", "o"),
+    ("ゃ", "ya"),
+    ("ゅ", "yu"),
+    ("ょ", "yo"),
+    ("ゎ", "wa"),
+];
+
+/// Romaji which is accepted by [`from_romaji`] in addition to [`ROMAJI_SYLLABLES`] but never
+/// produced by [`to_romaji`]. IMEs expect を to be typed as wo
+const ROMAJI_INPUT_ALIASES: &[(&str, &str)] = &[("を", "wo")];
+
+/// Converts a kana (hiragana or katakana) string to hepburn romaji. Characters which aren't kana
+/// are kept as they are. A っ which isn't followed by a consonant is written as an apostrophe
+///
+/// # Example
+/// ```
+/// use jp_inflections::romaji::to_romaji;
+///
+/// assert_eq!(to_romaji("ならった"), "naratta");
+/// assert_eq!(to_romaji("しんぶん"), "shimbun");
+/// assert_eq!(to_romaji("コーヒー"), "koohii");
+/// assert_eq!(to_romaji("あっ"), "a'");
+/// ```
+pub fn to_romaji(kana: &str) -> String {
+    let kana: Vec<char> = kana
+        .chars()
+        .map(|i| Syllable::from(i).to_hiragana().get_char())
+        .collect();
+
+    let mut romaji = String::with_capacity(kana.len() * 2);
+    let mut sokuon = false;
+    let mut pos = 0;
+
+    while pos < kana.len() {
+        let c = kana[pos];
+
+        if sokuon && c != 'っ' {
+            let next = match c {
+                'ん' | 'ー' => None,
+                _ => syllable_at(&kana, pos).map(|i| i.0),
+            };
+            push_sokuon(&mut romaji, next);
+        }
+
+        match c {
+            'っ' => {
+                if sokuon {
+                    push_sokuon(&mut romaji, None);
+                }
+                sokuon = true;
+                pos += 1;
+                continue;
+            }
+            'ん' => {
+                let next = syllable_at(&kana, pos + 1).map(|i| i.0);
+                match next.and_then(|i| i.chars().next()) {
+                    Some('b' | 'm' | 'p') => romaji.push('m'),
+                    Some('a' | 'i' | 'u' | 'e' | 'o' | 'y') => romaji.push_str("n'"),
+                    _ => romaji.push('n'),
+                }
+                pos += 1;
+            }
+            // Long vowel mark repeats the last vowel
+            'ー' => {
+                match romaji.chars().last().filter(|i| is_vowel(*i)) {
+                    Some(vowel) => romaji.push(vowel),
+                    None => romaji.push(c),
+                }
+                pos += 1;
+            }
+            _ => match syllable_at(&kana, pos) {
+                Some((syllable, len)) => {
+                    romaji.push_str(syllable);
+                    pos += len;
+                }
+                None => {
+                    romaji.push(c);
+                    pos += 1;
+                }
+            },
+        }
+
+        sokuon = false;
+    }
+
+    if sokuon {
+        push_sokuon(&mut romaji, None);
+    }
+
+    romaji
+}
+
+/// Converts hepburn romaji to hiragana. Returns `None` if the input can't be parsed
+///
+/// # Example
+/// ```
+/// use jp_inflections::romaji::from_romaji;
+///
+/// assert_eq!(from_romaji("narau"), Some(String::from("ならう")));
+/// assert_eq!(from_romaji("naratta"), Some(String::from("ならった")));
+/// assert_eq!(from_romaji("shimbun"), Some(String::from("しんぶん")));
+/// assert_eq!(from_romaji("kaowoarau"), Some(String::from("かおをあらう")));
+/// assert_eq!(from_romaji("xyz"), None);
+/// ```
+pub fn from_romaji(romaji: &str) -> Option<String> {
+    let romaji: Vec<char> = romaji.to_lowercase().chars().collect();
+
+    let mut kana = String::with_capacity(romaji.len() * 3);
+    let mut pos = 0;
+
+    while pos < romaji.len() {
+        let c = romaji[pos];
+        let next = romaji.get(pos + 1).copied();
+
+        // Separator used to disambiguate ん (kin'en)
+        if c == '\'' {
+            pos += 1;
+            continue;
+        }
+
+        // ん is written as n if no vowel follows and as m in front of labials
+        let is_n = c == 'n' && !next.map(|i| is_vowel(i) || i == 'y').unwrap_or_default();
+        let is_m = c == 'm' && matches!(next, Some('b' | 'm' | 'p'));
+        if is_n || is_m {
+            kana.push('ん');
+            pos += 1;
+            continue;
+        }
+
+        // Doubled consonants and tch are written with a っ
+        let is_double = !is_vowel(c) && next == Some(c);
+        if is_double || (c == 't' && next == Some('c')) {
+            kana.push('っ');
+            pos += 1;
+            continue;
+        }
+
+        let (syllable, len) = kana_at(&romaji, pos)?;
+        kana.push_str(syllable);
+        pos += len;
+    }
+
+    Some(kana)
+}
+
+/// Returns the kana of the longest romaji syllable starting at `pos` and its length in romaji
+fn kana_at(romaji: &[char], pos: usize) -> Option<(&'static str, usize)> {
+    let mut found: Option<(&'static str, usize)> = None;
+
+    for (kana, syllable) in ROMAJI_SYLLABLES.iter().chain(ROMAJI_INPUT_ALIASES) {
+        let len = syllable.chars().count();
+        let matches = romaji.len() >= pos + len
+            && syllable.chars().eq(romaji[pos..pos + len].iter().copied());
+
+        if matches && found.map(|i| len > i.1).unwrap_or(true) {
+            found = Some((kana, len));
+        }
+    }
+
+    found
+}
+
+/// Returns the romaji of the syllable starting at `pos` and its length in kana
+fn syllable_at(kana: &[char], pos: usize) -> Option<(&'static str, usize)> {
+    let first = *kana.get(pos)?;
+    let second = kana.get(pos + 1);
+
+    let mut single = None;
+    for (syllable, romaji) in ROMAJI_SYLLABLES {
+        let mut chars = syllable.chars();
+        if chars.next() != Some(first) {
+            continue;
+        }
+
+        match chars.next() {
+            Some(c) if Some(&c) == second => return Some((romaji, 2)),
+            None => single = Some((*romaji, 1)),
+            _ => (),
+        }
+    }
+
+    single
+}
+
+/// Pushes the doubled consonant of a っ in front of `syllable`, or an apostrophe if there is none
+fn push_sokuon(romaji: &mut String, syllable: Option<&str>) {
+    let syllable = syllable.unwrap_or_default();
+    if syllable.starts_with("ch") {
+        romaji.push('t');
+        return;
+    }
+
+    // Without a following consonant to double the っ is marked with an apostrophe
+    match syllable.chars().next().filter(|i| !is_vowel(*i)) {
+        Some(c) => romaji.push(c),
+        None => romaji.push('\''),
+    }
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'i' | 'u' | 'e' | 'o')
+}
@@ -0,0 +1,73 @@
+use super::{KanaKanjiPair, SpecialVerb};
+use crate::{PoliteLevel, Word};
+
+/// Kanji readings of ある
+const KANJI: &[&str] = &["有る", "在る"];
+
+// ある
+pub struct SpecialAru;
+impl SpecialVerb for SpecialAru {
+    #[inline]
+    fn dict() -> KanaKanjiPair<'static> {
+        ("ある", Some("有る"))
+    }
+
+    /// The negative of ある is the suppletive ない. The polite negative ありません is regular
+    #[inline]
+    fn negative_suf(wf: PoliteLevel) -> Option<KanaKanjiPair<'static>> {
+        match wf {
+            PoliteLevel::Plain => Some(("ない", Some("ない"))),
+            PoliteLevel::Polite => None,
+        }
+    }
+
+    /// Matches both kanji readings (有る, 在る) as well as compounds written in kana (書いてある)
+    fn replace_suffix(word: &Word, replace: KanaKanjiPair) -> Option<Word> {
+        let (kana_s, _) = Self::to_strip_suffix();
+
+        KANJI.iter().chain(&[kana_s]).find_map(|kanji| {
+            word.new_with_suffix_replaced(kana_s, Some(kanji), replace.0, replace.1)
+        })
+    }
+}
+
+impl SpecialAru {
+    #[inline]
+    pub fn negative_past_suf() -> KanaKanjiPair<'static> {
+        ("なかった", Some("なかった"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SpecialAru;
+    use super::SpecialVerb;
+    use crate::inflection::Inflection;
+    use crate::PoliteLevel;
+    use crate::Word;
+
+    #[test]
+    fn test_special_aru() {
+        for kanji in ["有る", "在る"].iter().copied() {
+            let word_aru = Word::new("ある", Some(kanji))
+                .into_verb(crate::VerbType::Godan)
+                .unwrap();
+
+            let neg = SpecialAru::format_verb(&word_aru, Inflection::Negative, PoliteLevel::Plain)
+                .unwrap();
+            assert_eq!(neg.kana, "ない");
+            assert_eq!(neg.kanji, Some("ない".to_string()));
+        }
+    }
+
+    #[test]
+    fn test_special_aru_compound() {
+        let word_aru = Word::new("かいてある", Some("書いてある"))
+            .into_verb(crate::VerbType::Godan)
+            .unwrap();
+
+        let neg = SpecialAru::replace_suffix(&word_aru, SpecialAru::negative_past_suf()).unwrap();
+        assert_eq!(neg.kana, "かいてなかった");
+        assert_eq!(neg.kanji, Some("書いてなかった".to_string()));
+    }
+}
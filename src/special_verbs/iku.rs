@@ -0,0 +1,56 @@
+use super::{KanaKanjiPair, SpecialVerb};
+
+// 行く
+pub struct SpecialIku;
+impl SpecialVerb for SpecialIku {
+    #[inline]
+    fn dict() -> KanaKanjiPair<'static> {
+        ("いく", Some("行く"))
+    }
+
+    #[inline]
+    fn te_form_suf() -> Option<KanaKanjiPair<'static>> {
+        Some(("いって", Some("行って")))
+    }
+
+    #[inline]
+    fn past_suf() -> Option<KanaKanjiPair<'static>> {
+        Some(("いった", Some("行った")))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SpecialIku;
+    use super::SpecialVerb;
+    use crate::inflection::Inflection;
+    use crate::PoliteLevel;
+    use crate::Word;
+
+    #[test]
+    fn test_special_iku() {
+        let word_iku = Word::new("もっていく", Some("持って行く"))
+            .into_verb(crate::VerbType::Godan)
+            .unwrap();
+
+        let past =
+            SpecialIku::format_verb(&word_iku, Inflection::Past, PoliteLevel::Plain).unwrap();
+        assert_eq!(past.kana, "もっていった");
+        assert_eq!(past.kanji, Some("持って行った".to_string()));
+
+        let te = SpecialIku::format_verb(&word_iku, Inflection::Te, PoliteLevel::Plain).unwrap();
+        assert_eq!(te.kana, "もっていって");
+        assert_eq!(te.kanji, Some("持って行って".to_string()));
+    }
+
+    #[test]
+    fn test_special_iku_regular() {
+        let word_iku = Word::new("いく", Some("行く"))
+            .into_verb(crate::VerbType::Godan)
+            .unwrap();
+
+        assert!(
+            SpecialIku::format_verb(&word_iku, Inflection::Negative, PoliteLevel::Plain).is_none()
+        );
+    }
+}
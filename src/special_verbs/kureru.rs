@@ -0,0 +1,71 @@
+use super::{KanaKanjiPair, SpecialVerb};
+use crate::Word;
+
+// 呉れる
+pub struct SpecialKureru;
+impl SpecialVerb for SpecialKureru {
+    #[inline]
+    fn dict() -> KanaKanjiPair<'static> {
+        ("くれる", Some("呉れる"))
+    }
+
+    #[inline]
+    fn imperative_suf() -> Option<KanaKanjiPair<'static>> {
+        Some(("くれ", Some("呉れ")))
+    }
+
+    /// Only matches くれる on its own or as auxiliary after a て form (待ってくれる) to not
+    /// confuse it with verbs like 隠れる (かくれる) or 暮れる
+    fn replace_suffix(word: &Word, replace: KanaKanjiPair) -> Option<Word> {
+        let (kana_s, kanji_s) = Self::to_strip_suffix();
+
+        let prefix = word.kana.strip_suffix(kana_s)?;
+        if !prefix.is_empty() && !prefix.ends_with('て') && !prefix.ends_with('で') {
+            return None;
+        }
+
+        word.new_with_suffix_replaced(kana_s, kanji_s, replace.0, replace.1)
+            .or_else(|| {
+                word.new_with_suffix_replaced(kana_s, Some(kana_s), replace.0, Some(replace.0))
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SpecialKureru;
+    use super::SpecialVerb;
+    use crate::inflection::Inflection;
+    use crate::PoliteLevel;
+    use crate::Word;
+
+    #[test]
+    fn test_special_kureru() {
+        let word_kureru = Word::new("まってくれる", Some("待ってくれる"))
+            .into_verb(crate::VerbType::Ichidan)
+            .unwrap();
+
+        let imperative =
+            SpecialKureru::format_verb(&word_kureru, Inflection::Imperative, PoliteLevel::Plain)
+                .unwrap();
+        assert_eq!(imperative.kana, "まってくれ");
+        assert_eq!(imperative.kanji, Some("待ってくれ".to_string()));
+    }
+
+    #[test]
+    fn test_special_kureru_regular() {
+        for (kana, kanji) in [("かくれる", "隠れる"), ("くれる", "暮れる")]
+            .iter()
+            .copied()
+        {
+            let word = Word::new(kana, Some(kanji))
+                .into_verb(crate::VerbType::Ichidan)
+                .unwrap();
+
+            assert!(
+                SpecialKureru::format_verb(&word, Inflection::Imperative, PoliteLevel::Plain)
+                    .is_none()
+            );
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use super::{KanaKanjiPair, SpecialVerb};
-use crate::WordForm;
+use crate::PoliteLevel;
 
 // 来る
 pub struct SpecialKuru;
@@ -10,58 +10,71 @@ impl SpecialVerb for SpecialKuru {
     }
 
     #[inline]
-    fn stem_potential_suf() -> KanaKanjiPair<'static> {
-        ("こられ", Some("来られ"))
+    fn stem_potential_suf() -> Option<KanaKanjiPair<'static>> {
+        Some(("こられ", Some("来られ")))
     }
 
     #[inline]
-    fn stem_ba_suf() -> KanaKanjiPair<'static> {
-        ("これ", Some("来れ"))
+    fn stem_ba_suf() -> Option<KanaKanjiPair<'static>> {
+        Some(("くれ", Some("来れ")))
     }
 
     #[inline]
-    fn stem_suf(wf: WordForm) -> KanaKanjiPair<'static> {
+    fn stem_suf(wf: PoliteLevel) -> Option<KanaKanjiPair<'static>> {
         match wf {
-            WordForm::Short => ("こ", Some("来")),
-            WordForm::Long => ("き", Some("来")),
+            PoliteLevel::Plain => Some(("こ", Some("来"))),
+            PoliteLevel::Polite => Some(("き", Some("来"))),
         }
     }
 
     #[inline]
-    fn te_form_suf() -> KanaKanjiPair<'static> {
-        ("きて", Some("来て"))
+    fn te_form_suf() -> Option<KanaKanjiPair<'static>> {
+        Some(("きて", Some("来て")))
     }
 
     #[inline]
-    fn past_suf() -> KanaKanjiPair<'static> {
-        ("きた", Some("来た"))
+    fn past_suf() -> Option<KanaKanjiPair<'static>> {
+        Some(("きた", Some("来た")))
     }
 
     #[inline]
-    fn negative_suf(wf: WordForm) -> KanaKanjiPair<'static> {
+    fn negative_suf(wf: PoliteLevel) -> Option<KanaKanjiPair<'static>> {
         match wf {
-            WordForm::Short => ("こない", Some("来ない")),
-            WordForm::Long => ("きません", Some("来ません")),
+            PoliteLevel::Plain => Some(("こない", Some("来ない"))),
+            PoliteLevel::Polite => Some(("きません", Some("来ません"))),
         }
     }
 
     #[inline]
-    fn causative_passive_suf() -> KanaKanjiPair<'static> {
-        ("こさせられる", Some("来させられる"))
+    fn causative_passive_suf() -> Option<KanaKanjiPair<'static>> {
+        Some(("こさせられる", Some("来させられる")))
     }
 
     #[inline]
-    fn causative_suf() -> KanaKanjiPair<'static> {
-        ("こさせる", Some("来させる"))
+    fn causative_suf() -> Option<KanaKanjiPair<'static>> {
+        Some(("こさせる", Some("来させる")))
     }
 
     #[inline]
-    fn passive_suf() -> KanaKanjiPair<'static> {
-        ("こられる", Some("来られる"))
+    fn passive_suf() -> Option<KanaKanjiPair<'static>> {
+        Some(("こられる", Some("来られる")))
     }
 
-    fn imperative_suf() -> KanaKanjiPair<'static> {
-        ("こい", Some("来い"))
+    fn imperative_suf() -> Option<KanaKanjiPair<'static>> {
+        Some(("こい", Some("来い")))
+    }
+
+    #[inline]
+    fn volitional_suf() -> Option<KanaKanjiPair<'static>> {
+        Some(("こよ", Some("来よ")))
+    }
+}
+
+impl SpecialKuru {
+    /// Stem of the Kansai negative けーへん
+    #[inline]
+    pub fn kansai_negative_stem_suf() -> KanaKanjiPair<'static> {
+        ("けー", Some("来ー"))
     }
 }
 
@@ -70,8 +83,8 @@ mod test {
     use super::SpecialKuru;
     use super::SpecialVerb;
     use crate::inflection::Inflection;
+    use crate::PoliteLevel;
     use crate::Word;
-    use crate::WordForm;
 
     #[test]
     fn test_special_kuru() {
@@ -79,14 +92,30 @@ mod test {
             .into_verb(crate::VerbType::Exception)
             .unwrap();
 
-        let past = SpecialKuru::format_verb(&word_kuru, Inflection::Past, WordForm::Long).unwrap();
+        let past =
+            SpecialKuru::format_verb(&word_kuru, Inflection::Past, PoliteLevel::Polite).unwrap();
         assert_eq!(past.kana, "きた");
         assert_eq!(past.kanji, Some("来た".to_string()));
 
         let neg =
-            SpecialKuru::format_verb(&word_kuru, Inflection::Negative, WordForm::Short).unwrap();
+            SpecialKuru::format_verb(&word_kuru, Inflection::Negative, PoliteLevel::Plain).unwrap();
         assert_eq!(neg.kana, "こない");
         assert_eq!(neg.kanji, Some("来ない".to_string()));
+
+        let ba =
+            SpecialKuru::format_verb(&word_kuru, Inflection::StemBa, PoliteLevel::Plain).unwrap();
+        assert_eq!(ba.kana, "くれ");
+        assert_eq!(ba.kanji, Some("来れ".to_string()));
+
+        let volitional =
+            SpecialKuru::format_verb(&word_kuru, Inflection::Volitional, PoliteLevel::Plain)
+                .unwrap();
+        assert_eq!(volitional.kana, "こよ");
+        assert_eq!(volitional.kanji, Some("来よ".to_string()));
+
+        let present =
+            SpecialKuru::format_verb(&word_kuru, Inflection::Present, PoliteLevel::Plain).unwrap();
+        assert_eq!(present, Word::new("くる", Some("来る")));
     }
 
     #[test]
@@ -95,12 +124,13 @@ mod test {
             .into_verb(crate::VerbType::Exception)
             .unwrap();
 
-        let past = SpecialKuru::format_verb(&word_kuru, Inflection::Past, WordForm::Long).unwrap();
+        let past =
+            SpecialKuru::format_verb(&word_kuru, Inflection::Past, PoliteLevel::Polite).unwrap();
         assert_eq!(past.kana, "よせきた");
         assert_eq!(past.kanji, Some("寄せ来た".to_string()));
 
         let neg =
-            SpecialKuru::format_verb(&word_kuru, Inflection::Negative, WordForm::Short).unwrap();
+            SpecialKuru::format_verb(&word_kuru, Inflection::Negative, PoliteLevel::Plain).unwrap();
         assert_eq!(neg.kana, "よせこない");
         assert_eq!(neg.kanji, Some("寄せ来ない".to_string()));
     }
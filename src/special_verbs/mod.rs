@@ -1,6 +1,11 @@
+pub mod aru;
+pub mod iku;
+pub mod kureru;
 pub mod kuru;
+pub mod suru;
+pub mod tou;
 
-use crate::{inflection::Inflection, Word, WordForm};
+use crate::{inflection::Inflection, PoliteLevel, Word};
 
 pub type KanaKanjiPair<'s> = (&'s str, Option<&'s str>);
 
@@ -11,34 +16,80 @@ pub trait SpecialVerb {
         Self::dict()
     }
 
-    fn stem_suf(wf: WordForm) -> KanaKanjiPair<'static>;
-    fn stem_ba_suf() -> KanaKanjiPair<'static>;
-    fn stem_potential_suf() -> KanaKanjiPair<'static>;
-    fn te_form_suf() -> KanaKanjiPair<'static>;
-    fn past_suf() -> KanaKanjiPair<'static>;
-    fn negative_suf(wf: WordForm) -> KanaKanjiPair<'static>;
-    fn passive_suf() -> KanaKanjiPair<'static>;
-    fn causative_suf() -> KanaKanjiPair<'static>;
-    fn causative_passive_suf() -> KanaKanjiPair<'static>;
-    fn imperative_suf() -> KanaKanjiPair<'static>;
-
-    fn format_verb(word: &Word, inflection: Inflection, wf: WordForm) -> Option<Word> {
-        let fmt = |word: &Word, replace: KanaKanjiPair| -> Option<Word> {
-            let (kana_s, kanji_s) = Self::to_strip_suffix();
-            word.new_with_suffix_replaced(kana_s, kanji_s, replace.0, replace.1)
-        };
-
-        match inflection {
-            Inflection::Stem => fmt(word, Self::stem_suf(wf)),
-            Inflection::StemPotential => fmt(word, Self::stem_potential_suf()),
-            Inflection::Te => fmt(word, Self::te_form_suf()),
-            Inflection::Past => fmt(word, Self::past_suf()),
-            Inflection::Passive => fmt(word, Self::passive_suf()),
-            Inflection::Causative => fmt(word, Self::causative_suf()),
-            Inflection::CausativePassive => fmt(word, Self::causative_passive_suf()),
-            Inflection::Negative => fmt(word, Self::negative_suf(wf)),
-            Inflection::Imperative => fmt(word, Self::imperative_suf()),
+    fn stem_suf(_wf: PoliteLevel) -> Option<KanaKanjiPair<'static>> {
+        None
+    }
+
+    fn stem_ba_suf() -> Option<KanaKanjiPair<'static>> {
+        None
+    }
+
+    fn stem_potential_suf() -> Option<KanaKanjiPair<'static>> {
+        None
+    }
+
+    fn te_form_suf() -> Option<KanaKanjiPair<'static>> {
+        None
+    }
+
+    fn past_suf() -> Option<KanaKanjiPair<'static>> {
+        None
+    }
+
+    fn negative_suf(_wf: PoliteLevel) -> Option<KanaKanjiPair<'static>> {
+        None
+    }
+
+    fn passive_suf() -> Option<KanaKanjiPair<'static>> {
+        None
+    }
+
+    fn causative_suf() -> Option<KanaKanjiPair<'static>> {
+        None
+    }
+
+    fn causative_passive_suf() -> Option<KanaKanjiPair<'static>> {
+        None
+    }
+
+    fn imperative_suf() -> Option<KanaKanjiPair<'static>> {
+        None
+    }
+
+    fn volitional_suf() -> Option<KanaKanjiPair<'static>> {
+        None
+    }
+
+    /// Returns the word conjugated into `inflection` or `None` if the word isn't this special
+    /// verb or the inflection is regular
+    fn format_verb(word: &Word, inflection: Inflection, wf: PoliteLevel) -> Option<Word> {
+        let replace = match inflection {
+            Inflection::Positive | Inflection::Present => Some(Self::dict()),
+            Inflection::Stem => Self::stem_suf(wf),
+            Inflection::StemPotential => Self::stem_potential_suf(),
+            Inflection::StemBa => Self::stem_ba_suf(),
+            Inflection::Te => Self::te_form_suf(),
+            Inflection::Past => Self::past_suf(),
+            Inflection::Passive => Self::passive_suf(),
+            Inflection::Causative => Self::causative_suf(),
+            Inflection::CausativePassive => Self::causative_passive_suf(),
+            Inflection::Negative => Self::negative_suf(wf),
+            Inflection::Imperative => Self::imperative_suf(),
+            Inflection::Volitional => Self::volitional_suf(),
             _ => None,
-        }
+        }?;
+
+        Self::replace_suffix(word, replace)
+    }
+
+    /// Replaces the suffix of this special verb in `word` with `replace`. Returns `None` if the word
+    /// doesn't end with this special verb. Words having their kanji reading written in kana
+    /// (持っていく) are matched by their kana suffix
+    fn replace_suffix(word: &Word, replace: KanaKanjiPair) -> Option<Word> {
+        let (kana_s, kanji_s) = Self::to_strip_suffix();
+        word.new_with_suffix_replaced(kana_s, kanji_s, replace.0, replace.1)
+            .or_else(|| {
+                word.new_with_suffix_replaced(kana_s, Some(kana_s), replace.0, Some(replace.0))
+            })
     }
 }
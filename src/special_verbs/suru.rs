@@ -0,0 +1,135 @@
+use super::{KanaKanjiPair, SpecialVerb};
+use crate::PoliteLevel;
+
+// する
+pub struct SpecialSuru;
+impl SpecialVerb for SpecialSuru {
+    #[inline]
+    fn dict() -> KanaKanjiPair<'static> {
+        ("する", Some("為る"))
+    }
+
+    #[inline]
+    fn stem_suf(_wf: PoliteLevel) -> Option<KanaKanjiPair<'static>> {
+        Some(("し", Some("為")))
+    }
+
+    #[inline]
+    fn stem_potential_suf() -> Option<KanaKanjiPair<'static>> {
+        Some(("でき", Some("出来")))
+    }
+
+    #[inline]
+    fn stem_ba_suf() -> Option<KanaKanjiPair<'static>> {
+        Some(("すれ", Some("為れ")))
+    }
+
+    #[inline]
+    fn te_form_suf() -> Option<KanaKanjiPair<'static>> {
+        Some(("して", Some("為て")))
+    }
+
+    #[inline]
+    fn past_suf() -> Option<KanaKanjiPair<'static>> {
+        Some(("した", Some("為た")))
+    }
+
+    #[inline]
+    fn passive_suf() -> Option<KanaKanjiPair<'static>> {
+        Some(("される", Some("為れる")))
+    }
+
+    #[inline]
+    fn causative_suf() -> Option<KanaKanjiPair<'static>> {
+        Some(("させる", Some("為せる")))
+    }
+
+    #[inline]
+    fn causative_passive_suf() -> Option<KanaKanjiPair<'static>> {
+        Some(("させられる", Some("為せられる")))
+    }
+
+    #[inline]
+    fn imperative_suf() -> Option<KanaKanjiPair<'static>> {
+        Some(("しろ", Some("為ろ")))
+    }
+
+    #[inline]
+    fn volitional_suf() -> Option<KanaKanjiPair<'static>> {
+        Some(("しよ", Some("為よ")))
+    }
+}
+
+impl SpecialSuru {
+    /// Stem used by the classical negatives (ぬ, ざる)
+    #[inline]
+    pub fn classical_negative_stem_suf() -> KanaKanjiPair<'static> {
+        ("せ", Some("為"))
+    }
+
+    /// Stem of the negative volitional すまい
+    #[inline]
+    pub fn negative_volitional_stem_suf() -> KanaKanjiPair<'static> {
+        ("す", Some("為"))
+    }
+
+    /// Stem of the Kansai negative せーへん
+    #[inline]
+    pub fn kansai_negative_stem_suf() -> KanaKanjiPair<'static> {
+        ("せー", Some("為ー"))
+    }
+
+    #[inline]
+    pub fn zu_suf() -> KanaKanjiPair<'static> {
+        ("せず", Some("為ず"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SpecialSuru;
+    use super::SpecialVerb;
+    use crate::inflection::Inflection;
+    use crate::PoliteLevel;
+    use crate::Word;
+
+    #[test]
+    fn test_special_suru() {
+        let word_suru = Word::new("する", Some("為る"))
+            .into_verb(crate::VerbType::Exception)
+            .unwrap();
+
+        let potential =
+            SpecialSuru::format_verb(&word_suru, Inflection::StemPotential, PoliteLevel::Polite)
+                .unwrap();
+        assert_eq!(potential.kana, "でき");
+        assert_eq!(potential.kanji, Some("出来".to_string()));
+
+        let zu = SpecialSuru::replace_suffix(&word_suru, SpecialSuru::zu_suf()).unwrap();
+        assert_eq!(zu.kana, "せず");
+        assert_eq!(zu.kanji, Some("為ず".to_string()));
+    }
+
+    #[test]
+    fn test_special_suru_compound() {
+        let word_suru = Word::new("べんきょうする", Some("勉強する"))
+            .into_verb(crate::VerbType::Exception)
+            .unwrap();
+
+        let passive =
+            SpecialSuru::format_verb(&word_suru, Inflection::Passive, PoliteLevel::Polite).unwrap();
+        assert_eq!(passive.kana, "べんきょうされる");
+        assert_eq!(passive.kanji, Some("勉強される".to_string()));
+
+        let volitional =
+            SpecialSuru::format_verb(&word_suru, Inflection::Volitional, PoliteLevel::Polite)
+                .unwrap();
+        assert_eq!(volitional.kana, "べんきょうしよ");
+        assert_eq!(volitional.kanji, Some("勉強しよ".to_string()));
+
+        assert!(
+            SpecialSuru::format_verb(&word_suru, Inflection::Negative, PoliteLevel::Plain)
+                .is_none()
+        );
+    }
+}
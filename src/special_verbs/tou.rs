@@ -0,0 +1,94 @@
+use super::{KanaKanjiPair, SpecialVerb};
+use crate::Word;
+
+/// う verbs which keep their う in the て form (問うて instead of 問って)
+pub const VERBS: &[KanaKanjiPair<'static>] = &[
+    ("とう", Some("問う")),
+    ("こう", Some("請う")),
+    ("こう", Some("乞う")),
+    ("こう", Some("恋う")),
+];
+
+// 問う and the other verbs in [`VERBS`]
+pub struct SpecialTou;
+impl SpecialVerb for SpecialTou {
+    #[inline]
+    fn dict() -> KanaKanjiPair<'static> {
+        VERBS[0]
+    }
+
+    #[inline]
+    fn te_form_suf() -> Option<KanaKanjiPair<'static>> {
+        Some(("て", Some("て")))
+    }
+
+    #[inline]
+    fn past_suf() -> Option<KanaKanjiPair<'static>> {
+        Some(("た", Some("た")))
+    }
+
+    /// Appends `replace` to the dictionary form if the word is one of [`VERBS`]. Words written in
+    /// kana only have to match the whole reading, since many godan verbs end in とう or こう (持とう,
+    /// 憩う)
+    fn replace_suffix(word: &Word, replace: KanaKanjiPair) -> Option<Word> {
+        let is_special = VERBS.iter().any(|(kana, kanji)| match word.kanji {
+            Some(ref word_kanji) if *word_kanji != word.kana => {
+                word.strip_suffix(kana, *kanji).is_some()
+            }
+            _ => word.kana == *kana,
+        });
+        if !is_special {
+            return None;
+        }
+
+        let mut word = word.clone();
+        word.kana.push_str(replace.0);
+        if let (Some(kanji), Some(suffix)) = (word.kanji.as_mut(), replace.1) {
+            kanji.push_str(suffix);
+        }
+        Some(word)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SpecialTou;
+    use super::SpecialVerb;
+    use crate::inflection::Inflection;
+    use crate::PoliteLevel;
+    use crate::Word;
+
+    #[test]
+    fn test_special_tou() {
+        let word_tou = Word::new("とう", Some("問う"))
+            .into_verb(crate::VerbType::Godan)
+            .unwrap();
+
+        let te = SpecialTou::format_verb(&word_tou, Inflection::Te, PoliteLevel::Plain).unwrap();
+        assert_eq!(te.kana, "とうて");
+        assert_eq!(te.kanji, Some("問うて".to_string()));
+    }
+
+    #[test]
+    fn test_special_tou_regular() {
+        let word_iu = Word::new("いう", Some("言う"))
+            .into_verb(crate::VerbType::Godan)
+            .unwrap();
+
+        assert!(SpecialTou::format_verb(&word_iu, Inflection::Te, PoliteLevel::Plain).is_none());
+    }
+
+    #[test]
+    fn test_special_tou_kana_suffix() {
+        let word_ikou = Word::new("いこう", None)
+            .into_verb(crate::VerbType::Godan)
+            .unwrap();
+        assert!(SpecialTou::format_verb(&word_ikou, Inflection::Te, PoliteLevel::Plain).is_none());
+
+        let word_kou = Word::new("こう", None)
+            .into_verb(crate::VerbType::Godan)
+            .unwrap();
+        let te = SpecialTou::format_verb(&word_kou, Inflection::Te, PoliteLevel::Plain).unwrap();
+        assert_eq!(te.kana, "こうて");
+    }
+}
@@ -1,7 +1,39 @@
-use std::{fmt::Display, ops::Deref};
+use std::{fmt::Display, ops::Deref, sync::OnceLock};
 
 use crate::{alphabet, umlaut::Umlaut};
 
+/// Distance between a hiragana character and its katakana equivalent in unicode
+const KANA_OFFSET: u32 = 0x60;
+
+/// First codepoint of the hiragana unicode block
+const KANA_BLOCK_START: u32 = 0x3040;
+
+/// Amount of codepoints within the hiragana and katakana unicode blocks
+const KANA_BLOCK_LEN: usize = 0xC0;
+
+/// Returns a lookup table for all hiragana and katakana syllables, indexed by their offset to
+/// [`KANA_BLOCK_START`]. The table gets built once on first access
+fn info_table() -> &'static [Option<Info>; KANA_BLOCK_LEN] {
+    static TABLE: OnceLock<[Option<Info>; KANA_BLOCK_LEN]> = OnceLock::new();
+
+    TABLE.get_or_init(|| {
+        let mut table = [None; KANA_BLOCK_LEN];
+
+        let tables = [alphabet::HIRAGANA_SYLLABLES, alphabet::KATAKANA_SYLLABLES];
+        for (row, letters) in tables.iter().flat_map(|i| i.iter()) {
+            for (character, umlaut) in *letters {
+                let index = (*character as u32 - KANA_BLOCK_START) as usize;
+                table[index] = Some(Info {
+                    umlaut: *umlaut,
+                    row: *row,
+                });
+            }
+        }
+
+        table
+    })
+}
+
 /// One single syllable within the a kana alphabet
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub struct Syllable(char);
@@ -86,20 +118,8 @@ impl Syllable {
     /// assert_eq!(s.get_info(), Some(Info { row: Row::G, umlaut: Umlaut::A }));
     /// ```
     pub fn get_info(&self) -> Option<Info> {
-        let c = self.0;
-
-        for (row, letters) in alphabet::HIRAGANA_SYLLABLES {
-            for (character, umlaut) in *letters {
-                if *character == c {
-                    return Some(Info {
-                        umlaut: *umlaut,
-                        row: *row,
-                    });
-                }
-            }
-        }
-
-        None
+        let index = (self.0 as u32).checked_sub(KANA_BLOCK_START)? as usize;
+        info_table().get(index).copied().flatten()
     }
 
     pub fn to_dakuten(&self) -> Self {
@@ -128,6 +148,170 @@ impl Syllable {
         }
     }
 
+    /// Returns the handakuten (ぱ row) version of a syllable in the は row or the syllable itself
+    /// otherwise
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::syllable::Syllable;
+    ///
+    /// assert_eq!(Syllable::from('は').to_handakuten(), Syllable::from('ぱ'));
+    /// assert_eq!(Syllable::from('フ').to_handakuten(), Syllable::from('プ'));
+    /// assert_eq!(Syllable::from('か').to_handakuten(), Syllable::from('か'));
+    /// ```
+    pub fn to_handakuten(&self) -> Self {
+        let handakuten = match self.to_hiragana().get_char() {
+            'は' => 'ぱ',
+            'ひ' => 'ぴ',
+            'ふ' => 'ぷ',
+            'へ' => 'ぺ',
+            'ほ' => 'ぽ',
+            _ => return *self,
+        };
+
+        self.with_script(handakuten)
+    }
+
+    /// Returns the syllable without its dakuten or handakuten
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::syllable::Syllable;
+    ///
+    /// assert_eq!(Syllable::from('が').to_seion(), Syllable::from('か'));
+    /// assert_eq!(Syllable::from('ぱ').to_seion(), Syllable::from('は'));
+    /// assert_eq!(Syllable::from('ヅ').to_seion(), Syllable::from('ツ'));
+    /// assert_eq!(Syllable::from('な').to_seion(), Syllable::from('な'));
+    /// ```
+    pub fn to_seion(&self) -> Self {
+        let seion = match self.to_hiragana().get_char() {
+            'が' => 'か',
+            'ぎ' => 'き',
+            'ぐ' => 'く',
+            'げ' => 'け',
+            'ご' => 'こ',
+            'ざ' => 'さ',
+            'じ' => 'し',
+            'ず' => 'す',
+            'ぜ' => 'せ',
+            'ぞ' => 'そ',
+            'だ' => 'た',
+            'ぢ' => 'ち',
+            'づ' => 'つ',
+            'で' => 'て',
+            'ど' => 'と',
+            'ば' | 'ぱ' => 'は',
+            'び' | 'ぴ' => 'ひ',
+            'ぶ' | 'ぷ' => 'ふ',
+            'べ' | 'ぺ' => 'へ',
+            'ぼ' | 'ぽ' => 'ほ',
+            _ => return *self,
+        };
+
+        self.with_script(seion)
+    }
+
+    /// Returns `true` if the syllable is a small kana (sokuon っ or youon ゃ, ゅ, ょ)
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::syllable::Syllable;
+    ///
+    /// assert!(Syllable::from('っ').is_small());
+    /// assert!(Syllable::from('ョ').is_small());
+    /// assert!(!Syllable::from('つ').is_small());
+    /// ```
+    pub fn is_small(&self) -> bool {
+        matches!(self.to_hiragana().get_char(), 'っ' | 'ゃ' | 'ゅ' | 'ょ')
+    }
+
+    /// Returns the full size version of a small kana or the syllable itself otherwise
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::syllable::Syllable;
+    ///
+    /// assert_eq!(Syllable::from('っ').to_large(), Syllable::from('つ'));
+    /// assert_eq!(Syllable::from('ャ').to_large(), Syllable::from('ヤ'));
+    /// assert_eq!(Syllable::from('か').to_large(), Syllable::from('か'));
+    /// ```
+    pub fn to_large(&self) -> Self {
+        let large = match self.to_hiragana().get_char() {
+            'っ' => 'つ',
+            'ゃ' => 'や',
+            'ゅ' => 'ゆ',
+            'ょ' => 'よ',
+            _ => return *self,
+        };
+
+        self.with_script(large)
+    }
+
+    /// Returns `c` as [`Syllable`] written in the same script as [`self`]
+    fn with_script(&self, c: char) -> Self {
+        match self.is_katakana() {
+            true => Self(c).to_katakana(),
+            false => Self(c),
+        }
+    }
+
+    /// Returns `true` if the syllable is a katakana character
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::syllable::Syllable;
+    ///
+    /// assert!(Syllable::from('カ').is_katakana());
+    /// assert!(!Syllable::from('か').is_katakana());
+    /// ```
+    pub fn is_katakana(&self) -> bool {
+        ('\u{30A1}'..='\u{30F6}').contains(&self.0)
+    }
+
+    /// Returns `true` if the syllable is a hiragana character
+    pub fn is_hiragana(&self) -> bool {
+        ('\u{3041}'..='\u{3096}').contains(&self.0)
+    }
+
+    /// Returns the hiragana equivalent of a katakana syllable. Other characters are returned
+    /// unchanged
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::syllable::Syllable;
+    ///
+    /// assert_eq!(Syllable::from('カ').to_hiragana(), Syllable::from('か'));
+    /// assert_eq!(Syllable::from('か').to_hiragana(), Syllable::from('か'));
+    /// ```
+    pub fn to_hiragana(&self) -> Self {
+        if !self.is_katakana() {
+            return *self;
+        }
+
+        std::char::from_u32(self.0 as u32 - KANA_OFFSET)
+            .map(Self)
+            .unwrap_or(*self)
+    }
+
+    /// Returns the katakana equivalent of a hiragana syllable. Other characters are returned
+    /// unchanged
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::syllable::Syllable;
+    ///
+    /// assert_eq!(Syllable::from('か').to_katakana(), Syllable::from('カ'));
+    /// ```
+    pub fn to_katakana(&self) -> Self {
+        if !self.is_hiragana() {
+            return *self;
+        }
+
+        std::char::from_u32(self.0 as u32 + KANA_OFFSET)
+            .map(Self)
+            .unwrap_or(*self)
+    }
+
     /// Returns the charactor hold by [`self`]
     pub fn get_char(&self) -> char {
         self.0
@@ -0,0 +1,97 @@
+use crate::{verb::Verb, word::PoliteLevel, Word};
+
+/// All common forms of a verb at once. Forms the verb can't be conjugated into are `None`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConjugationTable {
+    pub dictionary: Option<Word>,
+    pub dictionary_polite: Option<Word>,
+    pub negative: Option<Word>,
+    pub negative_polite: Option<Word>,
+    pub past: Option<Word>,
+    pub past_polite: Option<Word>,
+    pub negative_past: Option<Word>,
+    pub negative_past_polite: Option<Word>,
+    pub te: Option<Word>,
+    pub negative_te: Option<Word>,
+    pub potential: Option<Word>,
+    pub potential_polite: Option<Word>,
+    pub negative_potential: Option<Word>,
+    pub passive: Option<Word>,
+    pub causative: Option<Word>,
+    pub causative_passive: Option<Word>,
+    pub imperative: Option<Word>,
+    pub negative_imperative: Option<Word>,
+    pub volitional: Option<Word>,
+    pub volitional_polite: Option<Word>,
+    pub tara: Option<Word>,
+    pub ba: Option<Word>,
+    pub desiderative: Option<Word>,
+    pub honorific: Option<Word>,
+    pub humble: Option<Word>,
+}
+
+impl ConjugationTable {
+    /// Creates a new [`ConjugationTable`] by conjugating `verb` into all forms
+    pub fn new(verb: &Verb) -> Self {
+        Self {
+            dictionary: verb.dictionary(PoliteLevel::Plain).ok(),
+            dictionary_polite: verb.dictionary(PoliteLevel::Polite).ok(),
+            negative: verb.negative(PoliteLevel::Plain).ok(),
+            negative_polite: verb.negative(PoliteLevel::Polite).ok(),
+            past: verb.past(PoliteLevel::Plain).ok(),
+            past_polite: verb.past(PoliteLevel::Polite).ok(),
+            negative_past: verb.negative_past(PoliteLevel::Plain).ok(),
+            negative_past_polite: verb.negative_past(PoliteLevel::Polite).ok(),
+            te: verb.te_form().ok(),
+            negative_te: verb.negative_te_form().ok(),
+            potential: verb.potential(PoliteLevel::Plain).ok(),
+            potential_polite: verb.potential(PoliteLevel::Polite).ok(),
+            negative_potential: verb.negative_potential(PoliteLevel::Plain).ok(),
+            passive: verb.passive(PoliteLevel::Plain).ok(),
+            causative: verb.causative(PoliteLevel::Plain).ok(),
+            causative_passive: verb.causative_passive(PoliteLevel::Plain).ok(),
+            imperative: verb.imperative().ok(),
+            negative_imperative: verb.imperative_negative().ok(),
+            volitional: verb.volitional(PoliteLevel::Plain).ok(),
+            volitional_polite: verb.volitional(PoliteLevel::Polite).ok(),
+            tara: verb.tara().ok(),
+            ba: verb.ba().ok(),
+            desiderative: verb.desiderative().ok(),
+            honorific: verb.honorific(PoliteLevel::Plain).ok(),
+            humble: verb.humble(PoliteLevel::Plain).ok(),
+        }
+    }
+
+    /// Returns an iterator over all available forms as (label, word) pairs
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &Word)> {
+        vec![
+            ("dictionary", &self.dictionary),
+            ("dictionary polite", &self.dictionary_polite),
+            ("negative", &self.negative),
+            ("negative polite", &self.negative_polite),
+            ("past", &self.past),
+            ("past polite", &self.past_polite),
+            ("negative past", &self.negative_past),
+            ("negative past polite", &self.negative_past_polite),
+            ("te", &self.te),
+            ("negative te", &self.negative_te),
+            ("potential", &self.potential),
+            ("potential polite", &self.potential_polite),
+            ("negative potential", &self.negative_potential),
+            ("passive", &self.passive),
+            ("causative", &self.causative),
+            ("causative passive", &self.causative_passive),
+            ("imperative", &self.imperative),
+            ("negative imperative", &self.negative_imperative),
+            ("volitional", &self.volitional),
+            ("volitional polite", &self.volitional_polite),
+            ("tara", &self.tara),
+            ("ba", &self.ba),
+            ("desiderative", &self.desiderative),
+            ("honorific", &self.honorific),
+            ("humble", &self.humble),
+        ]
+        .into_iter()
+        .filter_map(|(label, word)| Some((label, word.as_ref()?)))
+    }
+}
@@ -0,0 +1,210 @@
+use crate::{Verb, VerbType, Word};
+
+/// A verb in dictionary form as (kana, kanji, verb type)
+type Entry = (&'static str, &'static str, VerbType);
+
+/// Transitive (他動詞) and intransitive (自動詞) verb pairs, the transitive verb first
+const PAIRS: &[(Entry, Entry)] = &[
+    (
+        ("あける", "開ける", VerbType::Ichidan),
+        ("あく", "開く", VerbType::Godan),
+    ),
+    (
+        ("しめる", "閉める", VerbType::Ichidan),
+        ("しまる", "閉まる", VerbType::Godan),
+    ),
+    (
+        ("はじめる", "始める", VerbType::Ichidan),
+        ("はじまる", "始まる", VerbType::Godan),
+    ),
+    (
+        ("おえる", "終える", VerbType::Ichidan),
+        ("おわる", "終わる", VerbType::Godan),
+    ),
+    (
+        ("つける", "付ける", VerbType::Ichidan),
+        ("つく", "付く", VerbType::Godan),
+    ),
+    (
+        ("けす", "消す", VerbType::Godan),
+        ("きえる", "消える", VerbType::Ichidan),
+    ),
+    (
+        ("いれる", "入れる", VerbType::Ichidan),
+        ("はいる", "入る", VerbType::Godan),
+    ),
+    (
+        ("だす", "出す", VerbType::Godan),
+        ("でる", "出る", VerbType::Ichidan),
+    ),
+    (
+        ("おとす", "落とす", VerbType::Godan),
+        ("おちる", "落ちる", VerbType::Ichidan),
+    ),
+    (
+        ("こわす", "壊す", VerbType::Godan),
+        ("こわれる", "壊れる", VerbType::Ichidan),
+    ),
+    (
+        ("なおす", "直す", VerbType::Godan),
+        ("なおる", "直る", VerbType::Godan),
+    ),
+    (
+        ("あげる", "上げる", VerbType::Ichidan),
+        ("あがる", "上がる", VerbType::Godan),
+    ),
+    (
+        ("さげる", "下げる", VerbType::Ichidan),
+        ("さがる", "下がる", VerbType::Godan),
+    ),
+    (
+        ("とめる", "止める", VerbType::Ichidan),
+        ("とまる", "止まる", VerbType::Godan),
+    ),
+    (
+        ("きめる", "決める", VerbType::Ichidan),
+        ("きまる", "決まる", VerbType::Godan),
+    ),
+    (
+        ("あつめる", "集める", VerbType::Ichidan),
+        ("あつまる", "集まる", VerbType::Godan),
+    ),
+    (
+        ("かける", "掛ける", VerbType::Ichidan),
+        ("かかる", "掛かる", VerbType::Godan),
+    ),
+    (
+        ("おこす", "起こす", VerbType::Godan),
+        ("おきる", "起きる", VerbType::Ichidan),
+    ),
+    (
+        ("のこす", "残す", VerbType::Godan),
+        ("のこる", "残る", VerbType::Godan),
+    ),
+    (
+        ("うごかす", "動かす", VerbType::Godan),
+        ("うごく", "動く", VerbType::Godan),
+    ),
+    (
+        ("わる", "割る", VerbType::Godan),
+        ("われる", "割れる", VerbType::Ichidan),
+    ),
+    (
+        ("きる", "切る", VerbType::Godan),
+        ("きれる", "切れる", VerbType::Ichidan),
+    ),
+    (
+        ("やく", "焼く", VerbType::Godan),
+        ("やける", "焼ける", VerbType::Ichidan),
+    ),
+    (
+        ("まわす", "回す", VerbType::Godan),
+        ("まわる", "回る", VerbType::Godan),
+    ),
+    (
+        ("わたす", "渡す", VerbType::Godan),
+        ("わたる", "渡る", VerbType::Godan),
+    ),
+    (
+        ("ならべる", "並べる", VerbType::Ichidan),
+        ("ならぶ", "並ぶ", VerbType::Godan),
+    ),
+    (
+        ("たてる", "立てる", VerbType::Ichidan),
+        ("たつ", "立つ", VerbType::Godan),
+    ),
+    (
+        ("かえす", "返す", VerbType::Godan),
+        ("かえる", "返る", VerbType::Godan),
+    ),
+    (
+        ("ふやす", "増やす", VerbType::Godan),
+        ("ふえる", "増える", VerbType::Ichidan),
+    ),
+    (
+        ("へらす", "減らす", VerbType::Godan),
+        ("へる", "減る", VerbType::Godan),
+    ),
+    (
+        ("ながす", "流す", VerbType::Godan),
+        ("ながれる", "流れる", VerbType::Ichidan),
+    ),
+    (
+        ("つづける", "続ける", VerbType::Ichidan),
+        ("つづく", "続く", VerbType::Godan),
+    ),
+    (
+        ("みつける", "見つける", VerbType::Ichidan),
+        ("みつかる", "見つかる", VerbType::Godan),
+    ),
+    (
+        ("わかす", "沸かす", VerbType::Godan),
+        ("わく", "沸く", VerbType::Godan),
+    ),
+];
+
+/// Returns `true` if `verb` is the verb described by `entry`. Verbs without a kanji reading are
+/// matched by their kana reading and verb type only
+fn matches(verb: &Verb, entry: &Entry) -> bool {
+    let (kana, kanji, verb_type) = *entry;
+    if verb.kana != kana || verb.verb_type() != verb_type {
+        return false;
+    }
+
+    match verb.kanji {
+        Some(ref word_kanji) => word_kanji == kanji || word_kanji == kana,
+        None => true,
+    }
+}
+
+/// Returns the transitive/intransitive counterpart of `verb` in dictionary form (開ける -> 開く,
+/// 始まる -> 始める) or `None` if the verb isn't known
+///
+/// # Example
+/// ```
+/// use jp_inflections::{transitivity, VerbType, Word};
+///
+/// let verb = Word::new("あける", Some("開ける")).into_verb(VerbType::Ichidan).unwrap();
+/// let pair = transitivity::pair_of(&verb).unwrap();
+/// assert_eq!(pair.word.kanji.as_deref(), Some("開く"));
+/// assert_eq!(pair.verb_type(), VerbType::Godan);
+/// ```
+pub fn pair_of(verb: &Verb) -> Option<Verb> {
+    let (kana, kanji, verb_type) = PAIRS.iter().find_map(|(transitive, intransitive)| {
+        if matches(verb, transitive) {
+            Some(*intransitive)
+        } else if matches(verb, intransitive) {
+            Some(*transitive)
+        } else {
+            None
+        }
+    })?;
+
+    let kanji = verb.kanji.as_ref().map(|_| kanji);
+    Some(Verb::new(Word::new(kana, kanji), verb_type))
+}
+
+/// Returns `Some(true)` if `verb` is a known transitive verb, `Some(false)` if it's a known
+/// intransitive verb and `None` otherwise
+///
+/// # Example
+/// ```
+/// use jp_inflections::{transitivity, VerbType, Word};
+///
+/// let verb = Word::new("はじまる", Some("始まる")).into_verb(VerbType::Godan).unwrap();
+/// assert_eq!(transitivity::is_transitive(&verb), Some(false));
+///
+/// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+/// assert_eq!(transitivity::is_transitive(&verb), None);
+/// ```
+pub fn is_transitive(verb: &Verb) -> Option<bool> {
+    PAIRS.iter().find_map(|(transitive, intransitive)| {
+        if matches(verb, transitive) {
+            Some(true)
+        } else if matches(verb, intransitive) {
+            Some(false)
+        } else {
+            None
+        }
+    })
+}
@@ -1,18 +1,49 @@
 use crate::{
+    attributive::Attributive,
+    builder::VerbBuilder,
     error::Error,
     inflection::Inflection,
-    special_verbs::{kuru::SpecialKuru, SpecialVerb},
+    special_verbs::{
+        aru::SpecialAru, iku::SpecialIku, kureru::SpecialKureru, kuru::SpecialKuru,
+        suru::SpecialSuru, tou::SpecialTou, SpecialVerb,
+    },
     syllable::Syllable,
-    word::WordForm,
+    table::ConjugationTable,
+    umlaut::Umlaut,
+    word::PoliteLevel,
     JapaneseResult, Word,
 };
-use std::ops::Deref;
+use std::{ops::Deref, str::FromStr};
+
+/// Kana and kanji spellings of the 5 polite verbs which use an い stem (なさいます)
+const POLITE_VERBS: &[(&str, &str)] = &[
+    ("いらっしゃる", "いらっしゃる"),
+    ("いらっしゃる", "居らっしゃる"),
+    ("おっしゃる", "仰る"),
+    ("くださる", "下さる"),
+    ("ござる", "御座る"),
+    ("なさる", "為さる"),
+];
 
 /// Represents a Japanese verb
+///
+/// The literary reading うる of 得る is marked by passing its kana reading with
+/// [`VerbType::Ichidan`]. Only the dictionary form keeps うる, all other forms are conjugated like
+/// える (得ない, 得ます)
+///
+/// # Example
+/// ```
+/// use jp_inflections::{PoliteLevel, VerbType, Word};
+///
+/// let verb = Word::new("ありうる", Some("有り得る")).into_verb(VerbType::Ichidan).unwrap();
+/// assert_eq!(verb.dictionary(PoliteLevel::Plain).unwrap().kana, String::from("ありうる"));
+/// assert_eq!(verb.negative(PoliteLevel::Plain).unwrap().kana, String::from("ありえない"));
+/// ```
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Verb {
     pub word: Word,
-    pub verb_type: VerbType,
+    verb_type: VerbType,
 }
 
 impl Deref for Verb {
@@ -26,6 +57,7 @@ impl Deref for Verb {
 
 /// Represents a type of verb
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VerbType {
     Godan,
     Ichidan,
@@ -33,6 +65,44 @@ pub enum VerbType {
     Exception,
 }
 
+/// The negative forms which connect a verb to the following clause
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NegativeConnective {
+    /// 食べなくて ("not ... and", "because ... not")
+    Nakute,
+    /// 食べないで ("without", requests)
+    Naide,
+    /// 食べずに, the written form of ないで
+    Zuni,
+    /// 食べませんで, the rare polite form of なくて
+    Masende,
+}
+
+impl FromStr for VerbType {
+    type Err = Error;
+
+    /// Parses a verb type case-insensitively. Accepts the names of the variants, "irregular" and
+    /// the "う"/"る" shorthands
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::VerbType;
+    ///
+    /// assert_eq!("Godan".parse(), Ok(VerbType::Godan));
+    /// assert_eq!("る".parse(), Ok(VerbType::Ichidan));
+    /// assert_eq!("irregular".parse(), Ok(VerbType::Exception));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "godan" | "う" => Ok(VerbType::Godan),
+            "ichidan" | "る" => Ok(VerbType::Ichidan),
+            "exception" | "irregular" => Ok(VerbType::Exception),
+            _ => Err(Error::InvalidVerbType),
+        }
+    }
+}
+
 impl Verb {
     /// Returns a new verb
     #[inline]
@@ -40,6 +110,95 @@ impl Verb {
         Self { word, verb_type }
     }
 
+    /// Returns the [`VerbType`] the verb gets conjugated as
+    #[inline]
+    pub fn verb_type(&self) -> VerbType {
+        self.verb_type
+    }
+
+    /// Sets the [`VerbType`] the verb gets conjugated as without checking whether the verb can be
+    /// of that type. Use [`Verb::try_set_verb_type`] to reject obviously wrong types
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{VerbType, Word};
+    ///
+    /// let mut verb = Word::new("かえる", Some("帰る")).into_verb_auto().unwrap();
+    /// verb.set_verb_type(VerbType::Ichidan);
+    /// assert_eq!(verb.verb_type(), VerbType::Ichidan);
+    /// ```
+    #[inline]
+    pub fn set_verb_type(&mut self, verb_type: VerbType) {
+        self.verb_type = verb_type;
+    }
+
+    /// Sets the [`VerbType`] the verb gets conjugated as if the ending of the verb allows it.
+    /// Ichidan verbs have to end in える/いる or be the literary 得る(うる), exceptions in する/来る
+    /// and godan verbs in any other u-row kana. Returns `Error::IncompatibleVerbType` and keeps the current type otherwise
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{error::Error, VerbType, Word};
+    ///
+    /// let mut verb = Word::new("べんきょうする", Some("勉強する")).into_verb_auto().unwrap();
+    /// assert_eq!(verb.try_set_verb_type(VerbType::Godan), Err(Error::IncompatibleVerbType));
+    /// assert_eq!(verb.verb_type(), VerbType::Exception);
+    ///
+    /// let mut verb = Word::new("きる", None).into_verb(VerbType::Godan).unwrap();
+    /// assert!(verb.try_set_verb_type(VerbType::Ichidan).is_ok());
+    /// ```
+    pub fn try_set_verb_type(&mut self, verb_type: VerbType) -> JapaneseResult<()> {
+        if !self.allows_verb_type(verb_type) {
+            return Err(Error::IncompatibleVerbType);
+        }
+
+        self.verb_type = verb_type;
+        Ok(())
+    }
+
+    /// Returns a new verb from a dictionary string and its reading. If `reading` is `None`,
+    /// `text` gets parsed using [`Word::from_notation`], so both 習う(ならう) and a kana only
+    /// たべる are accepted
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Verb, VerbType, Word};
+    ///
+    /// let verb = Verb::from_str_pair("習う", Some("ならう"), VerbType::Godan).unwrap();
+    /// assert_eq!(verb.word, Word::new("ならう", Some("習う")));
+    ///
+    /// let verb = Verb::from_str_pair("食べる(たべる)", None, VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.word, Word::new("たべる", Some("食べる")));
+    ///
+    /// assert!(Verb::from_str_pair("食べる", None, VerbType::Ichidan).is_err());
+    /// ```
+    pub fn from_str_pair(
+        text: &str,
+        reading: Option<&str>,
+        verb_type: VerbType,
+    ) -> JapaneseResult<Verb> {
+        let word = match reading {
+            Some(reading) => Word::new(reading, (text != reading).then_some(text)),
+            None => Word::from_notation(text)?,
+        };
+
+        word.into_verb(verb_type)
+    }
+
+    /// Returns a [`VerbBuilder`] to construct a verb
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::Verb;
+    ///
+    /// let verb = Verb::builder().kana("ならう").kanji("習う").build().unwrap();
+    /// assert_eq!(verb.te_form().unwrap().kanji.unwrap(), String::from("習って"));
+    /// ```
+    #[inline]
+    pub fn builder() -> VerbBuilder {
+        VerbBuilder::new()
+    }
+
     /// Same as Word::get_reading(&self)
     #[inline]
     pub fn get_reading(&self) -> String {
@@ -64,39 +223,231 @@ impl Verb {
     ///
     /// # Example
     /// ```
-    /// use jp_inflections::{Word, VerbType, WordForm};
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
     ///
     /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
-    /// assert_eq!(verb.get_stem(WordForm::Long).unwrap().kana, String::from("ならい"));
-    /// assert_eq!(verb.get_stem(WordForm::Long).unwrap().kanji.unwrap(), String::from("習い"));
+    /// assert_eq!(verb.get_stem(PoliteLevel::Polite).unwrap().kana, String::from("ならい"));
+    /// assert_eq!(verb.get_stem(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("習い"));
     ///
-    /// assert_eq!(verb.get_stem(WordForm::Short).unwrap().kana, String::from("ならわ"));
-    /// assert_eq!(verb.get_stem(WordForm::Short).unwrap().kanji.unwrap(), String::from("習わ"));
+    /// assert_eq!(verb.get_stem(PoliteLevel::Plain).unwrap().kana, String::from("ならわ"));
+    /// assert_eq!(verb.get_stem(PoliteLevel::Plain).unwrap().kanji.unwrap(), String::from("習わ"));
     /// ```
-    pub fn get_stem(&self, form: WordForm) -> JapaneseResult<Word> {
+    pub fn get_stem(&self, form: PoliteLevel) -> JapaneseResult<Word> {
         match form {
-            WordForm::Short => self.nai_stem(),
-            WordForm::Long => self.stem_long(),
+            PoliteLevel::Plain => self
+                .negative_stem()
+                .map(|i| self.inflect(i, &[Inflection::Stem])),
+            PoliteLevel::Polite => self
+                .masu_stem()
+                .map(|i| self.inflect(i, &[Inflection::Stem])),
+        }
+    }
+
+    /// Returns the attributive form (連体形) used in front of nouns, which is the plain dictionary
+    /// form for verbs (習う人)
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType};
+    ///
+    /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.attributive().unwrap().kanji.unwrap(), String::from("習う"));
+    /// ```
+    #[inline]
+    pub fn attributive(&self) -> JapaneseResult<Word> {
+        self.dictionary(PoliteLevel::Plain)
+    }
+
+    /// Returns the continuative form (連用中止形) used to connect clauses in writing (朝起き、顔を洗う).
+    /// This is the ます stem, except for the polite verbs which keep their り (なさり)
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType};
+    ///
+    /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.continuative().unwrap().kanji.unwrap(), String::from("習い"));
+    ///
+    /// let verb = Word::new("なさる", Some("為さる")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.continuative().unwrap().kanji.unwrap(), String::from("為さり"));
+    /// ```
+    pub fn continuative(&self) -> JapaneseResult<Word> {
+        let stem = match self.is_polite() {
+            true => self.map_ending(&[('る', 'り')])?,
+            false => self.masu_stem()?,
+        };
+        Ok(self.inflect(stem, &[Inflection::Stem]))
+    }
+
+    /// Joins the continuative forms of `verbs` with 、 and appends `clause`
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Verb, Word, VerbType};
+    ///
+    /// let verbs = [
+    ///     Word::new("あさおきる", Some("朝起きる")).into_verb(VerbType::Ichidan).unwrap(),
+    ///     Word::new("かおをあらう", Some("顔を洗う")).into_verb(VerbType::Godan).unwrap(),
+    /// ];
+    /// let sentence = Verb::join_continuative(&verbs, &Word::new("でかけた", Some("出かけた"))).unwrap();
+    /// assert_eq!(sentence.kana, String::from("あさおき、かおをあらい、でかけた"));
+    /// assert_eq!(sentence.kanji.unwrap(), String::from("朝起き、顔を洗い、出かけた"));
+    /// ```
+    pub fn join_continuative(verbs: &[Verb], clause: &Word) -> JapaneseResult<Word> {
+        let mut parts = verbs
+            .iter()
+            .map(|i| i.continuative())
+            .collect::<JapaneseResult<Vec<_>>>()?;
+        parts.push(clause.clone());
+
+        let has_kanji = parts.iter().any(|i| i.kanji.is_some());
+        let join = |f: fn(&Word) -> String| parts.iter().map(f).collect::<Vec<_>>().join("、");
+
+        Ok(Word {
+            kana: join(|i| i.kana.clone()),
+            kanji: has_kanji.then(|| join(|i| i.get_reading())),
+            inflections: Vec::new(),
+        })
+    }
+
+    /// Conjugates the verb into the given [`Inflection`]. Inflections which don't have a short and
+    /// long form ignore `form`. `Obligation` and `Prohibition` return the uncontracted forms
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Inflection, Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.conjugate(Inflection::Negative, PoliteLevel::Plain).unwrap().kana, String::from("ならわない"));
+    /// assert_eq!(verb.conjugate(Inflection::Te, PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("習って"));
+    /// ```
+    pub fn conjugate(&self, inflection: Inflection, form: PoliteLevel) -> JapaneseResult<Word> {
+        match inflection {
+            Inflection::Stem => self.get_stem(form),
+            Inflection::StemPotential => self
+                .potential_stem()
+                .map(|i| self.inflect(i, &[Inflection::StemPotential])),
+            Inflection::StemBa => self
+                .ba_stem()
+                .map(|i| self.inflect(i, &[Inflection::StemBa])),
+            Inflection::Positive | Inflection::Present => self.dictionary(form),
+            Inflection::Polite => self.dictionary(PoliteLevel::Polite),
+            Inflection::Negative => self.negative(form),
+            Inflection::Past => self.past(form),
+            Inflection::Te => self.te_form(),
+            Inflection::Passive => self.passive(form),
+            Inflection::Causative => self.causative(form),
+            Inflection::CausativePassive => self.causative_passive(form),
+            Inflection::Imperative => self.imperative(),
+            Inflection::Potential => self.potential(form),
+            Inflection::Volitional => self.volitional(form),
+            Inflection::Tara => self.tara(),
+            Inflection::Ba => self.ba(),
+            Inflection::Desiderative => self.desiderative(),
+            Inflection::Zu => self.zu(),
+            Inflection::Progressive => self.progressive(form),
+            Inflection::Shimau => self.shimau(form),
+            Inflection::Teoku => self.teoku(form),
+            Inflection::Honorific => self.honorific(form),
+            Inflection::Humble => self.humble(form),
+            Inflection::Presumptive => self.presumptive(form),
+            Inflection::Explanatory => self.explanatory(form, false),
+            Inflection::Excessive => self.sugiru(form),
+            Inflection::Appearance => self.sou_appearance(),
+            Inflection::Hearsay => self.sou_hearsay(),
+            Inflection::Nasai => self.command_nasai(),
+            Inflection::To => self.conditional_to(),
+            Inflection::Nara => self.conditional_nara(),
+            Inflection::Attempt => self.volitional_to_suru(),
+            Inflection::Intention => self.volitional_to_omou(),
+            Inflection::KansaiNegative => self.negative_kansai(),
+            Inflection::Nu => self.negative_nu(),
+            Inflection::Zaru => self.negative_zaru(),
+            Inflection::Zuni => self.zuni(),
+            Inflection::Obligation => self.must(false),
+            Inflection::Prohibition => self.must_not(false),
+            Inflection::Permission => self.temo_ii(),
+            Inflection::PotentialKoto => self.potential_koto(form),
+            Inflection::PotentialUru => self.potential_uru(),
+            Inflection::Naidesu => self.negative_naidesu(),
+            Inflection::Naide => self.naide(),
+            Inflection::Invitation => self.polite_negative_question(),
+            Inflection::Yasui => self.yasui(),
+            Inflection::Nikui => self.nikui(),
+            Inflection::DesiderativeOther => self.desiderative_other(form),
+            Inflection::TewaContracted => self.tewa_contracted(),
         }
     }
 
+    /// Conjugates the verb like [`Verb::conjugate`] but only returns the kana reading. The kanji
+    /// reading is still used to tell apart verbs sharing the same kana reading
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Inflection, Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.conjugate_kana(Inflection::Past, PoliteLevel::Polite).unwrap(), "ならいました");
+    /// ```
+    pub fn conjugate_kana(
+        &self,
+        inflection: Inflection,
+        form: PoliteLevel,
+    ) -> JapaneseResult<String> {
+        self.conjugate(inflection, form).map(|i| i.kana)
+    }
+
+    /// Conjugates the verb like [`Verb::conjugate`] but returns the kana and kanji reading only
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Inflection, Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
+    /// let (kana, kanji) = verb.conjugate_pair(Inflection::Past, PoliteLevel::Plain).unwrap();
+    /// assert_eq!(kana, "ならった");
+    /// assert_eq!(kanji.as_deref(), Some("習った"));
+    /// ```
+    pub fn conjugate_pair(
+        &self,
+        inflection: Inflection,
+        form: PoliteLevel,
+    ) -> JapaneseResult<(String, Option<String>)> {
+        self.conjugate(inflection, form).map(Word::into_pair)
+    }
+
+    /// Returns a [`ConjugationTable`] holding all common forms of the verb
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType};
+    ///
+    /// let verb = Word::new("する", None).into_verb(VerbType::Exception).unwrap();
+    /// let table = verb.conjugation_table();
+    /// assert_eq!(table.past.unwrap().kana, String::from("した"));
+    /// assert!(table.honorific.is_none());
+    /// ```
+    pub fn conjugation_table(&self) -> ConjugationTable {
+        ConjugationTable::new(self)
+    }
+
     /// Returns the dictionary form of a word
     ///
     /// # Example
     /// ```
-    /// use jp_inflections::{Word, VerbType, WordForm};
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
     ///
     /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
-    /// assert_eq!(verb.dictionary(WordForm::Long).unwrap().kana, String::from("ならいます"));
-    /// assert_eq!(verb.dictionary(WordForm::Long).unwrap().kanji.unwrap(), String::from("習います"));
+    /// assert_eq!(verb.dictionary(PoliteLevel::Polite).unwrap().kana, String::from("ならいます"));
+    /// assert_eq!(verb.dictionary(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("習います"));
     ///
-    /// assert_eq!(verb.dictionary(WordForm::Short).unwrap().kana, String::from("ならう"));
-    /// assert_eq!(verb.dictionary(WordForm::Short).unwrap().kanji.unwrap(), String::from("習う"));
+    /// assert_eq!(verb.dictionary(PoliteLevel::Plain).unwrap().kana, String::from("ならう"));
+    /// assert_eq!(verb.dictionary(PoliteLevel::Plain).unwrap().kanji.unwrap(), String::from("習う"));
     /// ```
-    pub fn dictionary(&self, form: WordForm) -> JapaneseResult<Word> {
+    pub fn dictionary(&self, form: PoliteLevel) -> JapaneseResult<Word> {
         Ok(match form {
-            WordForm::Short => self.word.clone(),
-            WordForm::Long => self.dictionary_polite()?,
+            PoliteLevel::Plain => self.inflect(self.word.clone(), &[]),
+            PoliteLevel::Polite => self.inflect(self.dictionary_polite()?, &[Inflection::Polite]),
         })
     }
 
@@ -104,45 +455,67 @@ impl Verb {
     ///
     /// # Example
     /// ```
-    /// use jp_inflections::{Word, VerbType, WordForm};
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
     ///
     /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
-    /// assert_eq!(verb.negative(WordForm::Short).unwrap().kana, String::from("ならわない"));
-    /// assert_eq!(verb.negative(WordForm::Short).unwrap().kanji.unwrap(), String::from("習わない"));
+    /// assert_eq!(verb.negative(PoliteLevel::Plain).unwrap().kana, String::from("ならわない"));
+    /// assert_eq!(verb.negative(PoliteLevel::Plain).unwrap().kanji.unwrap(), String::from("習わない"));
     ///
-    /// assert_eq!(verb.negative(WordForm::Long).unwrap().kana, String::from("ならいません"));
-    /// assert_eq!(verb.negative(WordForm::Long).unwrap().kanji.unwrap(), String::from("習いません"));
+    /// assert_eq!(verb.negative(PoliteLevel::Polite).unwrap().kana, String::from("ならいません"));
+    /// assert_eq!(verb.negative(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("習いません"));
     /// ```
-    pub fn negative(&self, form: WordForm) -> JapaneseResult<Word> {
+    pub fn negative(&self, form: PoliteLevel) -> JapaneseResult<Word> {
         match form {
-            WordForm::Short => self.negative_short(),
-            WordForm::Long => self.negative_long(),
+            PoliteLevel::Plain => self
+                .negative_short()
+                .map(|i| self.inflect(i, &[Inflection::Negative])),
+            PoliteLevel::Polite => self
+                .negative_long()
+                .map(|i| self.inflect(i, &[Inflection::Polite, Inflection::Negative])),
         }
     }
 
+    /// Returns the verb in the colloquial polite negative form ないです, the short negative followed
+    /// by です, as an alternative to ません
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.negative_naidesu().unwrap().kana, String::from("たべないです"));
+    /// assert_eq!(verb.negative_naidesu().unwrap().kanji.unwrap(), String::from("食べないです"));
+    /// ```
+    pub fn negative_naidesu(&self) -> JapaneseResult<Word> {
+        let mut negative = self.negative_short()?;
+        negative.push_str("です");
+        Ok(self.inflect(negative, &[Inflection::Naidesu]))
+    }
+
     /// Returns the verb in its て form.
     ///
     /// # Example
     /// ```
-    /// use jp_inflections::{Word, VerbType, WordForm};
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
     ///
     /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
     /// assert_eq!(verb.te_form().unwrap().kana, String::from("ならって"));
     /// assert_eq!(verb.te_form().unwrap().kanji.unwrap(), String::from("習って"));
     /// ```
     pub fn te_form(&self) -> JapaneseResult<Word> {
-        if self.word.kana == "いらっしゃる" {
-            return Ok(Word::new("いらして", None));
-        }
+        let te_form = match self.word.kana.as_str() {
+            "いらっしゃる" => Word::new("いらして", None),
+            _ => self.te_rule(Syllable::from('て'))?,
+        };
 
-        self.te_rule(Syllable::from('て'))
+        Ok(self.inflect(te_form, &[Inflection::Te]))
     }
 
     /// Returns the verb in its negative て form.
     ///
     /// # Example
     /// ```
-    /// use jp_inflections::{Word, VerbType, WordForm};
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
     ///
     /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
     /// assert_eq!(verb.negative_te_form().unwrap().kana, String::from("ならわなくて"));
@@ -151,26 +524,75 @@ impl Verb {
     pub fn negative_te_form(&self) -> JapaneseResult<Word> {
         let mut negated_short = self.negative_short()?.strip_end(1);
         negated_short.push_str("くて");
-        Ok(negated_short)
+        Ok(self.inflect(negated_short, &[Inflection::Negative, Inflection::Te]))
+    }
+
+    /// Returns the verb in the ないで form, the short negative followed by で. Unlike the なくて
+    /// form it's used for requests (食べないでください) and for "without doing"
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType};
+    ///
+    /// let verb = Word::new("いく", Some("行く")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.naide().unwrap().kana, String::from("いかないで"));
+    /// assert_eq!(verb.naide().unwrap().kanji.unwrap(), String::from("行かないで"));
+    /// ```
+    pub fn naide(&self) -> JapaneseResult<Word> {
+        let mut negative = self.negative_short()?;
+        negative.push('で');
+        Ok(self.inflect(negative, &[Inflection::Naide]))
+    }
+
+    /// Returns the verb in the negative connective form given by `style`
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{NegativeConnective, Word, VerbType};
+    ///
+    /// let verb = Word::new("いく", Some("行く")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.negative_connective(NegativeConnective::Nakute).unwrap().kanji.unwrap(), String::from("行かなくて"));
+    /// assert_eq!(verb.negative_connective(NegativeConnective::Naide).unwrap().kanji.unwrap(), String::from("行かないで"));
+    /// assert_eq!(verb.negative_connective(NegativeConnective::Zuni).unwrap().kanji.unwrap(), String::from("行かずに"));
+    /// assert_eq!(verb.negative_connective(NegativeConnective::Masende).unwrap().kanji.unwrap(), String::from("行きませんで"));
+    /// ```
+    pub fn negative_connective(&self, style: NegativeConnective) -> JapaneseResult<Word> {
+        match style {
+            NegativeConnective::Nakute => self.negative_te_form(),
+            NegativeConnective::Naide => self.naide(),
+            NegativeConnective::Zuni => self.zuni(),
+            NegativeConnective::Masende => {
+                let mut stem = self.masu_stem()?;
+                stem.push_str("ませんで");
+                Ok(self.inflect(
+                    stem,
+                    &[Inflection::Polite, Inflection::Negative, Inflection::Te],
+                ))
+            }
+        }
     }
 
     /// Returns the verb in the past form
     ///
     /// # Example
     /// ```
-    /// use jp_inflections::{Word, VerbType, WordForm};
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
     ///
     /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
-    /// assert_eq!(verb.past(WordForm::Short).unwrap().kana, String::from("ならった"));
-    /// assert_eq!(verb.past(WordForm::Short).unwrap().kanji.unwrap(), String::from("習った"));
+    /// assert_eq!(verb.past(PoliteLevel::Plain).unwrap().kana, String::from("ならった"));
+    /// assert_eq!(verb.past(PoliteLevel::Plain).unwrap().kanji.unwrap(), String::from("習った"));
     ///
-    /// assert_eq!(verb.past(WordForm::Long).unwrap().kana, String::from("ならいました"));
-    /// assert_eq!(verb.past(WordForm::Long).unwrap().kanji.unwrap(), String::from("習いました"));
+    /// assert_eq!(verb.past(PoliteLevel::Polite).unwrap().kana, String::from("ならいました"));
+    /// assert_eq!(verb.past(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("習いました"));
     /// ```
-    pub fn past(&self, form: WordForm) -> JapaneseResult<Word> {
+    pub fn past(&self, form: PoliteLevel) -> JapaneseResult<Word> {
         match form {
-            WordForm::Short => self.past_short(),
-            WordForm::Long => self.past_long(),
+            PoliteLevel::Plain => self
+                .past_short()
+                .map(|i| self.inflect(i, &[Inflection::Past])),
+            PoliteLevel::Polite => self
+                .past_long()
+                .map(|i| self.inflect(i, &[Inflection::Polite, Inflection::Past])),
         }
     }
 
@@ -178,39 +600,133 @@ impl Verb {
     ///
     /// # Example
     /// ```
-    /// use jp_inflections::{Word, VerbType, WordForm};
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
     ///
     /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
-    /// assert_eq!(verb.negative_past(WordForm::Short).unwrap().kana, String::from("ならわなかった"));
-    /// assert_eq!(verb.negative_past(WordForm::Short).unwrap().kanji.unwrap(), String::from("習わなかった"));
+    /// assert_eq!(verb.negative_past(PoliteLevel::Plain).unwrap().kana, String::from("ならわなかった"));
+    /// assert_eq!(verb.negative_past(PoliteLevel::Plain).unwrap().kanji.unwrap(), String::from("習わなかった"));
     ///
-    /// assert_eq!(verb.negative_past(WordForm::Long).unwrap().kana, String::from("ならいませんでした"));
-    /// assert_eq!(verb.negative_past(WordForm::Long).unwrap().kanji.unwrap(), String::from("習いませんでした"));
+    /// assert_eq!(verb.negative_past(PoliteLevel::Polite).unwrap().kana, String::from("ならいませんでした"));
+    /// assert_eq!(verb.negative_past(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("習いませんでした"));
     /// ```
-    pub fn negative_past(&self, form: WordForm) -> JapaneseResult<Word> {
+    pub fn negative_past(&self, form: PoliteLevel) -> JapaneseResult<Word> {
         match form {
-            WordForm::Short => self.negative_past_short(),
-            WordForm::Long => self.negative_past_long(),
+            PoliteLevel::Plain => self
+                .negative_past_short()
+                .map(|i| self.inflect(i, &[Inflection::Negative, Inflection::Past])),
+            PoliteLevel::Polite => self.negative_past_long().map(|i| {
+                self.inflect(
+                    i,
+                    &[Inflection::Polite, Inflection::Negative, Inflection::Past],
+                )
+            }),
         }
     }
 
+    /// Returns the verb in the polite negative past form ませんでした. Same as
+    /// [`Verb::negative_past`] with [`PoliteLevel::Polite`]
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.polite_past_negative().unwrap().kanji.unwrap(), String::from("食べませんでした"));
+    /// ```
+    #[inline]
+    pub fn polite_past_negative(&self) -> JapaneseResult<Word> {
+        self.negative_past(PoliteLevel::Polite)
+    }
+
+    /// Returns the polite negative followed by the question particle か, used for invitations
+    /// (食べませんか, "won't you eat?")
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.polite_negative_question().unwrap().kana, String::from("たべませんか"));
+    /// assert_eq!(verb.polite_negative_question().unwrap().kanji.unwrap(), String::from("食べませんか"));
+    /// ```
+    pub fn polite_negative_question(&self) -> JapaneseResult<Word> {
+        let mut negative = self.negative(PoliteLevel::Polite)?;
+        negative.push('か');
+        Ok(self.inflect(
+            negative,
+            &[
+                Inflection::Polite,
+                Inflection::Negative,
+                Inflection::Invitation,
+            ],
+        ))
+    }
+
     /// Returns the verb in the potential form
     ///
     /// # Example
     /// ```
-    /// use jp_inflections::{Word, VerbType, WordForm};
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
     ///
     /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
-    /// assert_eq!(verb.potential(WordForm::Short).unwrap().kana, String::from("ならえる"));
-    /// assert_eq!(verb.potential(WordForm::Short).unwrap().kanji.unwrap(), String::from("習える"));
+    /// assert_eq!(verb.potential(PoliteLevel::Plain).unwrap().kana, String::from("ならえる"));
+    /// assert_eq!(verb.potential(PoliteLevel::Plain).unwrap().kanji.unwrap(), String::from("習える"));
+    ///
+    /// assert_eq!(verb.potential(PoliteLevel::Polite).unwrap().kana, String::from("ならえます"));
+    /// assert_eq!(verb.potential(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("習えます"));
+    /// ```
+    pub fn potential(&self, form: PoliteLevel) -> JapaneseResult<Word> {
+        match form {
+            PoliteLevel::Plain => self
+                .potential_short()
+                .map(|i| self.inflect(i, &[Inflection::Potential])),
+            PoliteLevel::Polite => self
+                .potential_long()
+                .map(|i| self.inflect(i, &[Inflection::Potential, Inflection::Polite])),
+        }
+    }
+
+    /// Returns the verb in the literary potential form うる, which attaches to the long stem
+    /// (理解しうる, 起こりうる). [`Verb::potential`] stays the default. うる only exists in the
+    /// dictionary form, other forms are built from the ichidan variant える (理解しえない)
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType};
     ///
-    /// assert_eq!(verb.potential(WordForm::Long).unwrap().kana, String::from("ならえます"));
-    /// assert_eq!(verb.potential(WordForm::Long).unwrap().kanji.unwrap(), String::from("習えます"));
+    /// let verb = Word::new("りかいする", Some("理解する")).into_verb(VerbType::Exception).unwrap();
+    /// assert_eq!(verb.potential_uru().unwrap().kana, String::from("りかいしうる"));
+    /// assert_eq!(verb.potential_uru().unwrap().kanji.unwrap(), String::from("理解しうる"));
+    /// ```
+    pub fn potential_uru(&self) -> JapaneseResult<Word> {
+        let mut stem = self.masu_stem()?;
+        stem.push_str("うる");
+        Ok(self.inflect(stem, &[Inflection::PotentialUru]))
+    }
+
+    /// Returns the verb in the analytic potential form ことができる, which attaches to the dictionary
+    /// form. The result ends in the ichidan verb できる and can be conjugated further using
+    /// [`Word::reconjugate`]
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.potential_koto(PoliteLevel::Plain).unwrap().kana, String::from("たべることができる"));
+    /// assert_eq!(verb.potential_koto(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("食べることができます"));
     /// ```
-    pub fn potential(&self, form: WordForm) -> JapaneseResult<Word> {
+    pub fn potential_koto(&self, form: PoliteLevel) -> JapaneseResult<Word> {
+        let mut word = self.word.clone();
         match form {
-            WordForm::Short => self.potential_short(),
-            WordForm::Long => self.potential_long(),
+            PoliteLevel::Plain => {
+                word.push_str("ことができる");
+                Ok(self.inflect(word, &[Inflection::PotentialKoto]))
+            }
+            PoliteLevel::Polite => {
+                word.push_str("ことができます");
+                Ok(self.inflect(word, &[Inflection::PotentialKoto, Inflection::Polite]))
+            }
         }
     }
 
@@ -218,19 +734,30 @@ impl Verb {
     ///
     /// # Example
     /// ```
-    /// use jp_inflections::{Word, VerbType, WordForm};
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
     ///
     /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
-    /// assert_eq!(verb.negative_potential(WordForm::Short).unwrap().kana, String::from("ならえない"));
-    /// assert_eq!(verb.negative_potential(WordForm::Short).unwrap().kanji.unwrap(), String::from("習えない"));
+    /// assert_eq!(verb.negative_potential(PoliteLevel::Plain).unwrap().kana, String::from("ならえない"));
+    /// assert_eq!(verb.negative_potential(PoliteLevel::Plain).unwrap().kanji.unwrap(), String::from("習えない"));
     ///
-    /// assert_eq!(verb.negative_potential(WordForm::Long).unwrap().kana, String::from("ならえません"));
-    /// assert_eq!(verb.negative_potential(WordForm::Long).unwrap().kanji.unwrap(), String::from("習えません"));
+    /// assert_eq!(verb.negative_potential(PoliteLevel::Polite).unwrap().kana, String::from("ならえません"));
+    /// assert_eq!(verb.negative_potential(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("習えません"));
     /// ```
-    pub fn negative_potential(&self, form: WordForm) -> JapaneseResult<Word> {
+    pub fn negative_potential(&self, form: PoliteLevel) -> JapaneseResult<Word> {
         match form {
-            WordForm::Short => self.negative_potential_short(),
-            WordForm::Long => self.negative_potential_long(),
+            PoliteLevel::Plain => self
+                .negative_potential_short()
+                .map(|i| self.inflect(i, &[Inflection::Potential, Inflection::Negative])),
+            PoliteLevel::Polite => self.negative_potential_long().map(|i| {
+                self.inflect(
+                    i,
+                    &[
+                        Inflection::Potential,
+                        Inflection::Polite,
+                        Inflection::Negative,
+                    ],
+                )
+            }),
         }
     }
 
@@ -238,7 +765,7 @@ impl Verb {
     ///
     /// # Example
     /// ```
-    /// use jp_inflections::{Word, VerbType, WordForm};
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
     ///
     /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
     /// assert_eq!(verb.imperative().unwrap().kana, String::from("たべろ"));
@@ -249,46 +776,39 @@ impl Verb {
     /// assert_eq!(verb.imperative().unwrap().kanji.unwrap(), String::from("習え"));
     /// ```
     pub fn imperative(&self) -> JapaneseResult<Word> {
-        if self.verb_type == VerbType::Ichidan {
-            let mut stripped = self.word.clone().strip_end(1);
-            stripped.push_str("ろ");
-            return Ok(stripped);
-        }
-
-        if self.is_exception() {
-            if self.word.ends_with("する", None) {
-                if self.word.kana == "する" {
-                    return Ok(Word {
-                        kana: String::from("しろ"),
-                        kanji: Some(String::from("為ろ")),
-                        inflections: Vec::new(),
-                    });
-                }
-
-                let mut prefix = self.word.clone().strip_end(2);
-                prefix.push_str("しろ");
-                return Ok(prefix);
-            }
-
-            if let Some(kuru) =
-                SpecialKuru::format_verb(self, Inflection::Imperative, WordForm::Short)
-            {
-                return Ok(kuru);
-            }
-        }
+        self.imperative_plain()
+            .map(|i| self.inflect(i, &[Inflection::Imperative]))
+    }
 
-        if self.is_polite() {
-            return Ok(self.word.clone().strip_end(1).push_str("い").to_owned());
+    /// Returns the softened ませ imperative of the polite verbs (くださいませ, いらっしゃいませ) or
+    /// `Error::UnsupportedForm` for any other verb
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{error::Error, Word, VerbType};
+    ///
+    /// let verb = Word::new("くださる", Some("下さる")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.imperative_masu().unwrap().kana, String::from("くださいませ"));
+    /// assert_eq!(verb.imperative_masu().unwrap().kanji.unwrap(), String::from("下さいませ"));
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.imperative_masu(), Err(Error::UnsupportedForm));
+    /// ```
+    pub fn imperative_masu(&self) -> JapaneseResult<Word> {
+        if !self.is_polite() {
+            return Err(Error::UnsupportedForm);
         }
 
-        self.stem_potential()
+        let mut stem = self.polite_stem()?;
+        stem.push_str("ませ");
+        Ok(self.inflect(stem, &[Inflection::Polite, Inflection::Imperative]))
     }
 
     /// Returns the verb in the negative imperative form
     ///
     /// # Example
     /// ```
-    /// use jp_inflections::{Word, VerbType, WordForm};
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
     ///
     /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
     /// assert_eq!(verb.imperative_negative().unwrap().kana, String::from("たべるな"));
@@ -301,228 +821,394 @@ impl Verb {
     pub fn imperative_negative(&self) -> JapaneseResult<Word> {
         let mut stripped = self.word.clone();
         stripped.push_str("な");
-        Ok(stripped)
+        Ok(self.inflect(stripped, &[Inflection::Negative, Inflection::Imperative]))
     }
 
-    /// Returns the verb in the causative form
+    /// Returns the verb in the polite command なさい form
     ///
     /// # Example
     /// ```
-    /// use jp_inflections::{Word, VerbType, WordForm};
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
     ///
-    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
-    /// assert_eq!(verb.causative().unwrap().kana, String::from("たべさせる"));
-    /// assert_eq!(verb.causative().unwrap().kanji.unwrap(), String::from("食べさせる"));
+    /// let verb = Word::new("まつ", Some("待つ")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.command_nasai().unwrap().kana, String::from("まちなさい"));
+    /// assert_eq!(verb.command_nasai().unwrap().kanji.unwrap(), String::from("待ちなさい"));
     /// ```
-    pub fn causative(&self) -> JapaneseResult<Word> {
-        if self.verb_type == VerbType::Ichidan {
-            let mut stripped = self.word.clone().strip_end(1);
-            stripped.push_str("させる");
-            return Ok(stripped);
-        }
-
-        if self.is_exception() {
-            if self.word.ends_with("する", Some("為る")) {
-                if self.word.kana == "する" {
-                    return Ok(Word {
-                        kana: String::from("させる"),
-                        kanji: Some(String::from("為せる")),
-                        inflections: Vec::new(),
-                    });
-                }
-
-                let mut prefix = self.word.clone().strip_end(2);
-                prefix.push_str("させる");
-                return Ok(prefix);
-            }
-
-            if let Some(kuru) =
-                SpecialKuru::format_verb(self, Inflection::Causative, WordForm::Long)
-            {
-                return Ok(kuru);
-            }
-        }
-
-        let mut short_stem = self.nai_stem()?;
-        short_stem.push_str("せる");
-        Ok(short_stem)
+    pub fn command_nasai(&self) -> JapaneseResult<Word> {
+        let mut stem = self.masu_stem()?;
+        stem.push_str("なさい");
+        Ok(self.inflect(stem, &[Inflection::Nasai]))
     }
 
-    /// Returns the verb in the passive-causative form
+    /// Returns the verb in the honorific お…になる form. Exception verbs like する and 来る, the
+    /// polite verbs (なさる, くださる) and verbs with a one mora stem (見る, 寝る) don't take this
+    /// pattern and return [`Error::UnsupportedForm`]. Those use lexical honorifics (なさる,
+    /// いらっしゃる, ご覧になる) instead
     ///
     /// # Example
     /// ```
-    /// use jp_inflections::{Word, VerbType, WordForm};
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
     ///
-    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
-    /// assert_eq!(verb.causative_passive().unwrap().kana, String::from("たべさせられる"));
-    /// assert_eq!(verb.causative_passive().unwrap().kanji.unwrap(), String::from("食べさせられる"));
-    /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
-    /// assert_eq!(verb.causative_passive().unwrap().kanji.unwrap(), String::from("習わされる"));
+    /// let verb = Word::new("まつ", Some("待つ")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.honorific(PoliteLevel::Plain).unwrap().kana, String::from("おまちになる"));
+    /// assert_eq!(verb.honorific(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("お待ちになります"));
     /// ```
-    pub fn causative_passive(&self) -> JapaneseResult<Word> {
-        if self.verb_type == VerbType::Ichidan {
-            let mut stripped = self.word.clone().strip_end(1);
-            stripped.push_str("させられる");
-            return Ok(stripped);
+    pub fn honorific(&self, form: PoliteLevel) -> JapaneseResult<Word> {
+        if self.is_exception() || self.is_polite() {
+            return Err(Error::UnsupportedForm);
         }
 
-        if self.is_exception() {
-            if self.word.ends_with("する", None) {
-                if self.word.kana == "する" {
-                    return Ok(Word {
-                        kana: String::from("させられる"),
-                        kanji: Some(String::from("為せられる")),
-                        inflections: Vec::new(),
-                    });
-                }
+        let mut stem = self.masu_stem()?;
+        if stem.mora_count() < 2 {
+            return Err(Error::UnsupportedForm);
+        }
 
-                let mut prefix = self.word.clone().strip_end(2);
-                prefix.push_str("させられる");
-                return Ok(prefix);
+        stem.prepend_str("お");
+        match form {
+            PoliteLevel::Plain => {
+                stem.push_str("になる");
+                Ok(self.inflect(stem, &[Inflection::Honorific]))
             }
-
-            if let Some(kuru) =
-                SpecialKuru::format_verb(self, Inflection::CausativePassive, WordForm::Long)
-            {
-                return Ok(kuru);
+            PoliteLevel::Polite => {
+                stem.push_str("になります");
+                Ok(self.inflect(stem, &[Inflection::Honorific, Inflection::Polite]))
             }
         }
-
-        let mut short_stem = self.nai_stem()?;
-        short_stem.push_str("される");
-        Ok(short_stem)
     }
 
-    /// Returns the verb in the negative passive-causative form
+    /// Returns the verb in the humble お…する form. The short form is a する verb and can be
+    /// wrapped into a [`Verb`] with [`VerbType::Exception`] to conjugate it further. Exception
+    /// verbs like する and 来る use suppletive forms (いたす, 参る) and return
+    /// [`Error::UnsupportedForm`], as do the polite verbs (くださる, なさる)
     ///
     /// # Example
     /// ```
-    /// use jp_inflections::{Word, VerbType, WordForm};
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
     ///
-    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
-    /// assert_eq!(verb.negative_causative_passive().unwrap().kana, String::from("たべさせられない"));
-    /// assert_eq!(verb.negative_causative_passive().unwrap().kanji.unwrap(), String::from("食べさせられない"));
-    /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
-    /// assert_eq!(verb.negative_causative_passive().unwrap().kanji.unwrap(), String::from("習わされない"));
+    /// let verb = Word::new("もつ", Some("持つ")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.humble(PoliteLevel::Plain).unwrap().kana, String::from("おもちする"));
+    /// assert_eq!(verb.humble(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("お持ちします"));
     /// ```
-    pub fn negative_causative_passive(&self) -> JapaneseResult<Word> {
-        let mut causative_passive = self.causative_passive()?.strip_end(1);
-        causative_passive.push_str("ない");
-        Ok(causative_passive)
+    pub fn humble(&self, form: PoliteLevel) -> JapaneseResult<Word> {
+        if self.is_exception() || self.is_polite() {
+            return Err(Error::UnsupportedForm);
+        }
+
+        let mut stem = self.masu_stem()?;
+        stem.prepend_str("お").push_str("する");
+        let humble = Verb::new(stem, VerbType::Exception).dictionary(form)?;
+        Ok(match form {
+            PoliteLevel::Plain => self.inflect(humble, &[Inflection::Humble]),
+            PoliteLevel::Polite => self.inflect(humble, &[Inflection::Humble, Inflection::Polite]),
+        })
     }
 
-    /// Returns the verb in the negative causative form
+    /// Returns the verb in the causative form
     ///
     /// # Example
     /// ```
-    /// use jp_inflections::{Word, VerbType, WordForm};
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
     ///
     /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
-    /// assert_eq!(verb.negative_causative().unwrap().kana, String::from("たべさせない"));
-    /// assert_eq!(verb.negative_causative().unwrap().kanji.unwrap(), String::from("食べさせない"));
+    /// assert_eq!(verb.causative(PoliteLevel::Plain).unwrap().kana, String::from("たべさせる"));
+    /// assert_eq!(verb.causative(PoliteLevel::Plain).unwrap().kanji.unwrap(), String::from("食べさせる"));
+    /// assert_eq!(verb.causative(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("食べさせます"));
     /// ```
-    pub fn negative_causative(&self) -> JapaneseResult<Word> {
-        let causative = self.causative()?;
-        let mut negative_causative = causative.strip_end(1);
-        negative_causative.push_str("ない");
-        Ok(negative_causative)
+    pub fn causative(&self, form: PoliteLevel) -> JapaneseResult<Word> {
+        let causative = self.causative_plain()?;
+        Ok(self.derived_form(causative, Inflection::Causative, form))
     }
 
-    /// Returns the verb in the passive form
+    /// Returns the verb in the passive-causative form
     ///
     /// # Example
     /// ```
-    /// use jp_inflections::{Word, VerbType, WordForm};
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
     ///
     /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
-    /// assert_eq!(verb.passive().unwrap().kana, String::from("たべられる"));
-    /// assert_eq!(verb.passive().unwrap().kanji.unwrap(), String::from("食べられる"));
+    /// assert_eq!(verb.causative_passive(PoliteLevel::Plain).unwrap().kana, String::from("たべさせられる"));
+    /// assert_eq!(verb.causative_passive(PoliteLevel::Plain).unwrap().kanji.unwrap(), String::from("食べさせられる"));
+    /// assert_eq!(verb.causative_passive(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("食べさせられます"));
+    /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.causative_passive(PoliteLevel::Plain).unwrap().kanji.unwrap(), String::from("習わされる"));
     /// ```
-    pub fn passive(&self) -> JapaneseResult<Word> {
-        if self.is_exception() {
-            if self.word.ends_with("する", None) {
-                if self.word.kana == "する" {
-                    return Ok(Word {
-                        kana: String::from("される"),
-                        kanji: Some(String::from("為れる")),
-                        inflections: Vec::new(),
-                    });
-                }
+    pub fn causative_passive(&self, form: PoliteLevel) -> JapaneseResult<Word> {
+        let causative_passive = self.causative_passive_plain()?;
+        Ok(self.derived_form(causative_passive, Inflection::CausativePassive, form))
+    }
 
-                let mut prefix = self.word.clone().strip_end(2);
-                prefix.push_str("される");
-                return Ok(prefix);
-            }
+    /// Returns the verb in the negative passive-causative form
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.negative_causative_passive(PoliteLevel::Plain).unwrap().kana, String::from("たべさせられない"));
+    /// assert_eq!(verb.negative_causative_passive(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("食べさせられません"));
+    /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.negative_causative_passive(PoliteLevel::Plain).unwrap().kanji.unwrap(), String::from("習わされない"));
+    /// assert_eq!(verb.negative_causative_passive(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("習わされません"));
+    /// ```
+    pub fn negative_causative_passive(&self, form: PoliteLevel) -> JapaneseResult<Word> {
+        let mut causative_passive = self.causative_passive(PoliteLevel::Plain)?.strip_end(1);
 
-            if let Some(kuru) = SpecialKuru::format_verb(self, Inflection::Passive, WordForm::Long)
-            {
-                return Ok(kuru);
+        match form {
+            PoliteLevel::Plain => {
+                causative_passive.push_str("ない");
+                Ok(self.inflect(
+                    causative_passive,
+                    &[Inflection::CausativePassive, Inflection::Negative],
+                ))
+            }
+            PoliteLevel::Polite => {
+                causative_passive.push_str("ません");
+                Ok(self.inflect(
+                    causative_passive,
+                    &[
+                        Inflection::CausativePassive,
+                        Inflection::Polite,
+                        Inflection::Negative,
+                    ],
+                ))
             }
         }
+    }
 
-        let mut short_stem = self.nai_stem()?;
-        if self.verb_type == VerbType::Ichidan {
-            short_stem.push('ら');
-        }
-        short_stem.push_str("れる");
-        Ok(short_stem)
+    /// Returns the verb in the negative causative form
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.negative_causative().unwrap().kana, String::from("たべさせない"));
+    /// assert_eq!(verb.negative_causative().unwrap().kanji.unwrap(), String::from("食べさせない"));
+    /// ```
+    pub fn negative_causative(&self) -> JapaneseResult<Word> {
+        let causative = self.causative(PoliteLevel::Plain)?;
+        let mut negative_causative = causative.strip_end(1);
+        negative_causative.push_str("ない");
+        Ok(self.inflect(
+            negative_causative,
+            &[Inflection::Causative, Inflection::Negative],
+        ))
+    }
+
+    /// Returns the verb in the passive form. The same form is used for the suffering passive
+    /// (雨に降られる), which isn't distinguished here.
+    ///
+    /// する becomes される. Its kanji reading only becomes 為れる if the verb was written as 為る,
+    /// compound verbs keep their kanji and get される attached (勉強する -> 勉強される)
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.passive(PoliteLevel::Plain).unwrap().kana, String::from("たべられる"));
+    /// assert_eq!(verb.passive(PoliteLevel::Plain).unwrap().kanji.unwrap(), String::from("食べられる"));
+    /// assert_eq!(verb.passive(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("食べられます"));
+    ///
+    /// let verb = Word::new("べんきょうする", Some("勉強する")).into_verb(VerbType::Exception).unwrap();
+    /// assert_eq!(verb.passive(PoliteLevel::Plain).unwrap().kanji.unwrap(), String::from("勉強される"));
+    /// ```
+    pub fn passive(&self, form: PoliteLevel) -> JapaneseResult<Word> {
+        let passive = self.passive_plain()?;
+        Ok(self.derived_form(passive, Inflection::Passive, form))
     }
 
     /// Returns the verb in the negative passive form
     ///
     /// # Example
     /// ```
-    /// use jp_inflections::{Word, VerbType, WordForm};
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
     ///
     /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
     /// assert_eq!(verb.negative_passive().unwrap().kana, String::from("たべられない"));
     /// assert_eq!(verb.negative_passive().unwrap().kanji.unwrap(), String::from("食べられない"));
     /// ```
     pub fn negative_passive(&self) -> JapaneseResult<Word> {
-        let passive = self.passive()?;
+        let passive = self.passive(PoliteLevel::Plain)?;
         let mut negative_passive = passive.strip_end(1);
         negative_passive.push_str("ない");
-        Ok(negative_passive)
+        Ok(self.inflect(
+            negative_passive,
+            &[Inflection::Passive, Inflection::Negative],
+        ))
+    }
+
+    /// Returns the verb in the past of its causative form
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.causative_past(PoliteLevel::Plain).unwrap().kana, String::from("たべさせた"));
+    /// assert_eq!(verb.causative_past(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("食べさせました"));
+    /// ```
+    pub fn causative_past(&self, form: PoliteLevel) -> JapaneseResult<Word> {
+        self.derived_verb(self.causative(PoliteLevel::Plain)?)
+            .past(form)
+    }
+
+    /// Returns the verb in the て form of its causative form
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.causative_te().unwrap().kana, String::from("たべさせて"));
+    /// assert_eq!(verb.causative_te().unwrap().kanji.unwrap(), String::from("食べさせて"));
+    /// ```
+    pub fn causative_te(&self) -> JapaneseResult<Word> {
+        self.derived_verb(self.causative(PoliteLevel::Plain)?)
+            .te_form()
+    }
+
+    /// Returns the verb in the past of its passive form
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.passive_past(PoliteLevel::Plain).unwrap().kana, String::from("たべられた"));
+    /// assert_eq!(verb.passive_past(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("食べられました"));
+    /// ```
+    pub fn passive_past(&self, form: PoliteLevel) -> JapaneseResult<Word> {
+        self.derived_verb(self.passive(PoliteLevel::Plain)?)
+            .past(form)
+    }
+
+    /// Returns the verb in the て form of its passive form
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.passive_te().unwrap().kana, String::from("たべられて"));
+    /// assert_eq!(verb.passive_te().unwrap().kanji.unwrap(), String::from("食べられて"));
+    /// ```
+    pub fn passive_te(&self) -> JapaneseResult<Word> {
+        self.derived_verb(self.passive(PoliteLevel::Plain)?)
+            .te_form()
+    }
+
+    /// Returns the verb in the past of its causative-passive form
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.causative_passive_past(PoliteLevel::Plain).unwrap().kana, String::from("たべさせられた"));
+    /// assert_eq!(verb.causative_passive_past(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("食べさせられました"));
+    /// ```
+    pub fn causative_passive_past(&self, form: PoliteLevel) -> JapaneseResult<Word> {
+        self.derived_verb(self.causative_passive(PoliteLevel::Plain)?)
+            .past(form)
+    }
+
+    /// Returns the verb in the て form of its causative-passive form
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.causative_passive_te().unwrap().kana, String::from("たべさせられて"));
+    /// assert_eq!(verb.causative_passive_te().unwrap().kanji.unwrap(), String::from("食べさせられて"));
+    /// ```
+    pub fn causative_passive_te(&self) -> JapaneseResult<Word> {
+        self.derived_verb(self.causative_passive(PoliteLevel::Plain)?)
+            .te_form()
+    }
+
+    /// Returns the verb in the past of its potential form
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.potential_past(PoliteLevel::Plain).unwrap().kana, String::from("たべられた"));
+    /// assert_eq!(verb.potential_past(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("食べられました"));
+    /// ```
+    pub fn potential_past(&self, form: PoliteLevel) -> JapaneseResult<Word> {
+        self.derived_verb(self.potential(PoliteLevel::Plain)?)
+            .past(form)
+    }
+
+    /// Returns the verb in the て form of its potential form
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.potential_te().unwrap().kana, String::from("たべられて"));
+    /// assert_eq!(verb.potential_te().unwrap().kanji.unwrap(), String::from("食べられて"));
+    /// ```
+    pub fn potential_te(&self) -> JapaneseResult<Word> {
+        self.derived_verb(self.potential(PoliteLevel::Plain)?)
+            .te_form()
+    }
+
+    /// Returns the verb in the ば form of its potential form ("if one can")
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.potential_ba().unwrap().kana, String::from("たべられれば"));
+    /// assert_eq!(verb.potential_ba().unwrap().kanji.unwrap(), String::from("食べられれば"));
+    /// ```
+    pub fn potential_ba(&self) -> JapaneseResult<Word> {
+        self.derived_verb(self.potential(PoliteLevel::Plain)?).ba()
     }
 
     /// Returns the verb in the tara form
     ///
     /// # Example
     /// ```
-    /// use jp_inflections::{Word, VerbType, WordForm};
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
     ///
     /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
     /// assert_eq!(verb.tara().unwrap().kana, String::from("たべたら"));
     /// assert_eq!(verb.tara().unwrap().kanji.unwrap(), String::from("食べたら"));
     /// ```
     pub fn tara(&self) -> JapaneseResult<Word> {
-        let mut ta_form = self.past(WordForm::Short)?;
+        let mut ta_form = self.past(PoliteLevel::Plain)?;
         ta_form.push_str("ら");
-        Ok(ta_form)
+        Ok(self.inflect(ta_form, &[Inflection::Tara]))
     }
 
     /// Returns the verb in the negative tara form
     ///
     /// # Example
     /// ```
-    /// use jp_inflections::{Word, VerbType, WordForm};
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
     ///
     /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
     /// assert_eq!(verb.negative_tara().unwrap().kana, String::from("たべなかったら"));
     /// assert_eq!(verb.negative_tara().unwrap().kanji.unwrap(), String::from("食べなかったら"));
     /// ```
     pub fn negative_tara(&self) -> JapaneseResult<Word> {
-        let mut ta_form = self.negative_past(WordForm::Short)?;
+        let mut ta_form = self.negative_past(PoliteLevel::Plain)?;
         ta_form.push_str("ら");
-        Ok(ta_form)
+        Ok(self.inflect(ta_form, &[Inflection::Negative, Inflection::Tara]))
     }
 
     /// Returns the verb in the ba form
     ///
     /// # Example
     /// ```
-    /// use jp_inflections::{Word, VerbType, WordForm};
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
     ///
     /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
     /// assert_eq!(verb.ba().unwrap().kana, String::from("たべれば"));
@@ -531,179 +1217,888 @@ impl Verb {
     pub fn ba(&self) -> JapaneseResult<Word> {
         let mut e_stem = self.ba_stem()?;
         e_stem.push_str("ば");
-        Ok(e_stem)
+        Ok(self.inflect(e_stem, &[Inflection::Ba]))
     }
 
     /// Returns the verb in the negative ba form
     ///
     /// # Example
     /// ```
-    /// use jp_inflections::{Word, VerbType, WordForm};
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.negative_ba().unwrap().kana, String::from("たべなければ"));
+    /// assert_eq!(verb.negative_ba().unwrap().kanji.unwrap(), String::from("食べなければ"));
+    /// ```
+    pub fn negative_ba(&self) -> JapaneseResult<Word> {
+        let mut negative = self.negative(PoliteLevel::Plain)?.strip_end(1);
+        negative.push_str("ければ");
+        Ok(self.inflect(negative, &[Inflection::Negative, Inflection::Ba]))
+    }
+
+    /// Returns the verb in the と conditional form
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.conditional_to().unwrap().kana, String::from("たべると"));
+    /// assert_eq!(verb.conditional_to().unwrap().kanji.unwrap(), String::from("食べると"));
+    /// ```
+    pub fn conditional_to(&self) -> JapaneseResult<Word> {
+        let mut word = self.word.clone();
+        word.push_str("と");
+        Ok(self.inflect(word, &[Inflection::To]))
+    }
+
+    /// Returns the verb in the negative と conditional form
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.negative_conditional_to().unwrap().kana, String::from("たべないと"));
+    /// assert_eq!(verb.negative_conditional_to().unwrap().kanji.unwrap(), String::from("食べないと"));
+    /// ```
+    pub fn negative_conditional_to(&self) -> JapaneseResult<Word> {
+        let mut negative = self.negative_short()?;
+        negative.push_str("と");
+        Ok(self.inflect(negative, &[Inflection::Negative, Inflection::To]))
+    }
+
+    /// Returns the verb in the なら conditional form
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.conditional_nara().unwrap().kana, String::from("たべるなら"));
+    /// assert_eq!(verb.conditional_nara().unwrap().kanji.unwrap(), String::from("食べるなら"));
+    /// ```
+    pub fn conditional_nara(&self) -> JapaneseResult<Word> {
+        let mut word = self.word.clone();
+        word.push_str("なら");
+        Ok(self.inflect(word, &[Inflection::Nara]))
+    }
+
+    /// Returns the verb in the volitional form
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.volitional(PoliteLevel::Plain).unwrap().kana, String::from("ならおう"));
+    /// assert_eq!(verb.volitional(PoliteLevel::Plain).unwrap().kanji.unwrap(), String::from("習おう"));
+    ///
+    /// assert_eq!(verb.volitional(PoliteLevel::Polite).unwrap().kana, String::from("ならいましょう"));
+    /// assert_eq!(verb.volitional(PoliteLevel::Polite).unwrap().kanji.unwrap(),
+    /// String::from("習いましょう"));
+    /// ```
+    pub fn volitional(&self, form: PoliteLevel) -> JapaneseResult<Word> {
+        match form {
+            PoliteLevel::Plain => self
+                .volitional_short()
+                .map(|i| self.inflect(i, &[Inflection::Volitional])),
+            PoliteLevel::Polite => self
+                .volitional_long()
+                .map(|i| self.inflect(i, &[Inflection::Polite, Inflection::Volitional])),
+        }
+    }
+
+    /// Returns the verb in the negative volitional form. Godan verbs append まい to the dictionary
+    /// form, ichidan verbs and 来る to the short stem (食べまい, こまい) and する becomes すまい.
+    /// Appending まい to the dictionary form (食べるまい, するまい) is an accepted alternative for
+    /// all verbs which isn't returned here
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.negative_volitional().unwrap().kana, String::from("ならうまい"));
+    /// assert_eq!(verb.negative_volitional().unwrap().kanji.unwrap(), String::from("習うまい"));
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.negative_volitional().unwrap().kanji.unwrap(), String::from("食べまい"));
+    /// ```
+    pub fn negative_volitional(&self) -> JapaneseResult<Word> {
+        let mut word = match self.verb_type {
+            VerbType::Godan => self.word.clone(),
+            VerbType::Ichidan => self.negative_stem()?,
+            VerbType::Exception => {
+                let suru =
+                    SpecialSuru::replace_suffix(self, SpecialSuru::negative_volitional_stem_suf());
+                match suru {
+                    Some(suru) => suru,
+                    None => self.negative_stem()?,
+                }
+            }
+        };
+        word.push_str("まい");
+        Ok(self.inflect(word, &[Inflection::Negative, Inflection::Volitional]))
+    }
+
+    /// Returns the verb in the volitional form followed by とする ("try to", "be about to")
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.volitional_to_suru().unwrap().kana, String::from("たべようとする"));
+    /// assert_eq!(verb.volitional_to_suru().unwrap().kanji.unwrap(), String::from("食べようとする"));
+    /// ```
+    pub fn volitional_to_suru(&self) -> JapaneseResult<Word> {
+        let mut volitional = self.volitional(PoliteLevel::Plain)?;
+        volitional.push_str("とする");
+        Ok(self.inflect(volitional, &[Inflection::Attempt]))
+    }
+
+    /// Returns the verb in the volitional form followed by と思う ("intend to")
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.volitional_to_omou().unwrap().kana, String::from("たべようとおもう"));
+    /// assert_eq!(verb.volitional_to_omou().unwrap().kanji.unwrap(), String::from("食べようと思う"));
+    /// ```
+    pub fn volitional_to_omou(&self) -> JapaneseResult<Word> {
+        let mut volitional = self.volitional(PoliteLevel::Plain)?;
+        volitional.kana.push_str("とおもう");
+        if let Some(kanji) = volitional.kanji.as_mut() {
+            kanji.push_str("と思う");
+        }
+        Ok(self.inflect(volitional, &[Inflection::Intention]))
+    }
+
+    /// Returns the verb in the Kansai dialect negative form, which attaches へん to the short stem
+    /// (食べへん, 行かへん). する and 来る become せーへん and けーへん. Regional variants such as
+    /// しーへん, こーへん or 行けへん aren't returned
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType};
+    ///
+    /// let verb = Word::new("いく", Some("行く")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.negative_kansai().unwrap().kana, String::from("いかへん"));
+    /// assert_eq!(verb.negative_kansai().unwrap().kanji.unwrap(), String::from("行かへん"));
+    ///
+    /// let verb = Word::new("する", None).into_verb(VerbType::Exception).unwrap();
+    /// assert_eq!(verb.negative_kansai().unwrap().kana, String::from("せーへん"));
+    /// ```
+    pub fn negative_kansai(&self) -> JapaneseResult<Word> {
+        let special = if self.is_exception() {
+            SpecialSuru::replace_suffix(self, SpecialSuru::kansai_negative_stem_suf()).or_else(
+                || SpecialKuru::replace_suffix(self, SpecialKuru::kansai_negative_stem_suf()),
+            )
+        } else {
+            None
+        };
+
+        let mut word = match special {
+            Some(word) => word,
+            None => self.negative_stem()?,
+        };
+        word.push_str("へん");
+        Ok(self.inflect(word, &[Inflection::KansaiNegative]))
+    }
+
+    /// Returns the verb in the zu form
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.zu().unwrap().kana, String::from("ならわず"));
+    /// assert_eq!(verb.zu().unwrap().kanji.unwrap(), String::from("習わず"));
+    /// ```
+    pub fn zu(&self) -> JapaneseResult<Word> {
+        if self.is_exception() {
+            if let Some(suru) = SpecialSuru::replace_suffix(self, SpecialSuru::zu_suf()) {
+                return Ok(self.inflect(suru, &[Inflection::Zu]));
+            }
+        }
+
+        // Built from the negative stem since ある's negative is the suppletive ない (あらず)
+        let mut word = self.negative_stem()?;
+        word.push_str("ず");
+        Ok(self.inflect(word, &[Inflection::Zu]))
+    }
+
+    /// Returns the verb in the ずに form
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.zuni().unwrap().kana, String::from("ならわずに"));
+    /// assert_eq!(verb.zuni().unwrap().kanji.unwrap(), String::from("習わずに"));
+    /// ```
+    pub fn zuni(&self) -> JapaneseResult<Word> {
+        let mut word = self.zu()?;
+        word.push('に');
+        Ok(self.inflect(word, &[Inflection::Zuni]))
+    }
+
+    /// Returns the verb in the archaic ぬ negative form
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.negative_nu().unwrap().kana, String::from("ならわぬ"));
+    /// assert_eq!(verb.negative_nu().unwrap().kanji.unwrap(), String::from("習わぬ"));
+    /// ```
+    pub fn negative_nu(&self) -> JapaneseResult<Word> {
+        let mut stem = self.classical_negative_stem()?;
+        stem.push('ぬ');
+        Ok(self.inflect(stem, &[Inflection::Nu]))
+    }
+
+    /// Returns the verb in the literary ざる negative form
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("いう", Some("言う")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.negative_zaru().unwrap().kana, String::from("いわざる"));
+    /// assert_eq!(verb.negative_zaru().unwrap().kanji.unwrap(), String::from("言わざる"));
+    /// ```
+    pub fn negative_zaru(&self) -> JapaneseResult<Word> {
+        let mut stem = self.classical_negative_stem()?;
+        stem.push_str("ざる");
+        Ok(self.inflect(stem, &[Inflection::Zaru]))
+    }
+
+    /// Returns the verb in the ざるを得ない form ("can't help but")
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.zaru_wo_enai().unwrap().kana, String::from("たべざるをえない"));
+    /// assert_eq!(verb.zaru_wo_enai().unwrap().kanji.unwrap(), String::from("食べざるを得ない"));
+    /// ```
+    pub fn zaru_wo_enai(&self) -> JapaneseResult<Word> {
+        let mut zaru = self.negative_zaru()?;
+        zaru.kana.push_str("をえない");
+        if let Some(kanji) = zaru.kanji.as_mut() {
+            kanji.push_str("を得ない");
+        }
+        Ok(self.inflect(zaru, &[Inflection::Zaru, Inflection::Obligation]))
+    }
+
+    /// Returns the verb in the てしまう form
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.shimau(PoliteLevel::Plain).unwrap().kana, String::from("たべてしまう"));
+    /// assert_eq!(verb.shimau(PoliteLevel::Plain).unwrap().kanji.unwrap(), String::from("食べてしまう"));
+    ///
+    /// assert_eq!(verb.shimau(PoliteLevel::Polite).unwrap().kana, String::from("たべてしまいます"));
+    /// assert_eq!(verb.shimau(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("食べてしまいます"));
+    /// ```
+    pub fn shimau(&self, form: PoliteLevel) -> JapaneseResult<Word> {
+        let mut te_form = self.te_form()?;
+        match form {
+            PoliteLevel::Plain => {
+                te_form.push_str("しまう");
+                Ok(self.inflect(te_form, &[Inflection::Shimau]))
+            }
+            PoliteLevel::Polite => {
+                te_form.push_str("しまいます");
+                Ok(self.inflect(te_form, &[Inflection::Shimau, Inflection::Polite]))
+            }
+        }
+    }
+
+    /// Returns the verb in the contracted てしまう form (ちゃう/じゃう)
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("はなす", Some("話す")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.shimau_contracted().unwrap().kana, String::from("はなしちゃう"));
+    /// assert_eq!(verb.shimau_contracted().unwrap().kanji.unwrap(), String::from("話しちゃう"));
+    ///
+    /// let verb = Word::new("よむ", Some("読む")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.shimau_contracted().unwrap().kana, String::from("よんじゃう"));
+    /// assert_eq!(verb.shimau_contracted().unwrap().kanji.unwrap(), String::from("読んじゃう"));
+    /// ```
+    pub fn shimau_contracted(&self) -> JapaneseResult<Word> {
+        let contracted = self.te_contraction("ちゃう", "じゃう")?;
+        Ok(self.inflect(contracted, &[Inflection::Shimau]))
+    }
+
+    /// Returns the verb in the ておく form
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.teoku(PoliteLevel::Plain).unwrap().kana, String::from("たべておく"));
+    /// assert_eq!(verb.teoku(PoliteLevel::Plain).unwrap().kanji.unwrap(), String::from("食べておく"));
+    ///
+    /// assert_eq!(verb.teoku(PoliteLevel::Polite).unwrap().kana, String::from("たべておきます"));
+    /// assert_eq!(verb.teoku(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("食べておきます"));
+    /// ```
+    pub fn teoku(&self, form: PoliteLevel) -> JapaneseResult<Word> {
+        let mut te_form = self.te_form()?;
+        match form {
+            PoliteLevel::Plain => {
+                te_form.push_str("おく");
+                Ok(self.inflect(te_form, &[Inflection::Teoku]))
+            }
+            PoliteLevel::Polite => {
+                te_form.push_str("おきます");
+                Ok(self.inflect(te_form, &[Inflection::Teoku, Inflection::Polite]))
+            }
+        }
+    }
+
+    /// Returns the verb in the contracted ておく form (とく/どく)
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.teoku_contracted().unwrap().kana, String::from("たべとく"));
+    /// assert_eq!(verb.teoku_contracted().unwrap().kanji.unwrap(), String::from("食べとく"));
+    ///
+    /// let verb = Word::new("あそぶ", Some("遊ぶ")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.teoku_contracted().unwrap().kana, String::from("あそんどく"));
+    /// assert_eq!(verb.teoku_contracted().unwrap().kanji.unwrap(), String::from("遊んどく"));
+    /// ```
+    pub fn teoku_contracted(&self) -> JapaneseResult<Word> {
+        let contracted = self.te_contraction("とく", "どく")?;
+        Ok(self.inflect(contracted, &[Inflection::Teoku]))
+    }
+
+    /// Returns the verb in the progressive ている form
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("よむ", Some("読む")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.progressive(PoliteLevel::Plain).unwrap().kana, String::from("よんでいる"));
+    /// assert_eq!(verb.progressive(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("読んでいます"));
+    /// ```
+    pub fn progressive(&self, form: PoliteLevel) -> JapaneseResult<Word> {
+        let mut te_form = self.te_form()?;
+        te_form.push_str("いる");
+        Ok(self.derived_form(te_form, Inflection::Progressive, form))
+    }
+
+    /// Returns the verb in the contracted ては form (ちゃ/じゃ)
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.tewa_contracted().unwrap().kana, String::from("たべちゃ"));
+    /// assert_eq!(verb.tewa_contracted().unwrap().kanji.unwrap(), String::from("食べちゃ"));
+    ///
+    /// let verb = Word::new("よむ", Some("読む")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.tewa_contracted().unwrap().kana, String::from("よんじゃ"));
+    /// assert_eq!(verb.tewa_contracted().unwrap().kanji.unwrap(), String::from("読んじゃ"));
+    /// ```
+    pub fn tewa_contracted(&self) -> JapaneseResult<Word> {
+        self.te_contraction("ちゃ", "じゃ")
+            .map(|i| self.inflect(i, &[Inflection::TewaContracted]))
+    }
+
+    /// Returns the verb in the contracted なくては form (なくちゃ)
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.nakucha().unwrap().kana, String::from("たべなくちゃ"));
+    /// assert_eq!(verb.nakucha().unwrap().kanji.unwrap(), String::from("食べなくちゃ"));
+    /// ```
+    pub fn nakucha(&self) -> JapaneseResult<Word> {
+        let mut negative = self.negative_short()?.strip_end(1);
+        negative.push_str("くちゃ");
+        Ok(self.inflect(negative, &[Inflection::Obligation]))
+    }
+
+    /// Returns the verb in the てもいい form ("may", "it's fine to")
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.temo_ii().unwrap().kana, String::from("たべてもいい"));
+    /// assert_eq!(verb.temo_ii().unwrap().kanji.unwrap(), String::from("食べてもいい"));
+    ///
+    /// let verb = Word::new("よむ", Some("読む")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.temo_ii().unwrap().kanji.unwrap(), String::from("読んでもいい"));
+    /// ```
+    pub fn temo_ii(&self) -> JapaneseResult<Word> {
+        let mut te_form = self.te_form()?;
+        te_form.push_str("もいい");
+        Ok(self.inflect(te_form, &[Inflection::Permission]))
+    }
+
+    /// Returns the verb in the なくてもいい form ("doesn't have to")
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.nakutemo_ii().unwrap().kana, String::from("たべなくてもいい"));
+    /// assert_eq!(verb.nakutemo_ii().unwrap().kanji.unwrap(), String::from("食べなくてもいい"));
+    /// ```
+    pub fn nakutemo_ii(&self) -> JapaneseResult<Word> {
+        let mut negative_te = self.negative_te_form()?;
+        negative_te.push_str("もいい");
+        Ok(self.inflect(negative_te, &[Inflection::Negative, Inflection::Permission]))
+    }
+
+    /// Returns the verb in the なければならない obligation form ("must"). Returns the colloquial
+    /// contraction なきゃ instead if `contracted` is `true`
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.must(false).unwrap().kana, String::from("たべなければならない"));
+    /// assert_eq!(verb.must(false).unwrap().kanji.unwrap(), String::from("食べなければならない"));
+    ///
+    /// assert_eq!(verb.must(true).unwrap().kana, String::from("たべなきゃ"));
+    /// assert_eq!(verb.must(true).unwrap().kanji.unwrap(), String::from("食べなきゃ"));
+    /// ```
+    pub fn must(&self, contracted: bool) -> JapaneseResult<Word> {
+        if contracted {
+            let mut negative = self.negative_short()?.strip_end(1);
+            negative.push_str("きゃ");
+            return Ok(self.inflect(negative, &[Inflection::Obligation]));
+        }
+
+        let mut negative_ba = self.negative_ba()?;
+        negative_ba.push_str("ならない");
+        Ok(self.inflect(negative_ba, &[Inflection::Obligation]))
+    }
+
+    /// Returns the verb in the てはいけない prohibition form ("must not"). Returns the contracted
+    /// ちゃいけない/じゃいけない instead if `contracted` is `true`
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType};
+    ///
+    /// let verb = Word::new("のむ", Some("飲む")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.must_not(false).unwrap().kana, String::from("のんではいけない"));
+    /// assert_eq!(verb.must_not(false).unwrap().kanji.unwrap(), String::from("飲んではいけない"));
+    ///
+    /// assert_eq!(verb.must_not(true).unwrap().kana, String::from("のんじゃいけない"));
+    /// assert_eq!(verb.must_not(true).unwrap().kanji.unwrap(), String::from("飲んじゃいけない"));
+    /// ```
+    pub fn must_not(&self, contracted: bool) -> JapaneseResult<Word> {
+        let mut word = match contracted {
+            true => self.tewa_contracted()?,
+            false => self.te_form()?.push_str("は").to_owned(),
+        };
+        word.push_str("いけない");
+        Ok(self.inflect(word, &[Inflection::Prohibition]))
+    }
+
+    /// Returns the verb in the appearance そう form ("looks like")
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("ふる", Some("降る")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.sou_appearance().unwrap().kana, String::from("ふりそう"));
+    /// assert_eq!(verb.sou_appearance().unwrap().kanji.unwrap(), String::from("降りそう"));
+    /// ```
+    pub fn sou_appearance(&self) -> JapaneseResult<Word> {
+        let mut stem = self.masu_stem()?;
+        stem.push_str("そう");
+        Ok(self.inflect(stem, &[Inflection::Appearance]))
+    }
+
+    /// Returns the verb in the negative appearance そう form (なさそう)
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("ふる", Some("降る")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.sou_appearance_negative().unwrap().kana, String::from("ふらなさそう"));
+    /// assert_eq!(verb.sou_appearance_negative().unwrap().kanji.unwrap(), String::from("降らなさそう"));
+    /// ```
+    pub fn sou_appearance_negative(&self) -> JapaneseResult<Word> {
+        let mut negative = self.negative_short()?.strip_end(1);
+        negative.push_str("さそう");
+        Ok(self.inflect(negative, &[Inflection::Negative, Inflection::Appearance]))
+    }
+
+    /// Returns the verb in the hearsay そう form ("I heard that"). The hearsay そう attaches to
+    /// any plain form, so past or negative variants can be built by appending そう to the output
+    /// of [`Verb::past`] or [`Verb::negative`]
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.sou_hearsay().unwrap().kana, String::from("たべるそう"));
+    /// assert_eq!(verb.sou_hearsay().unwrap().kanji.unwrap(), String::from("食べるそう"));
+    ///
+    /// let mut negative = verb.negative(PoliteLevel::Plain).unwrap();
+    /// negative.push_str("そう");
+    /// assert_eq!(negative.kanji.unwrap(), String::from("食べないそう"));
+    /// ```
+    pub fn sou_hearsay(&self) -> JapaneseResult<Word> {
+        let mut word = self.word.clone();
+        word.push_str("そう");
+        Ok(self.inflect(word, &[Inflection::Hearsay]))
+    }
+
+    /// Returns the verb in the explanatory のだ form. If `casual` is true the contracted ん is
+    /// used instead of の. Only verbs are supported, since nouns and な-adjectives would need a な
+    /// inserted before の
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
     ///
     /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
-    /// assert_eq!(verb.negative_ba().unwrap().kana, String::from("たべなければ"));
-    /// assert_eq!(verb.negative_ba().unwrap().kanji.unwrap(), String::from("食べなければ"));
+    /// assert_eq!(verb.explanatory(PoliteLevel::Plain, false).unwrap().kana, String::from("たべるのだ"));
+    /// assert_eq!(verb.explanatory(PoliteLevel::Plain, true).unwrap().kanji.unwrap(), String::from("食べるんだ"));
+    ///
+    /// assert_eq!(verb.explanatory(PoliteLevel::Polite, false).unwrap().kana, String::from("たべるのです"));
+    /// assert_eq!(verb.explanatory(PoliteLevel::Polite, true).unwrap().kanji.unwrap(), String::from("食べるんです"));
     /// ```
-    pub fn negative_ba(&self) -> JapaneseResult<Word> {
-        let mut negative = self.negative(WordForm::Short)?.strip_end(1);
-        negative.push_str("ければ");
-        Ok(negative)
+    pub fn explanatory(&self, form: PoliteLevel, casual: bool) -> JapaneseResult<Word> {
+        let mut word = self.word.clone();
+        word.push(if casual { 'ん' } else { 'の' });
+        match form {
+            PoliteLevel::Plain => {
+                word.push_str("だ");
+                Ok(self.inflect(word, &[Inflection::Explanatory]))
+            }
+            PoliteLevel::Polite => {
+                word.push_str("です");
+                Ok(self.inflect(word, &[Inflection::Explanatory, Inflection::Polite]))
+            }
+        }
     }
 
-    /// Returns the verb in the volitional form
+    /// Returns the verb in the presumptive form (だろう/でしょう). Both endings are kana only, so
+    /// the kanji reading only gets the same suffix appended
     ///
     /// # Example
     /// ```
-    /// use jp_inflections::{Word, VerbType, WordForm};
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
     ///
-    /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
-    /// assert_eq!(verb.volitional(WordForm::Short).unwrap().kana, String::from("ならおう"));
-    /// assert_eq!(verb.volitional(WordForm::Short).unwrap().kanji.unwrap(), String::from("習おう"));
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.presumptive(PoliteLevel::Plain).unwrap().kana, String::from("たべるだろう"));
+    /// assert_eq!(verb.presumptive(PoliteLevel::Plain).unwrap().kanji.unwrap(), String::from("食べるだろう"));
     ///
-    /// assert_eq!(verb.volitional(WordForm::Long).unwrap().kana, String::from("ならいましょう"));
-    /// assert_eq!(verb.volitional(WordForm::Long).unwrap().kanji.unwrap(),
-    /// String::from("習いましょう"));
+    /// assert_eq!(verb.presumptive(PoliteLevel::Polite).unwrap().kana, String::from("たべるでしょう"));
+    /// assert_eq!(verb.presumptive(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("食べるでしょう"));
     /// ```
-    pub fn volitional(&self, form: WordForm) -> JapaneseResult<Word> {
+    pub fn presumptive(&self, form: PoliteLevel) -> JapaneseResult<Word> {
+        let mut word = self.word.clone();
         match form {
-            WordForm::Short => self.volitional_short(),
-            WordForm::Long => self.volitional_long(),
+            PoliteLevel::Plain => {
+                word.push_str("だろう");
+                Ok(self.inflect(word, &[Inflection::Presumptive]))
+            }
+            PoliteLevel::Polite => {
+                word.push_str("でしょう");
+                Ok(self.inflect(word, &[Inflection::Presumptive, Inflection::Polite]))
+            }
         }
     }
 
-    /// Returns the verb in the negative volitional form
+    /// Returns the verb in the すぎる form ("too much")
     ///
     /// # Example
     /// ```
-    /// use jp_inflections::{Word, VerbType, WordForm};
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
     ///
-    /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
-    /// assert_eq!(verb.negative_volitional().unwrap().kana, String::from("ならうまい"));
-    /// assert_eq!(verb.negative_volitional().unwrap().kanji.unwrap(), String::from("習うまい"));
+    /// let verb = Word::new("のむ", Some("飲む")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.sugiru(PoliteLevel::Plain).unwrap().kana, String::from("のみすぎる"));
+    /// assert_eq!(verb.sugiru(PoliteLevel::Plain).unwrap().kanji.unwrap(), String::from("飲みすぎる"));
+    ///
+    /// assert_eq!(verb.sugiru(PoliteLevel::Polite).unwrap().kana, String::from("のみすぎます"));
+    /// assert_eq!(verb.sugiru(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("飲みすぎます"));
     /// ```
-    pub fn negative_volitional(&self) -> JapaneseResult<Word> {
-        let mut word = self.word.clone();
-        word.push_str("まい");
-        Ok(word)
+    pub fn sugiru(&self, form: PoliteLevel) -> JapaneseResult<Word> {
+        let mut stem = self.masu_stem()?;
+        stem.push_sugiru(form);
+        Ok(match form {
+            PoliteLevel::Plain => self.inflect(stem, &[Inflection::Excessive]),
+            PoliteLevel::Polite => self.inflect(stem, &[Inflection::Excessive, Inflection::Polite]),
+        })
     }
 
-    /// Returns the verb in the zu form
+    /// Returns the verb in the やすい form ("easy to do")
     ///
     /// # Example
     /// ```
-    /// use jp_inflections::{Word, VerbType, WordForm};
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
     ///
-    /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
-    /// assert_eq!(verb.zu().unwrap().kana, String::from("ならわず"));
-    /// assert_eq!(verb.zu().unwrap().kanji.unwrap(), String::from("習わず"));
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.yasui().unwrap().kana, String::from("たべやすい"));
+    /// assert_eq!(verb.yasui().unwrap().kanji.unwrap(), String::from("食べやすい"));
     /// ```
-    pub fn zu(&self) -> JapaneseResult<Word> {
-        if self.word.ends_with("する", None) {
-            if self.word.kana == "する" {
-                return Ok(Word {
-                    kana: String::from("せず"),
-                    kanji: Some(String::from("為ず")),
-                    inflections: vec![],
-                });
-            }
+    pub fn yasui(&self) -> JapaneseResult<Word> {
+        let mut stem = self.masu_stem()?;
+        stem.push_str("やすい");
+        Ok(self.inflect(stem, &[Inflection::Yasui]))
+    }
 
-            let mut word = self.word.clone().strip_end(2);
-            word.push_str("せず");
-            return Ok(word);
-        }
-        let mut word = self.negative(WordForm::Short)?.strip_end(2);
-        word.push_str("ず");
-        Ok(word)
+    /// Returns the verb in the にくい form ("hard to do")
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("よむ", Some("読む")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.nikui().unwrap().kana, String::from("よみにくい"));
+    /// assert_eq!(verb.nikui().unwrap().kanji.unwrap(), String::from("読みにくい"));
+    /// ```
+    pub fn nikui(&self) -> JapaneseResult<Word> {
+        let mut stem = self.masu_stem()?;
+        stem.push_str("にくい");
+        Ok(self.inflect(stem, &[Inflection::Nikui]))
     }
 
     /// Returns the short negative potential form of the verb
     fn negative_potential_short(&self) -> JapaneseResult<Word> {
-        let mut stem = self.stem_potential()?;
+        let mut stem = self.potential_stem()?;
         stem.push_str("ない");
         Ok(stem)
     }
 
     /// Returns the long negative potential form of the verb
     fn negative_potential_long(&self) -> JapaneseResult<Word> {
-        let mut stem = self.stem_potential()?;
+        let mut stem = self.potential_stem()?;
         stem.push_str("ません");
         Ok(stem)
     }
 
     /// Returns the short potential form of the verb
     fn potential_short(&self) -> JapaneseResult<Word> {
-        let mut stem = self.stem_potential()?;
+        let mut stem = self.potential_stem()?;
         stem.push('る');
         Ok(stem)
     }
 
     /// Returns the long potential form of the verb
     fn potential_long(&self) -> JapaneseResult<Word> {
-        let mut stem = self.stem_potential()?;
+        let mut stem = self.potential_stem()?;
         stem.push_str("ます");
         Ok(stem)
     }
 
     /// Returns the polite present form of the word
     fn dictionary_polite(&self) -> JapaneseResult<Word> {
-        let mut stem = self.stem_long()?;
+        let mut stem = self.masu_stem()?;
         stem.push_str("ます");
         Ok(stem)
     }
 
     /// Returns the verb in the negative short past form
     fn negative_past_short(&self) -> JapaneseResult<Word> {
-        if self.word.has_reading("ある", None) {
-            return Ok(Word::new("なかった", None));
+        if self.verb_type == VerbType::Godan {
+            if let Some(aru) = SpecialAru::replace_suffix(self, SpecialAru::negative_past_suf()) {
+                return Ok(aru);
+            }
         }
 
-        let mut negative_past = self.nai_stem()?;
+        let mut negative_past = self.negative_stem()?;
         negative_past.push_str("なかった");
         Ok(negative_past)
     }
 
     /// Returns the verb in the negative long past form
     fn negative_past_long(&self) -> JapaneseResult<Word> {
-        let mut negative_past = self.stem_long()?;
+        let mut negative_past = self.masu_stem()?;
         negative_past.push_str("ませんでした");
         Ok(negative_past)
     }
 
-    /// Returns a word conjungated like て from but with a custom character instead of て
-    pub fn te_rule(&self, to_append: Syllable) -> JapaneseResult<Word> {
-        if self.word.ends_with("いく", Some("行く")) {
-            return Ok(Word::new(
-                format!("いっ{}", to_append),
-                Some(format!("行っ{}", to_append)),
-            ));
+    /// Returns the derived ichidan verb `derived` (eg. the causative) as [`Verb`] to conjugate it
+    /// further
+    fn derived_verb(&self, derived: Word) -> Verb {
+        Verb::new(derived, VerbType::Ichidan)
+    }
+
+    /// Returns the derived ichidan verb `derived` (eg. the causative) in the given form tracking
+    /// `inflection`
+    fn derived_form(&self, derived: Word, inflection: Inflection, form: PoliteLevel) -> Word {
+        match form {
+            PoliteLevel::Plain => self.inflect(derived, &[inflection]),
+            PoliteLevel::Polite => {
+                let mut polite = derived.strip_end(1);
+                polite.push_str("ます");
+                self.inflect(polite, &[inflection, Inflection::Polite])
+            }
+        }
+    }
+
+    /// Returns the imperative form of the verb without tracking the inflection
+    fn imperative_plain(&self) -> JapaneseResult<Word> {
+        if self.verb_type == VerbType::Ichidan {
+            if let Some(kureru) =
+                SpecialKureru::format_verb(self, Inflection::Imperative, PoliteLevel::Plain)
+            {
+                return Ok(kureru);
+            }
+
+            let mut stripped = self.ichidan_stem();
+            stripped.push_str("ろ");
+            return Ok(stripped);
         }
 
         if self.is_exception() {
-            if self.word.ends_with("する", None) {
-                if self.word.kana == "する" {
-                    let kanji = format!("為{}", to_append);
-                    return Ok(Word::new(format!("し{}", to_append), Some(kanji)));
-                }
+            if let Some(suru) =
+                SpecialSuru::format_verb(self, Inflection::Imperative, PoliteLevel::Polite)
+            {
+                return Ok(suru);
+            }
+
+            if let Some(kuru) =
+                SpecialKuru::format_verb(self, Inflection::Imperative, PoliteLevel::Plain)
+            {
+                return Ok(kuru);
+            }
+        }
+
+        if self.is_polite() {
+            return self.polite_stem();
+        }
+
+        self.potential_stem()
+    }
+
+    /// Returns the causative form of the verb without tracking the inflection
+    fn causative_plain(&self) -> JapaneseResult<Word> {
+        if self.verb_type == VerbType::Ichidan {
+            let mut stripped = self.ichidan_stem();
+            stripped.push_str("させる");
+            return Ok(stripped);
+        }
+
+        if self.is_exception() {
+            if let Some(suru) =
+                SpecialSuru::format_verb(self, Inflection::Causative, PoliteLevel::Polite)
+            {
+                return Ok(suru);
+            }
 
-                let mut prefix = self.word.clone().strip_end(2);
-                prefix.push_str(format!("し{}", to_append).as_str());
-                return Ok(prefix);
+            if let Some(kuru) =
+                SpecialKuru::format_verb(self, Inflection::Causative, PoliteLevel::Polite)
+            {
+                return Ok(kuru);
             }
+        }
 
-            if let Some(mut kuru) = SpecialKuru::format_verb(self, Inflection::Te, WordForm::Long) {
-                // ugly hack to replace the て with `to_append` but whatever
-                kuru.kana.pop();
-                kuru.kana.push(*to_append);
-                if let Some(kanji) = kuru.kanji.as_mut() {
-                    kanji.pop();
-                    kanji.push(*to_append);
-                }
+        let mut short_stem = self.negative_stem()?;
+        short_stem.push_str("せる");
+        Ok(short_stem)
+    }
+
+    /// Returns the causative passive form of the verb without tracking the inflection
+    fn causative_passive_plain(&self) -> JapaneseResult<Word> {
+        if self.verb_type == VerbType::Ichidan {
+            let mut stripped = self.ichidan_stem();
+            stripped.push_str("させられる");
+            return Ok(stripped);
+        }
+
+        if self.is_exception() {
+            if let Some(suru) =
+                SpecialSuru::format_verb(self, Inflection::CausativePassive, PoliteLevel::Polite)
+            {
+                return Ok(suru);
+            }
+
+            if let Some(kuru) =
+                SpecialKuru::format_verb(self, Inflection::CausativePassive, PoliteLevel::Polite)
+            {
+                return Ok(kuru);
+            }
+        }
+
+        let mut short_stem = self.negative_stem()?;
+        short_stem.push_str("される");
+        Ok(short_stem)
+    }
+
+    /// Returns the passive form of the verb without tracking the inflection
+    fn passive_plain(&self) -> JapaneseResult<Word> {
+        if self.is_exception() {
+            if let Some(suru) =
+                SpecialSuru::format_verb(self, Inflection::Passive, PoliteLevel::Polite)
+            {
+                return Ok(suru);
+            }
+
+            if let Some(kuru) =
+                SpecialKuru::format_verb(self, Inflection::Passive, PoliteLevel::Polite)
+            {
                 return Ok(kuru);
             }
         }
 
-        if self.word.ends_with("ある", None) {
-            return Ok(Word::new(format!("あっ{}", to_append), None));
+        let mut short_stem = self.negative_stem()?;
+        if self.verb_type == VerbType::Ichidan {
+            short_stem.push('ら');
+        }
+        short_stem.push_str("れる");
+        Ok(short_stem)
+    }
+
+    /// Returns a word conjungated like て from but with a custom character instead of て
+    pub fn te_rule(&self, to_append: Syllable) -> JapaneseResult<Word> {
+        if self.verb_type != VerbType::Ichidan {
+            let special = SpecialIku::format_verb(self, Inflection::Te, PoliteLevel::Plain)
+                .or_else(|| SpecialTou::format_verb(self, Inflection::Te, PoliteLevel::Plain));
+            if let Some(te_form) = special {
+                return Ok(Self::replace_te(te_form, to_append));
+            }
+        }
+
+        if self.is_exception() {
+            if let Some(suru) = SpecialSuru::format_verb(self, Inflection::Te, PoliteLevel::Polite)
+            {
+                return Ok(Self::replace_te(suru, to_append));
+            }
+
+            if let Some(kuru) = SpecialKuru::format_verb(self, Inflection::Te, PoliteLevel::Polite)
+            {
+                return Ok(Self::replace_te(kuru, to_append));
+            }
         }
 
         match self.verb_type {
@@ -712,9 +2107,30 @@ impl Verb {
         }
     }
 
+    /// Replaces the trailing て of a special verbs て form with `to_append`
+    fn replace_te(mut te_form: Word, to_append: Syllable) -> Word {
+        te_form.kana.pop();
+        te_form.kana.push(*to_append);
+        if let Some(kanji) = te_form.kanji.as_mut() {
+            kanji.pop();
+            kanji.push(*to_append);
+        }
+        te_form
+    }
+
+    /// Replaces the て of the て form with `unvoiced` or its で with `voiced`
+    fn te_contraction(&self, unvoiced: &str, voiced: &str) -> JapaneseResult<Word> {
+        let te_form = self.te_form()?;
+        let is_voiced = te_form.ending_syllable().map(|i| i.get_char()) == Some('で');
+
+        let mut contracted = te_form.strip_end(1);
+        contracted.push_str(if is_voiced { voiced } else { unvoiced });
+        Ok(contracted)
+    }
+
     /// Applies the て rule for an ichidan verb
     fn te_rule_ichidan(&self, to_append: Syllable) -> Word {
-        let mut w = self.word.clone().strip_end(1);
+        let mut w = self.ichidan_stem();
         w.push(to_append.into());
         w
     }
@@ -736,7 +2152,12 @@ impl Verb {
         let mut to_append = to_append;
 
         // Change `to_append` to だ/で
-        let ending = self.word.ending_syllable().unwrap().get_char();
+        let ending = self
+            .word
+            .ending_syllable()
+            .unwrap()
+            .to_hiragana()
+            .get_char();
         if matches!(ending, 'ぐ' | 'む' | 'ぶ' | 'ぬ') {
             to_append = to_append.to_dakuten();
         }
@@ -752,53 +2173,61 @@ impl Verb {
 
     /// Returns the verb in the long past form
     fn past_long(&self) -> JapaneseResult<Word> {
-        let mut stem = self.stem_long()?;
+        let mut stem = self.masu_stem()?;
         stem.push_str("ました");
         Ok(stem)
     }
 
     /// Returns the word in the short negative form
     fn negative_short(&self) -> JapaneseResult<Word> {
-        if self.word.kana == "ある" {
-            return Ok(Word::new("ない", None));
+        if self.verb_type == VerbType::Godan {
+            if let Some(aru) =
+                SpecialAru::format_verb(self, Inflection::Negative, PoliteLevel::Plain)
+            {
+                return Ok(aru);
+            }
         }
 
-        let mut negative = self.nai_stem()?;
+        let mut negative = self.negative_stem()?;
         negative.push_str("ない");
         Ok(negative)
     }
 
     /// Returns the word in the long negative form
     fn negative_long(&self) -> JapaneseResult<Word> {
-        let mut negative = self.stem_long()?;
+        let mut negative = self.masu_stem()?;
         negative.push_str("ません");
         Ok(negative)
     }
 
-    /// Returns the short stem of a the verb
-    fn nai_stem(&self) -> JapaneseResult<Word> {
+    /// Returns the short stem of the verb which ない attaches to
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType};
+    ///
+    /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.negative_stem().unwrap().kanji.unwrap(), String::from("習わ"));
+    ///
+    /// let verb = Word::new("くる", Some("来る")).into_verb(VerbType::Exception).unwrap();
+    /// assert_eq!(verb.negative_stem().unwrap().kana, String::from("こ"));
+    /// ```
+    pub fn negative_stem(&self) -> JapaneseResult<Word> {
         if self.verb_type == VerbType::Ichidan {
-            return Ok(self.word.clone().strip_end(1));
+            return Ok(self.ichidan_stem());
         }
 
         // Handle exception: 来る
         if self.is_exception() {
-            if let Some(kuru) = SpecialKuru::format_verb(self, Inflection::Stem, WordForm::Short) {
+            if let Some(kuru) = SpecialKuru::format_verb(self, Inflection::Stem, PoliteLevel::Plain)
+            {
                 return Ok(kuru);
             }
 
-            if self.word.ends_with("する", None) {
-                if self.word.kana == "する" {
-                    return Ok(Word {
-                        kanji: Some(String::from("為")),
-                        kana: String::from("し"),
-                        inflections: Vec::new(),
-                    });
-                }
-
-                let mut prefix = self.word.clone().strip_end(2);
-                prefix.push_str("し");
-                return Ok(prefix);
+            if let Some(suru) =
+                SpecialSuru::format_verb(self, Inflection::Stem, PoliteLevel::Polite)
+            {
+                return Ok(suru);
             }
         }
 
@@ -815,34 +2244,52 @@ impl Verb {
         ])
     }
 
-    /// Returns the long stem of the verb
-    fn stem_long(&self) -> JapaneseResult<Word> {
+    /// Returns the short stem used by the classical negatives (ぬ, ざる), which is せ for する
+    fn classical_negative_stem(&self) -> JapaneseResult<Word> {
+        if self.is_exception() {
+            if let Some(suru) =
+                SpecialSuru::replace_suffix(self, SpecialSuru::classical_negative_stem_suf())
+            {
+                return Ok(suru);
+            }
+        }
+
+        self.negative_stem()
+    }
+
+    /// Returns the long stem of the verb which ます attaches to
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType};
+    ///
+    /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.masu_stem().unwrap().kanji.unwrap(), String::from("習い"));
+    ///
+    /// let verb = Word::new("くださる", Some("下さる")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.masu_stem().unwrap().kanji.unwrap(), String::from("下さい"));
+    /// ```
+    pub fn masu_stem(&self) -> JapaneseResult<Word> {
         if self.verb_type == VerbType::Ichidan {
-            return Ok(self.word.clone().strip_end(1));
+            return Ok(self.ichidan_stem());
         }
 
         if self.is_exception() {
-            if let Some(kuru) = SpecialKuru::format_verb(self, Inflection::Stem, WordForm::Long) {
+            if let Some(kuru) =
+                SpecialKuru::format_verb(self, Inflection::Stem, PoliteLevel::Polite)
+            {
                 return Ok(kuru);
             }
 
-            if self.word.ends_with("する", None) {
-                if self.word.kana == "する" {
-                    return Ok(Word {
-                        kanji: Some(String::from("為")),
-                        kana: String::from("し"),
-                        inflections: Vec::new(),
-                    });
-                }
-
-                let mut prefix = self.word.clone().strip_end(2);
-                prefix.push_str("し");
-                return Ok(prefix);
+            if let Some(suru) =
+                SpecialSuru::format_verb(self, Inflection::Stem, PoliteLevel::Polite)
+            {
+                return Ok(suru);
             }
         }
 
         if self.is_polite() {
-            return Ok(self.word.clone().strip_end(1).push_str("い").to_owned());
+            return self.polite_stem();
         }
 
         self.mapped_stem(&[
@@ -858,29 +2305,32 @@ impl Verb {
         ])
     }
 
-    /// Returns the potential stem of the verb
-    fn stem_potential(&self) -> JapaneseResult<Word> {
+    /// Returns the potential stem of the verb which る and ます attach to
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType};
+    ///
+    /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.potential_stem().unwrap().kanji.unwrap(), String::from("習え"));
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.potential_stem().unwrap().kanji.unwrap(), String::from("食べられ"));
+    /// ```
+    pub fn potential_stem(&self) -> JapaneseResult<Word> {
         if self.verb_type == VerbType::Ichidan {
-            return Ok(self.word.clone().strip_end(1).push_str("られ").to_owned());
+            return Ok(self.ichidan_stem().push_str("られ").to_owned());
         }
 
         if self.is_exception() {
-            if self.word.ends_with("する", None) {
-                if self.word.kana == "する" {
-                    return Ok(Word {
-                        kana: String::from("でき"),
-                        kanji: Some(String::from("出来")),
-                        inflections: Vec::new(),
-                    });
-                }
-
-                let mut prefix = self.word.clone().strip_end(2);
-                prefix.push_str("でき");
-                return Ok(prefix);
+            if let Some(suru) =
+                SpecialSuru::format_verb(self, Inflection::StemPotential, PoliteLevel::Polite)
+            {
+                return Ok(suru);
             }
 
             if let Some(kuru) =
-                SpecialKuru::format_verb(self, Inflection::StemPotential, WordForm::Long)
+                SpecialKuru::format_verb(self, Inflection::StemPotential, PoliteLevel::Polite)
             {
                 return Ok(kuru);
             }
@@ -899,28 +2349,33 @@ impl Verb {
         ])
     }
 
-    /// Returns the ba stem of the verb
-    fn ba_stem(&self) -> JapaneseResult<Word> {
+    /// Returns the stem of the verb which the conditional ば attaches to
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType};
+    ///
+    /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.ba_stem().unwrap().kanji.unwrap(), String::from("習え"));
+    ///
+    /// let verb = Word::new("くる", Some("来る")).into_verb(VerbType::Exception).unwrap();
+    /// assert_eq!(verb.ba_stem().unwrap().kanji.unwrap(), String::from("来れ"));
+    /// ```
+    pub fn ba_stem(&self) -> JapaneseResult<Word> {
         if self.verb_type == VerbType::Ichidan {
-            return Ok(self.word.clone().strip_end(1).push_str("れ").to_owned());
+            return Ok(self.ichidan_stem().push_str("れ").to_owned());
         }
 
         if self.is_exception() {
-            if self.word.ends_with("する", None) {
-                if self.word.kana == "する" {
-                    return Ok(Word {
-                        kana: String::from("すれ"),
-                        kanji: Some(String::from("為れ")),
-                        inflections: Vec::new(),
-                    });
-                }
-
-                let mut prefix = self.word.clone().strip_end(2);
-                prefix.push_str("すれ");
-                return Ok(prefix);
+            if let Some(suru) =
+                SpecialSuru::format_verb(self, Inflection::StemBa, PoliteLevel::Polite)
+            {
+                return Ok(suru);
             }
 
-            if let Some(kuru) = SpecialKuru::format_verb(self, Inflection::StemBa, WordForm::Long) {
+            if let Some(kuru) =
+                SpecialKuru::format_verb(self, Inflection::StemBa, PoliteLevel::Polite)
+            {
                 return Ok(kuru);
             }
         }
@@ -947,38 +2402,40 @@ impl Verb {
 
     /// Returns the word in the long volitional form
     fn volitional_long(&self) -> JapaneseResult<Word> {
-        let mut stem = self.dictionary(WordForm::Long)?.strip_end(1);
+        let mut stem = self.dictionary(PoliteLevel::Polite)?.strip_end(1);
         stem.push_str("しょう");
         Ok(stem)
     }
 
-    /// Returns the volitional stem of the verb
-    fn volitional_stem(&self) -> JapaneseResult<Word> {
+    /// Returns the stem of the verb which the volitional う attaches to
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType};
+    ///
+    /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.volitional_stem().unwrap().kanji.unwrap(), String::from("習お"));
+    ///
+    /// let verb = Word::new("する", None).into_verb(VerbType::Exception).unwrap();
+    /// assert_eq!(verb.volitional_stem().unwrap().kana, String::from("しよ"));
+    /// ```
+    pub fn volitional_stem(&self) -> JapaneseResult<Word> {
         if self.is_exception() {
-            if self.word.ends_with("する", None) {
-                if self.word.kana == "する" {
-                    return Ok(Word {
-                        kana: String::from("しよ"),
-                        kanji: Some("為よ".to_owned()),
-                        inflections: Vec::new(),
-                    });
-                }
-                let mut word = self.word.clone().strip_end(2);
-                word.push_str("しよ");
-                return Ok(word);
+            if let Some(suru) =
+                SpecialSuru::format_verb(self, Inflection::Volitional, PoliteLevel::Polite)
+            {
+                return Ok(suru);
             }
 
-            if self.word.ends_with("くる", None) {
-                return Ok(Word {
-                    kana: String::from("こよ"),
-                    kanji: Some("来よ".to_owned()),
-                    inflections: Vec::new(),
-                });
+            if let Some(kuru) =
+                SpecialKuru::format_verb(self, Inflection::Volitional, PoliteLevel::Polite)
+            {
+                return Ok(kuru);
             }
         }
 
         if self.verb_type == VerbType::Ichidan {
-            let mut word = self.word.clone().strip_end(1);
+            let mut word = self.ichidan_stem();
             word.push_str("よ");
             return Ok(word);
         }
@@ -998,42 +2455,149 @@ impl Verb {
 
     /// Returns the desiderative form of the verb
     pub fn desiderative(&self) -> JapaneseResult<Word> {
-        let mut stem = self.stem_long()?;
+        let mut stem = self.masu_stem()?;
         stem.push_str("たい");
-        Ok(stem)
+        Ok(self.inflect(stem, &[Inflection::Desiderative]))
     }
 
     /// Returns the negative desiderative form of the verb
     pub fn negative_desiderative(&self) -> JapaneseResult<Word> {
-        let mut stem = self.stem_long()?;
+        let mut stem = self.masu_stem()?;
         stem.push_str("たくない");
-        Ok(stem)
+        Ok(self.inflect(stem, &[Inflection::Desiderative, Inflection::Negative]))
     }
 
-    /// Returns the stem of a word using [`mappings`]
-    fn mapped_stem(&self, mappings: &[(char, char)]) -> JapaneseResult<Word> {
-        let word = &self.word.kana;
+    /// Returns the desiderative form of the verb in its て form
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("ならう", Some("習う")).into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.desiderative_te().unwrap().kana, String::from("ならいたくて"));
+    /// assert_eq!(verb.desiderative_te().unwrap().kanji.unwrap(), String::from("習いたくて"));
+    /// ```
+    pub fn desiderative_te(&self) -> JapaneseResult<Word> {
+        let mut desiderative = self.desiderative()?.strip_end(1);
+        desiderative.push_str("くて");
+        Ok(self.inflect(desiderative, &[Inflection::Desiderative, Inflection::Te]))
+    }
 
-        if word.ends_with("する") && self.is_exception() {
-            return Ok(self.word.clone().strip_end(2).push('し').to_owned());
+    /// Returns the third person desiderative (たがる) form of the verb. The short form is a godan
+    /// verb itself and can be wrapped into a [`Verb`] with [`VerbType::Godan`] to conjugate it
+    /// further
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// assert_eq!(verb.desiderative_other(PoliteLevel::Plain).unwrap().kana, String::from("たべたがる"));
+    /// assert_eq!(verb.desiderative_other(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("食べたがります"));
+    ///
+    /// let tagaru = verb.desiderative_other(PoliteLevel::Plain).unwrap().into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(tagaru.past(PoliteLevel::Plain).unwrap().kanji.unwrap(), String::from("食べたがった"));
+    /// ```
+    pub fn desiderative_other(&self, form: PoliteLevel) -> JapaneseResult<Word> {
+        let mut stem = self.masu_stem()?;
+        match form {
+            PoliteLevel::Plain => {
+                stem.push_str("たがる");
+                Ok(self.inflect(stem, &[Inflection::DesiderativeOther]))
+            }
+            PoliteLevel::Polite => {
+                stem.push_str("たがります");
+                Ok(self.inflect(stem, &[Inflection::DesiderativeOther, Inflection::Polite]))
+            }
         }
+    }
+
+    /// Returns `word` with its inflections set to the ones of the verb followed by `inflections`
+    fn inflect(&self, mut word: Word, inflections: &[Inflection]) -> Word {
+        word.inflections = self.word.inflections.clone();
+        word.inflections.extend_from_slice(inflections);
+        word
+    }
 
+    /// Returns the stem of a word using [`mappings`]
+    fn mapped_stem(&self, mappings: &[(char, char)]) -> JapaneseResult<Word> {
         self.map_ending(mappings)
     }
 
     /// Maps the last `char` of the verb using [`mappings`]
     fn map_ending(&self, mappings: &[(char, char)]) -> JapaneseResult<Word> {
-        let ending = self.word.ending_syllable().ok_or(Error::UnexpectedEnding)?;
+        let ending = self.word.ending_syllable().ok_or(Error::NotAVerb)?;
+        let kanji_ending = self.word.kanji.as_ref().and_then(|i| i.chars().last());
         let mut new_word = self.word.clone().strip_end(1);
 
+        // Keep the script of katakana written verbs
+        let in_script = |dst: char, ending: Syllable| match ending.is_katakana() {
+            true => Syllable::from(dst).to_katakana().get_char(),
+            false => dst,
+        };
+
         for (src, dst) in mappings {
-            if ending.get_char() == *src {
-                new_word.push(*dst);
+            if ending.to_hiragana().get_char() == *src {
+                new_word.kana.push(in_script(*dst, ending));
+                if let Some(kanji) = new_word.kanji.as_mut() {
+                    let kanji_ending = kanji_ending.map(Syllable::from).unwrap_or(ending);
+                    kanji.push(in_script(*dst, kanji_ending));
+                }
                 return Ok(new_word);
             }
         }
 
-        Err(Error::UnexpectedEnding)
+        Err(Error::UnexpectedEnding {
+            ending: ending.get_char(),
+        })
+    }
+
+    /// Returns `true` if the ending of the verb allows it to be conjugated as `verb_type`
+    fn allows_verb_type(&self, verb_type: VerbType) -> bool {
+        let kanji_ends_with = |suffix: &str| {
+            self.kanji
+                .as_ref()
+                .map(|i| i.ends_with(suffix))
+                .unwrap_or_default()
+        };
+
+        let is_suru = self.word.is_suru_verb();
+        let is_kuru =
+            self.kana == "くる" || self.kana.ends_with("てくる") || kanji_ends_with("来る");
+
+        let mut chars = self.kana.chars().rev();
+        let last = chars.next().map(Syllable::from);
+        let second_last = chars.next().map(Syllable::from);
+
+        match verb_type {
+            VerbType::Exception => is_suru || is_kuru,
+            VerbType::Ichidan => {
+                let e_or_i_ending = second_last
+                    .map(|i| i.ends_with(Umlaut::E) || i.ends_with(Umlaut::I))
+                    .unwrap_or_default();
+                last.map(|i| i.get_char()) == Some('る')
+                    && (e_or_i_ending || kanji_ends_with("得る"))
+            }
+            VerbType::Godan => {
+                let ends_in_u = last.map(|i| i.ends_with(Umlaut::U)).unwrap_or_default();
+                ends_in_u && !is_suru && !kanji_ends_with("来る")
+            }
+        }
+    }
+
+    /// Returns the stem of an ichidan verb by stripping its る. The literary 得る(うる) only differs
+    /// from える in the dictionary form, so its stem is え (得ない, 得ます)
+    fn ichidan_stem(&self) -> Word {
+        let mut stem = self.word.clone().strip_end(1);
+        if stem.kana.ends_with('う') {
+            stem.kana.pop();
+            stem.kana.push('え');
+            if let Some(kanji) = stem.kanji.as_mut().filter(|i| i.ends_with('う')) {
+                kanji.pop();
+                kanji.push('え');
+            }
+        }
+        stem
     }
 
     /// Returuns `true` if verb_type is exception
@@ -1041,12 +2605,18 @@ impl Verb {
         self.verb_type == VerbType::Exception
     }
 
-    /// Returns `true` if the verb is one of the 5 polite verbs
+    /// Returns the い stem of the 5 polite verbs. Only the trailing る gets replaced, so the
+    /// okurigana of the kanji reading stays as it is (為さる -> 為さい, 仰る -> 仰い)
+    fn polite_stem(&self) -> JapaneseResult<Word> {
+        self.map_ending(&[('る', 'い')])
+    }
+
+    /// Returns `true` if the verb is one of the 5 polite verbs or a compound ending in one
+    /// (勉強なさる)
     fn is_polite(&self) -> bool {
-        matches!(
-            self.word.kana.as_str(),
-            "いらっしゃる" | "おっしゃる" | "くださる" | "ござる" | "なさる"
-        )
+        POLITE_VERBS
+            .iter()
+            .any(|(kana, kanji)| self.word.ends_with(kana, Some(kanji)))
     }
 
     #[inline]
@@ -1054,3 +2624,10 @@ impl Verb {
         &mut self.word
     }
 }
+
+impl Attributive for Verb {
+    #[inline]
+    fn attributive(&self) -> JapaneseResult<Word> {
+        Verb::attributive(self)
+    }
+}
@@ -1,31 +1,95 @@
 use crate::{
+    adjective::{Adjective, AdjectiveType},
+    copula::Copula,
     error::Error,
     inflection::Inflection,
+    romaji,
     syllable::Syllable,
     umlaut::Umlaut,
     verb::{Verb, VerbType},
     JapaneseResult,
 };
+use std::fmt::Display;
 
-/// Represents a japanese word
+/// Godan verbs ending in える/いる which would otherwise be detected as ichidan verbs
+const GODAN_RU_EXCEPTIONS: &[(&str, &str)] = &[
+    ("かえる", "帰る"),
+    ("はしる", "走る"),
+    ("はいる", "入る"),
+    ("きる", "切る"),
+    ("しる", "知る"),
+    ("いる", "要る"),
+    ("へる", "減る"),
+    ("ける", "蹴る"),
+    ("ねる", "練る"),
+    ("てる", "照る"),
+    ("ちる", "散る"),
+    ("まいる", "参る"),
+    ("すべる", "滑る"),
+    ("しゃべる", "喋る"),
+    ("かぎる", "限る"),
+    ("にぎる", "握る"),
+    ("しげる", "茂る"),
+    ("まじる", "混じる"),
+    ("あせる", "焦る"),
+    ("かじる", "齧る"),
+    ("いじる", "弄る"),
+    ("ひねる", "捻る"),
+    ("つねる", "抓る"),
+    ("くつがえる", "覆る"),
+    ("さえぎる", "遮る"),
+    ("おちいる", "陥る"),
+    ("あざける", "嘲る"),
+    ("よみがえる", "蘇る"),
+    ("ひるがえる", "翻る"),
+];
+
+/// Kana readings shared by a godan and an ichidan verb
+const AMBIGUOUS_RU_READINGS: &[&str] = &["きる", "いる", "かえる", "ねる", "へる"];
+
+/// Represents a japanese word.
+///
+/// `==` compares the readings and the inflections. Use [`Word::reading_eq`] to compare the
+/// readings only
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Word {
     pub kana: String,
     pub kanji: Option<String>,
+    /// Inflections applied to the word, innermost first
     pub inflections: Vec<Inflection>,
 }
 
-/// The form of a word.
+/// The politeness of a conjugated word.
 ///
 /// Example:
-/// [`Short`]: しない
-/// [`Long`]: しません
+/// [`Plain`]: しない
+/// [`Polite`]: しません
+///
+/// [`Plain`]: PoliteLevel::Plain
+/// [`Polite`]: PoliteLevel::Polite
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum WordForm {
-    Short,
-    Long,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PoliteLevel {
+    #[cfg_attr(feature = "serde", serde(alias = "Short"))]
+    Plain,
+    #[cfg_attr(feature = "serde", serde(alias = "Long"))]
+    Polite,
 }
 
+#[allow(non_upper_case_globals)]
+impl PoliteLevel {
+    #[deprecated(note = "use `PoliteLevel::Plain` instead")]
+    pub const Short: PoliteLevel = PoliteLevel::Plain;
+
+    #[deprecated(note = "use `PoliteLevel::Polite` instead")]
+    pub const Long: PoliteLevel = PoliteLevel::Polite;
+}
+
+/// The former name of [`PoliteLevel`], kept for source compatibility
+#[deprecated(note = "use `PoliteLevel` instead")]
+pub type WordForm = PoliteLevel;
+
 impl Word {
     /// Creates a new [`Word`] value of a kana and optionally kanji word. Requires both words to be
     /// in the dictionary form
@@ -60,7 +124,8 @@ impl Word {
         self.kana
             .chars()
             .last()
-            .map(|i| Syllable::from(i).ends_with(Umlaut::U))
+            .map(Syllable::from)
+            .map(|i| !i.is_small() && i.ends_with(Umlaut::U))
             .unwrap_or_default()
     }
 
@@ -77,6 +142,208 @@ impl Word {
         Ok(Verb::new(self, verb_type))
     }
 
+    /// Returns an adjective from the word. I-adjectives are required to end in い
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{AdjectiveType, Word};
+    ///
+    /// assert!(Word::new("たかい", Some("高い")).into_adjective(AdjectiveType::I).is_ok());
+    /// assert!(Word::new("えいご", Some("英語")).into_adjective(AdjectiveType::I).is_err());
+    /// ```
+    pub fn into_adjective(self, adjective_type: AdjectiveType) -> JapaneseResult<Adjective> {
+        if adjective_type == AdjectiveType::I && !self.kana.ends_with('い') {
+            return Err(Error::NotAnAdjective);
+        }
+
+        Ok(Adjective::new(self, adjective_type))
+    }
+
+    /// Returns the word followed by the copula だ to conjugate it as a predicate
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, PoliteLevel};
+    ///
+    /// let copula = Word::new("がくせい", Some("学生")).with_copula();
+    /// assert_eq!(copula.past(PoliteLevel::Polite).kana, String::from("がくせいでした"));
+    /// ```
+    pub fn with_copula(self) -> Copula {
+        Copula::new(self)
+    }
+
+    /// Guesses the [`VerbType`] of a verb in dictionary form or returns `None` if the word isn't a
+    /// verb. This is only a heuristic: words ending in する and 来る are exceptions, words ending in
+    /// える/いる are ichidan verbs unless they're in a list of known godan verbs and all other verbs
+    /// are godan verbs. Kanji readings are preferred for the lookup since some kana readings are
+    /// shared by verbs of both types (切る and 着る). Kana only words with such a reading are
+    /// guessed as ichidan verbs (いる is 居る rather than 要る), [`Word::into_verb_auto`] rejects
+    /// them instead. Words read する with another kanji (擦る) are godan verbs. Pass an explicit
+    /// [`VerbType`] to [`Word::into_verb`] to override the guess
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType};
+    ///
+    /// assert_eq!(Word::new("たべる", Some("食べる")).detect_verb_type(), Some(VerbType::Ichidan));
+    /// assert_eq!(Word::new("かえる", Some("帰る")).detect_verb_type(), Some(VerbType::Godan));
+    /// assert_eq!(Word::new("くる", Some("来る")).detect_verb_type(), Some(VerbType::Exception));
+    /// assert_eq!(Word::new("えいご", Some("英語")).detect_verb_type(), None);
+    /// ```
+    pub fn detect_verb_type(&self) -> Option<VerbType> {
+        if !self.is_verb() {
+            return None;
+        }
+
+        let is_kuru =
+            self.kana == "くる" || self.kanji.as_ref().map(|i| i.ends_with("来る")) == Some(true);
+        if self.is_suru_verb() || is_kuru {
+            return Some(VerbType::Exception);
+        }
+
+        if !self.kana.ends_with('る') {
+            return Some(VerbType::Godan);
+        }
+
+        let is_godan_exception = GODAN_RU_EXCEPTIONS
+            .iter()
+            .any(|(kana, kanji)| match self.kanji {
+                // Compounds like 思い切る conjugate like their last verb
+                Some(ref word_kanji) => word_kanji.ends_with(kanji) && self.kana.ends_with(kana),
+                None => self.kana == *kana && !AMBIGUOUS_RU_READINGS.contains(kana),
+            });
+
+        // The literary うる reading of 得る is still conjugated like える
+        let is_uru = self.kana.ends_with("うる")
+            && self.kanji.as_ref().map(|i| i.ends_with("得る")) == Some(true);
+
+        let e_or_i_ending = self
+            .kana
+            .chars()
+            .rev()
+            .nth(1)
+            .map(Syllable::from)
+            .map(|i| i.ends_with(Umlaut::E) || i.ends_with(Umlaut::I))
+            .unwrap_or_default();
+
+        if (e_or_i_ending || is_uru) && !is_godan_exception {
+            Some(VerbType::Ichidan)
+        } else {
+            Some(VerbType::Godan)
+        }
+    }
+
+    /// Returns `true` if the word ends in the verb する. Words read する with another kanji
+    /// reading (擦る) aren't する verbs
+    pub(crate) fn is_suru_verb(&self) -> bool {
+        let kanji_suru = match self.kanji {
+            Some(ref kanji) => kanji.ends_with("する") || kanji.ends_with("為る"),
+            None => true,
+        };
+
+        self.kana.ends_with("する") && kanji_suru
+    }
+
+    /// Returns a verb from the word using the [`VerbType`] guessed by [`Word::detect_verb_type`].
+    /// Returns `Error::AmbiguousVerbType` if the word has no kanji reading and its kana reading
+    /// belongs to both a godan and an ichidan verb (きる: 切る, 着る)
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{error::Error, Word, VerbType};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb_auto().unwrap();
+    /// assert_eq!(verb.verb_type(), VerbType::Ichidan);
+    ///
+    /// assert_eq!(Word::new("きる", None).into_verb_auto(), Err(Error::AmbiguousVerbType));
+    /// ```
+    pub fn into_verb_auto(self) -> JapaneseResult<Verb> {
+        self.require_verb()?;
+
+        if self.kanji.is_none() && AMBIGUOUS_RU_READINGS.contains(&self.kana.as_str()) {
+            return Err(Error::AmbiguousVerbType);
+        }
+
+        let verb_type = self.detect_verb_type().ok_or(Error::NotAVerb)?;
+        Ok(Verb::new(self, verb_type))
+    }
+
+    /// Returns a verb from an already conjugated word to conjugate it further, keeping its
+    /// inflections. Derived forms ending in える/いる (食べられる, 習わせる, 食べている) are
+    /// conjugated as ichidan verbs, other words use the [`VerbType`] guessed by
+    /// [`Word::detect_verb_type`]
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// let potential = verb.potential(PoliteLevel::Plain).unwrap().reconjugate().unwrap();
+    /// assert_eq!(potential.verb_type(), VerbType::Ichidan);
+    /// assert_eq!(potential.negative(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("食べられません"));
+    /// ```
+    pub fn reconjugate(self) -> JapaneseResult<Verb> {
+        self.require_verb()?;
+
+        let e_or_i_ending = self.kana.ends_with('る')
+            && self
+                .kana
+                .chars()
+                .rev()
+                .nth(1)
+                .map(Syllable::from)
+                .map(|i| i.ends_with(Umlaut::E) || i.ends_with(Umlaut::I))
+                .unwrap_or_default();
+
+        let verb_type = match e_or_i_ending {
+            true => VerbType::Ichidan,
+            false => self.detect_verb_type().ok_or(Error::NotAVerb)?,
+        };
+
+        Ok(Verb::new(self, verb_type))
+    }
+
+    /// Returns `true` if both words have the same kana and kanji readings, ignoring their
+    /// inflections
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{PoliteLevel, VerbType, Word};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// let past = verb.past(PoliteLevel::Plain).unwrap();
+    /// let expected = Word::new("たべた", Some("食べた"));
+    /// assert!(past.reading_eq(&expected));
+    /// assert_ne!(past, expected);
+    /// ```
+    #[inline]
+    pub fn reading_eq(&self, other: &Word) -> bool {
+        self.kana == other.kana && self.kanji == other.kanji
+    }
+
+    /// Returns `true` if `input` equals the kana or kanji reading when ignoring dakuten and
+    /// handakuten (は, ば and ぱ are treated as the same character)
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::Word;
+    ///
+    /// let word = Word::new("よんで", Some("読んで"));
+    /// assert!(word.matches_loose("よんて"));
+    /// assert!(word.matches_loose("読んて"));
+    /// assert!(!word.matches_loose("よんだ"));
+    /// ```
+    pub fn matches_loose(&self, input: &str) -> bool {
+        let normalize = |s: &str| -> String {
+            s.chars()
+                .map(|i| Syllable::from(i).to_seion().get_char())
+                .collect()
+        };
+
+        let input = normalize(input);
+        normalize(&self.kana) == input || self.kanji.as_deref().map(normalize) == Some(input)
+    }
+
     /// Returns true if [`self`] has the passed readings. If kanji is none, but the word has a
     /// kanji reading the output represents only a kana match
     pub fn has_reading(&self, kana: &str, kanji: Option<&str>) -> bool {
@@ -110,7 +377,8 @@ impl Word {
     }
 
     /// Tries to strip the given kana and kanji readings from the word and replaces them with the
-    /// given new kanji and kana suffixes. Returns `None` if the word doesn't have the given kana, kanji or both suffixes
+    /// given new kanji and kana suffixes. Returns `None` if the word doesn't have the given kana, kanji or both suffixes.
+    /// The inflections of the word are kept
     pub fn new_with_suffix_replaced(
         &self,
         kana_suffix: impl AsRef<str>,
@@ -129,7 +397,7 @@ impl Word {
         Some(Word {
             kana: new_kana,
             kanji: new_kanji,
-            inflections: Vec::new(),
+            inflections: self.inflections.clone(),
         })
     }
 
@@ -168,30 +436,323 @@ impl Word {
         self.get_reading()
     }
 
+    /// Returns the kana and kanji reading of the word
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::Word;
+    ///
+    /// assert_eq!(Word::new("ならう", Some("習う")).as_pair(), ("ならう", Some("習う")));
+    /// assert_eq!(Word::new("する", None).as_pair(), ("する", None));
+    /// ```
+    #[inline]
+    pub fn as_pair(&self) -> (&str, Option<&str>) {
+        (&self.kana, self.kanji.as_deref())
+    }
+
+    /// Returns the kana and kanji reading of the word, dropping its inflections
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::Word;
+    ///
+    /// let (kana, kanji) = Word::new("ならう", Some("習う")).into_pair();
+    /// assert_eq!(kana, String::from("ならう"));
+    /// assert_eq!(kanji, Some(String::from("習う")));
+    /// ```
+    #[inline]
+    pub fn into_pair(self) -> (String, Option<String>) {
+        (self.kana, self.kanji)
+    }
+
+    /// Returns the word with `kanji` as its kanji reading, eg. to attach the kanji to a word which
+    /// was created from its kana reading only. The kanji reading doesn't need to have the same
+    /// length as the kana reading since conjugations only touch the okurigana at the end, which
+    /// both readings have to share
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{PoliteLevel, VerbType, Word};
+    ///
+    /// let word = Word::new("かえる", None).with_kanji("帰る");
+    /// let verb = word.into_verb(VerbType::Godan).unwrap();
+    /// assert_eq!(verb.negative(PoliteLevel::Polite).unwrap().kanji.unwrap(), String::from("帰りません"));
+    /// ```
+    #[inline]
+    pub fn with_kanji(mut self, kanji: impl AsRef<str>) -> Word {
+        self.kanji = Some(kanji.as_ref().to_owned());
+        self
+    }
+
+    /// Returns the word without its kanji reading
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::Word;
+    ///
+    /// assert_eq!(Word::new("ならう", Some("習う")).kana_only(), Word::new("ならう", None));
+    /// ```
+    #[inline]
+    pub fn kana_only(mut self) -> Word {
+        self.kanji = None;
+        self
+    }
+
+    /// Returns a copy of the word with its kana reading transliterated to katakana. The kanji
+    /// reading and characters without a katakana equivalent (eg. ー) are left untouched
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::Word;
+    ///
+    /// let word = Word::new("ならった", Some("習った")).to_katakana();
+    /// assert_eq!(word.kana, "ナラッタ");
+    /// assert_eq!(word.kanji, Some(String::from("習った")));
+    /// ```
+    pub fn to_katakana(&self) -> Word {
+        self.map_kana(|i| i.to_katakana())
+    }
+
+    /// Returns a copy of the word with its kana reading transliterated to hiragana. The kanji
+    /// reading and characters without a hiragana equivalent (eg. ー) are left untouched
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::Word;
+    ///
+    /// assert_eq!(Word::new("ナラッタ", None).to_hiragana().kana, "ならった");
+    /// ```
+    pub fn to_hiragana(&self) -> Word {
+        self.map_kana(|i| i.to_hiragana())
+    }
+
+    /// Returns a copy of the word with `f` applied on each syllable of the kana reading
+    fn map_kana<F: Fn(Syllable) -> Syllable>(&self, f: F) -> Word {
+        Word {
+            kana: self
+                .kana
+                .chars()
+                .map(|i| f(Syllable::from(i)).get_char())
+                .collect(),
+            kanji: self.kanji.clone(),
+            inflections: self.inflections.clone(),
+        }
+    }
+
+    /// Creates a new [`Word`] from a hepburn romaji reading. Returns `None` if `romaji` can't be
+    /// parsed
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::Word;
+    ///
+    /// let word = Word::from_romaji("narau", Some("習う")).unwrap();
+    /// assert_eq!(word, Word::new("ならう", Some("習う")));
+    /// ```
+    pub fn from_romaji(romaji: &str, kanji: Option<&str>) -> Option<Word> {
+        let kana = romaji::from_romaji(romaji)?;
+        Some(Word::new(kana.as_str(), kanji))
+    }
+
+    /// Parses a word written in the 漢字(かな) notation, eg. 習う(ならう). Full width parentheses
+    /// are accepted as well. A word written in kana only doesn't need a reading. Returns
+    /// `Error::InvalidNotation` if the notation is malformed or a word containing kanji has no
+    /// reading
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{error::Error, Word};
+    ///
+    /// assert_eq!(Word::from_notation("習う(ならう)"), Ok(Word::new("ならう", Some("習う"))));
+    /// assert_eq!(Word::from_notation("たべる"), Ok(Word::new("たべる", None)));
+    /// assert_eq!(Word::from_notation("食べる"), Err(Error::InvalidNotation));
+    /// assert_eq!(Word::from_notation("習う(ならう"), Err(Error::InvalidNotation));
+    /// ```
+    pub fn from_notation(notation: &str) -> JapaneseResult<Word> {
+        let notation = notation.trim();
+
+        let open = match notation.find(['(', '（']) {
+            Some(open) => open,
+            None if is_kana(notation) => return Ok(Word::new(notation, None)),
+            None => return Err(Error::InvalidNotation),
+        };
+
+        let (kanji, rest) = notation.split_at(open);
+        let mut rest = rest.chars();
+        rest.next();
+        let kana = rest
+            .as_str()
+            .strip_suffix([')', '）'])
+            .ok_or(Error::InvalidNotation)?;
+
+        let kanji = kanji.trim();
+        if kanji.is_empty() || !is_kana(kana) {
+            return Err(Error::InvalidNotation);
+        }
+
+        Ok(Word::new(kana, (kanji != kana).then_some(kanji)))
+    }
+
+    /// Returns the hepburn romaji of the kana reading
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::Word;
+    ///
+    /// assert_eq!(Word::new("ならった", Some("習った")).romaji(), "naratta");
+    /// ```
+    pub fn romaji(&self) -> String {
+        romaji::to_romaji(&self.kana)
+    }
+
+    /// Returns a human readable description of the inflections applied to the word, using the
+    /// labels of [`Inflection::label`]
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// let word = verb.negative_past(PoliteLevel::Polite).unwrap();
+    /// assert_eq!(word.describe(), "polite, negative, past");
+    /// ```
+    pub fn describe(&self) -> String {
+        self.describe_with(|i| i.label().to_owned())
+    }
+
+    /// Returns a description of the inflections applied to the word, using `label` to render
+    /// each inflection
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{Inflection, Word, VerbType, PoliteLevel};
+    ///
+    /// let verb = Word::new("たべる", Some("食べる")).into_verb(VerbType::Ichidan).unwrap();
+    /// let word = verb.past(PoliteLevel::Polite).unwrap();
+    /// let description = word.describe_with(|i| match i {
+    ///     Inflection::Polite => String::from("丁寧"),
+    ///     _ => i.to_string(),
+    /// });
+    /// assert_eq!(description, "丁寧, past");
+    /// ```
+    pub fn describe_with<F: Fn(Inflection) -> String>(&self, label: F) -> String {
+        self.inflections
+            .iter()
+            .map(|i| label(*i))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Splits the kanji reading into its kanji stem and the kana tail it shares with the kana
+    /// reading (the okurigana plus any conjugated suffix). Returns `None` if the word has no kanji
+    /// reading. The kana reading of the stem are the remaining leading characters of the kana
+    /// reading, which can be used as furigana
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::Word;
+    ///
+    /// let word = Word::new("ならいます", Some("習います"));
+    /// assert_eq!(word.okurigana_split(), Some((String::from("習"), String::from("います"))));
+    ///
+    /// let word = Word::new("えいご", Some("英語"));
+    /// assert_eq!(word.okurigana_split(), Some((String::from("英語"), String::new())));
+    /// assert_eq!(Word::new("する", None).okurigana_split(), None);
+    /// ```
+    pub fn okurigana_split(&self) -> Option<(String, String)> {
+        let kanji = self.kanji.as_ref()?;
+
+        let tail_len: usize = kanji
+            .chars()
+            .rev()
+            .zip(self.kana.chars().rev())
+            .take_while(|(a, b)| a == b)
+            .map(|(a, _)| a.len_utf8())
+            .sum();
+
+        let (stem, tail) = kanji.split_at(kanji.len() - tail_len);
+        Some((stem.to_owned(), tail.to_owned()))
+    }
+
     /// Returns the last syllable of the word
     pub fn ending_syllable(&self) -> Option<Syllable> {
         self.kana.chars().last().map(Syllable::from)
     }
 
-    /// Remove last n characters from [`self`]
-    pub fn strip_end(self, n: usize) -> Word {
-        let kana_bytes: usize = self.kana.chars().rev().take(n).map(|i| i.len_utf8()).sum();
-        let kanji_bytes: usize = self
-            .kanji
-            .as_ref()
-            .map(|kanji| kanji.chars().rev().take(n).map(|i| i.len_utf8()).sum())
-            .unwrap_or_default();
+    /// Returns an iterator over the syllables of the kana reading, including small kana (ゃ, っ)
+    /// and the long vowel mark ー. Characters which aren't kana (eg. punctuation) are skipped
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::{syllable::Syllable, Word};
+    ///
+    /// let word = Word::new("きゃっと、", None);
+    /// let syllables: Vec<Syllable> = word.syllables().collect();
+    /// assert_eq!(syllables, vec!['き', 'ゃ', 'っ', 'と'].into_iter().map(Syllable::from).collect::<Vec<_>>());
+    /// ```
+    pub fn syllables(&self) -> impl Iterator<Item = Syllable> + '_ {
+        self.kana
+            .chars()
+            .map(Syllable::from)
+            .filter(is_kana_syllable)
+    }
+
+    /// Returns the amount of morae in the kana reading. Small ゃ, ゅ and ょ (and small vowels as in
+    /// ファ) form one mora with the preceding kana, whereas っ, ー and ん count as their own mora
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::Word;
+    ///
+    /// assert_eq!(Word::new("きょう", Some("今日")).mora_count(), 2);
+    /// assert_eq!(Word::new("いっちゃった", Some("行っちゃった")).mora_count(), 5);
+    /// assert_eq!(Word::new("コーヒー", None).mora_count(), 4);
+    /// ```
+    pub fn mora_count(&self) -> usize {
+        self.syllables()
+            .filter(|i| {
+                !matches!(
+                    i.to_hiragana().get_char(),
+                    'ゃ' | 'ゅ' | 'ょ' | 'ぁ' | 'ぃ' | 'ぅ' | 'ぇ' | 'ぉ' | 'ゎ'
+                )
+            })
+            .count()
+    }
 
+    /// Remove last n characters from [`self`], keeping its inflections. Both readings saturate at
+    /// their start, so stripping more characters than a reading has leaves it empty
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::Word;
+    ///
+    /// assert_eq!(Word::new("ならう", Some("習う")).strip_end(1), Word::new("なら", Some("習")));
+    /// assert_eq!(Word::new("す", Some("為")).strip_end(2), Word::new("", Some("")));
+    /// ```
+    pub fn strip_end(self, n: usize) -> Word {
         Word {
-            inflections: self.inflections.clone(),
-            kanji: self
-                .kanji
-                .as_ref()
-                .map(|i| i[..i.len() - kanji_bytes].to_owned()),
-            kana: self.kana[..self.kana.len() - kana_bytes].to_owned(),
+            kana: strip_chars(&self.kana, n).to_owned(),
+            kanji: self.kanji.as_deref().map(|i| strip_chars(i, n).to_owned()),
+            inflections: self.inflections,
         }
     }
 
+    /// Returns the number of characters of the kana reading. Unlike [`str::len`] this doesn't
+    /// count bytes, so it can be used to get the `n` to pass to [`Word::strip_end`]
+    ///
+    /// # Example
+    /// ```
+    /// use jp_inflections::Word;
+    ///
+    /// let word = Word::new("たべる", Some("食べる"));
+    /// assert_eq!(word.char_len(), 3);
+    /// assert_eq!(word.kana.len(), 9);
+    /// ```
+    #[inline]
+    pub fn char_len(&self) -> usize {
+        self.kana.chars().count()
+    }
+
     /// Pushes a &str onto the end of the kana and kanji word
     pub fn push_str(&mut self, s: &str) -> &mut Word {
         self.kana.push_str(s);
@@ -201,6 +762,15 @@ impl Word {
         self
     }
 
+    /// Inserts a &str at the beginning of the kana and kanji word
+    pub fn prepend_str(&mut self, s: &str) -> &mut Word {
+        self.kana.insert_str(0, s);
+        if let Some(ref mut kanji) = self.kanji {
+            kanji.insert_str(0, s);
+        }
+        self
+    }
+
     /// Pushes a char onto the end of the kana and kanji word
     pub fn push(&mut self, c: char) -> &mut Word {
         self.kana.push(c);
@@ -210,8 +780,60 @@ impl Word {
         self
     }
 
+    /// Pushes すぎる or すぎます onto a verb or adjective stem
+    pub(crate) fn push_sugiru(&mut self, form: PoliteLevel) -> &mut Word {
+        match form {
+            PoliteLevel::Plain => self.push_str("すぎる"),
+            PoliteLevel::Polite => self.push_str("すぎます"),
+        }
+    }
+
     /// Retuns a `Error::NotAVerb` error if self is not a verb
     pub fn require_verb(&self) -> JapaneseResult<()> {
         self.is_verb().then_some(()).ok_or(Error::NotAVerb)
     }
 }
+
+/// Returns `true` if `syllable` is a hiragana or katakana character or the long vowel mark ー
+fn is_kana_syllable(syllable: &Syllable) -> bool {
+    syllable.is_hiragana() || syllable.is_katakana() || syllable.get_char() == 'ー'
+}
+
+/// Returns `true` if `s` isn't empty and consists of kana only
+fn is_kana(s: &str) -> bool {
+    !s.is_empty() && s.chars().map(Syllable::from).all(|i| is_kana_syllable(&i))
+}
+
+/// Returns `s` without its last `n` characters. Always cuts at a char boundary and returns an
+/// empty string if `s` has less than `n` characters
+fn strip_chars(s: &str, n: usize) -> &str {
+    if n == 0 {
+        return s;
+    }
+
+    match s.char_indices().rev().nth(n - 1) {
+        Some((end, _)) => &s[..end],
+        None => "",
+    }
+}
+
+/// Displays the kanji reading if possible, otherwise the kana reading. The alternate flag (`{:#}`)
+/// always displays the kana reading
+///
+/// # Example
+/// ```
+/// use jp_inflections::Word;
+///
+/// let word = Word::new("ならう", Some("習う"));
+/// assert_eq!(format!("{}", word), "習う");
+/// assert_eq!(format!("{:#}", word), "ならう");
+/// assert_eq!(format!("{}", Word::new("する", None)), "する");
+/// ```
+impl Display for Word {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.kanji {
+            Some(ref kanji) if !f.alternate() => write!(f, "{}", kanji),
+            _ => write!(f, "{}", self.kana),
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use jp_inflections::{error::Error, Adjective, AdjectiveType, PoliteLevel, Word};
+
+fn i_adjective(kana: &str, kanji: Option<&str>) -> Adjective {
+    Word::new(kana, kanji)
+        .into_adjective(AdjectiveType::I)
+        .unwrap()
+}
+
+fn assert_word(word: Word, kana: &str, kanji: Option<&str>) {
+    assert_eq!(word.kana, kana);
+    assert_eq!(word.kanji.as_deref(), kanji);
+}
+
+#[test]
+fn i_adjective_forms() {
+    let adjective = i_adjective("たかい", Some("高い"));
+
+    assert_word(
+        adjective.negative(false).unwrap(),
+        "たかくない",
+        Some("高くない"),
+    );
+    assert_word(adjective.past().unwrap(), "たかかった", Some("高かった"));
+    assert_word(
+        adjective.negative_past(false).unwrap(),
+        "たかくなかった",
+        Some("高くなかった"),
+    );
+    assert_word(adjective.te().unwrap(), "たかくて", Some("高くて"));
+    assert_word(adjective.adverb().unwrap(), "たかく", Some("高く"));
+    assert_word(adjective.ba().unwrap(), "たかければ", Some("高ければ"));
+}
+
+#[test]
+fn i_adjective_kana_only() {
+    let adjective = i_adjective("かわいい", None);
+
+    assert_word(adjective.negative(false).unwrap(), "かわいくない", None);
+    assert_word(adjective.past().unwrap(), "かわいかった", None);
+}
+
+#[test]
+fn ii() {
+    let adjective = i_adjective("いい", None);
+    assert_word(adjective.negative(false).unwrap(), "よくない", None);
+    assert_word(adjective.past().unwrap(), "よかった", None);
+    assert_word(adjective.ba().unwrap(), "よければ", None);
+
+    let adjective = i_adjective("いい", Some("良い"));
+    assert_word(
+        adjective.negative(false).unwrap(),
+        "よくない",
+        Some("良くない"),
+    );
+    assert_word(adjective.te().unwrap(), "よくて", Some("良くて"));
+
+    let adjective = i_adjective("よい", Some("良い"));
+    assert_word(adjective.past().unwrap(), "よかった", Some("良かった"));
+
+    let adjective = i_adjective("かっこいい", Some("格好いい"));
+    assert_word(
+        adjective.negative_past(false).unwrap(),
+        "かっこよくなかった",
+        Some("格好よくなかった"),
+    );
+}
+
+#[test]
+fn not_an_adjective() {
+    assert_eq!(
+        Word::new("えいご", Some("英語")).into_adjective(AdjectiveType::I),
+        Err(Error::NotAnAdjective)
+    );
+
+    let adjective = Adjective::new(Word::new("えいご", None), AdjectiveType::I);
+    assert_eq!(adjective.negative(false), Err(Error::NotAnAdjective));
+}
+
+fn na_adjective(kana: &str, kanji: Option<&str>) -> Adjective {
+    Word::new(kana, kanji)
+        .into_adjective(AdjectiveType::Na)
+        .unwrap()
+}
+
+#[test]
+fn na_adjective_forms() {
+    let adjective = na_adjective("しずか", Some("静か"));
+
+    assert_word(adjective.predicate().unwrap(), "しずかだ", Some("静かだ"));
+    assert_word(adjective.attributive().unwrap(), "しずかな", Some("静かな"));
+    assert_word(
+        adjective.negative(false).unwrap(),
+        "しずかではない",
+        Some("静かではない"),
+    );
+    assert_word(
+        adjective.negative(true).unwrap(),
+        "しずかじゃない",
+        Some("静かじゃない"),
+    );
+    assert_word(
+        adjective.past().unwrap(),
+        "しずかだった",
+        Some("静かだった"),
+    );
+    assert_word(
+        adjective.negative_past(false).unwrap(),
+        "しずかではなかった",
+        Some("静かではなかった"),
+    );
+    assert_word(adjective.te().unwrap(), "しずかで", Some("静かで"));
+    assert_word(adjective.ba().unwrap(), "しずかなら", Some("静かなら"));
+}
+
+#[test]
+fn na_adjective_ending_in_i() {
+    // Na-adjectives ending in い must not be conjugated like i-adjectives
+    let adjective = na_adjective("きれい", Some("綺麗"));
+
+    assert_word(
+        adjective.negative(true).unwrap(),
+        "きれいじゃない",
+        Some("綺麗じゃない"),
+    );
+    assert_word(
+        adjective.past().unwrap(),
+        "きれいだった",
+        Some("綺麗だった"),
+    );
+}
+
+#[test]
+fn i_adjective_attributive() {
+    let adjective = i_adjective("たかい", Some("高い"));
+
+    assert_word(adjective.predicate().unwrap(), "たかい", Some("高い"));
+    assert_word(adjective.attributive().unwrap(), "たかい", Some("高い"));
+}
+
+#[test]
+fn adverb() {
+    let adjective = i_adjective("はやい", Some("速い"));
+    assert_word(adjective.adverb().unwrap(), "はやく", Some("速く"));
+
+    let adjective = i_adjective("いい", None);
+    assert_word(adjective.adverb().unwrap(), "よく", None);
+
+    let adjective = i_adjective("いい", Some("良い"));
+    assert_word(adjective.adverb().unwrap(), "よく", Some("良く"));
+
+    let adjective = na_adjective("しずか", Some("静か"));
+    assert_word(adjective.adverb().unwrap(), "しずかに", Some("静かに"));
+
+    let adjective = na_adjective("きれい", Some("綺麗"));
+    assert_word(adjective.adverb().unwrap(), "きれいに", Some("綺麗に"));
+
+    let adjective = Adjective::new(Word::new("はしる", Some("走る")), AdjectiveType::I);
+    assert_eq!(adjective.adverb(), Err(Error::NotAnAdjective));
+}
+
+#[test]
+fn sugiru() {
+    let adjective = i_adjective("たかい", Some("高い"));
+    assert_word(
+        adjective.sugiru(PoliteLevel::Plain).unwrap(),
+        "たかすぎる",
+        Some("高すぎる"),
+    );
+    assert_word(
+        adjective.sugiru(PoliteLevel::Polite).unwrap(),
+        "たかすぎます",
+        Some("高すぎます"),
+    );
+
+    let adjective = i_adjective("いい", None);
+    assert_word(
+        adjective.sugiru(PoliteLevel::Plain).unwrap(),
+        "よすぎる",
+        None,
+    );
+
+    let adjective = na_adjective("しずか", Some("静か"));
+    assert_word(
+        adjective.sugiru(PoliteLevel::Plain).unwrap(),
+        "しずかすぎる",
+        Some("静かすぎる"),
+    );
+}
+
+#[test]
+fn noun_sa() {
+    let adjective = i_adjective("たかい", Some("高い"));
+    assert_word(adjective.noun_sa().unwrap(), "たかさ", Some("高さ"));
+
+    let adjective = i_adjective("よい", Some("良い"));
+    assert_word(adjective.noun_sa().unwrap(), "よさ", Some("良さ"));
+
+    let adjective = i_adjective("いい", None);
+    assert_word(adjective.noun_sa().unwrap(), "よさ", None);
+
+    let adjective = na_adjective("しずか", Some("静か"));
+    assert_word(adjective.noun_sa().unwrap(), "しずかさ", Some("静かさ"));
+}
@@ -0,0 +1,40 @@
+use jp_inflections::{AdjectiveType, Attributive, VerbType, Word};
+
+fn modify<A: Attributive>(modifier: &A, noun: &Word) -> Word {
+    let mut word = modifier.attributive().unwrap();
+    word.kana.push_str(&noun.kana);
+    word.kanji = Some(format!("{}{}", word.get_reading(), noun.get_reading()));
+    word
+}
+
+#[test]
+fn attributive() {
+    let noun = Word::new("ほん", Some("本"));
+
+    let verb = Word::new("よむ", Some("読む"))
+        .into_verb(VerbType::Godan)
+        .unwrap();
+    assert_eq!(modify(&verb, &noun), Word::new("よむほん", Some("読む本")));
+
+    let i_adjective = Word::new("たかい", Some("高い"))
+        .into_adjective(AdjectiveType::I)
+        .unwrap();
+    assert_eq!(
+        modify(&i_adjective, &noun),
+        Word::new("たかいほん", Some("高い本"))
+    );
+
+    let na_adjective = Word::new("きれい", Some("綺麗"))
+        .into_adjective(AdjectiveType::Na)
+        .unwrap();
+    assert_eq!(
+        modify(&na_adjective, &noun),
+        Word::new("きれいなほん", Some("綺麗な本"))
+    );
+
+    let copula = Word::new("がくせい", Some("学生")).with_copula();
+    assert_eq!(
+        modify(&copula, &noun),
+        Word::new("がくせいのほん", Some("学生の本"))
+    );
+}
@@ -1,6 +1,6 @@
 mod verb_test;
 
-use jp_inflections::{self, VerbType};
+use jp_inflections::{self, PoliteLevel, VerbType};
 use verb_test::{AssertedResult, VerbTest};
 
 #[test]
@@ -9,9 +9,15 @@ fn ichidan() {
         "たべる",
         Some("食べる"),
         VerbType::Ichidan,
-        vec![AssertedResult::new("たべさせる", Some("食べさせる"))],
+        vec![
+            AssertedResult::new("たべさせる", Some("食べさせる")),
+            AssertedResult::new("たべさせます", Some("食べさせます")),
+        ],
     )
-    .run([|v| v.causative()]);
+    .run([
+        |v| v.causative(PoliteLevel::Plain),
+        |v| v.causative(PoliteLevel::Polite),
+    ]);
 }
 
 #[test]
@@ -20,9 +26,15 @@ fn godan() {
         "まもる",
         Some("守る"),
         VerbType::Godan,
-        vec![AssertedResult::new("まもらせる", Some("守らせる"))],
+        vec![
+            AssertedResult::new("まもらせる", Some("守らせる")),
+            AssertedResult::new("まもらせます", Some("守らせます")),
+        ],
     )
-    .run([|v| v.causative()]);
+    .run([
+        |v| v.causative(PoliteLevel::Plain),
+        |v| v.causative(PoliteLevel::Polite),
+    ]);
 }
 
 #[test]
@@ -31,23 +43,41 @@ fn exceptions() {
         "くる",
         Some("来る"),
         VerbType::Exception,
-        vec![AssertedResult::new("こさせる", Some("来させる"))],
+        vec![
+            AssertedResult::new("こさせる", Some("来させる")),
+            AssertedResult::new("こさせます", Some("来させます")),
+        ],
     )
-    .run([|v| v.causative()]);
+    .run([
+        |v| v.causative(PoliteLevel::Plain),
+        |v| v.causative(PoliteLevel::Polite),
+    ]);
 
     VerbTest::new(
         "する",
         Some("為る"),
         VerbType::Exception,
-        vec![AssertedResult::new("させる", Some("為せる"))],
+        vec![
+            AssertedResult::new("させる", Some("為せる")),
+            AssertedResult::new("させます", Some("為せます")),
+        ],
     )
-    .run([|v| v.causative()]);
+    .run([
+        |v| v.causative(PoliteLevel::Plain),
+        |v| v.causative(PoliteLevel::Polite),
+    ]);
 
     VerbTest::new(
         "みみにする",
         Some("耳にする"),
         VerbType::Exception,
-        vec![AssertedResult::new("みみにさせる", Some("耳にさせる"))],
+        vec![
+            AssertedResult::new("みみにさせる", Some("耳にさせる")),
+            AssertedResult::new("みみにさせます", Some("耳にさせます")),
+        ],
     )
-    .run([|v| v.causative()]);
+    .run([
+        |v| v.causative(PoliteLevel::Plain),
+        |v| v.causative(PoliteLevel::Polite),
+    ]);
 }
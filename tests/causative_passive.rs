@@ -0,0 +1,69 @@
+mod verb_test;
+
+use jp_inflections::{self, PoliteLevel, VerbType};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn ichidan() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![
+            AssertedResult::new("たべさせられる", Some("食べさせられる")),
+            AssertedResult::new("たべさせられます", Some("食べさせられます")),
+        ],
+    )
+    .run([
+        |v| v.causative_passive(PoliteLevel::Plain),
+        |v| v.causative_passive(PoliteLevel::Polite),
+    ]);
+}
+
+#[test]
+fn godan() {
+    VerbTest::new(
+        "ならう",
+        Some("習う"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("ならわされる", Some("習わされる")),
+            AssertedResult::new("ならわされます", Some("習わされます")),
+        ],
+    )
+    .run([
+        |v| v.causative_passive(PoliteLevel::Plain),
+        |v| v.causative_passive(PoliteLevel::Polite),
+    ]);
+}
+
+#[test]
+fn exceptions() {
+    VerbTest::new(
+        "する",
+        Some("為る"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("させられる", Some("為せられる")),
+            AssertedResult::new("させられます", Some("為せられます")),
+        ],
+    )
+    .run([
+        |v| v.causative_passive(PoliteLevel::Plain),
+        |v| v.causative_passive(PoliteLevel::Polite),
+    ]);
+
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("こさせられる", Some("来させられる")),
+            AssertedResult::new("こさせられます", Some("来させられます")),
+        ],
+    )
+    .run([
+        |v| v.causative_passive(PoliteLevel::Plain),
+        |v| v.causative_passive(PoliteLevel::Polite),
+    ]);
+}
@@ -0,0 +1,53 @@
+mod verb_test;
+
+use jp_inflections::VerbType;
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn ichidan() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![AssertedResult::new("たべなさい", Some("食べなさい"))],
+    )
+    .run([|v| v.command_nasai()]);
+}
+
+#[test]
+fn godan() {
+    VerbTest::new(
+        "まつ",
+        Some("待つ"),
+        VerbType::Godan,
+        vec![AssertedResult::new("まちなさい", Some("待ちなさい"))],
+    )
+    .run([|v| v.command_nasai()]);
+
+    VerbTest::new(
+        "ならう",
+        Some("習う"),
+        VerbType::Godan,
+        vec![AssertedResult::new("ならいなさい", Some("習いなさい"))],
+    )
+    .run([|v| v.command_nasai()]);
+}
+
+#[test]
+fn exceptions() {
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![AssertedResult::new("しなさい", None)],
+    )
+    .run([|v| v.command_nasai()]);
+
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![AssertedResult::new("きなさい", Some("来なさい"))],
+    )
+    .run([|v| v.command_nasai()]);
+}
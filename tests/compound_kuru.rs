@@ -0,0 +1,101 @@
+mod verb_test;
+
+use jp_inflections::{self, PoliteLevel, VerbType};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn yose() {
+    VerbTest::new(
+        "よせくる",
+        Some("寄せ来る"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("よせこない", Some("寄せ来ない")),
+            AssertedResult::new("よせきません", Some("寄せ来ません")),
+            AssertedResult::new("よせきた", Some("寄せ来た")),
+            AssertedResult::new("よせきました", Some("寄せ来ました")),
+            AssertedResult::new("よせきて", Some("寄せ来て")),
+            AssertedResult::new("よせこられる", Some("寄せ来られる")),
+            AssertedResult::new("よせこよう", Some("寄せ来よう")),
+            AssertedResult::new("よせこい", Some("寄せ来い")),
+            AssertedResult::new("よせくれば", Some("寄せ来れば")),
+            AssertedResult::new("よせこさせられる", Some("寄せ来させられる")),
+        ],
+    )
+    .run([
+        |v| v.negative(PoliteLevel::Plain),
+        |v| v.negative(PoliteLevel::Polite),
+        |v| v.past(PoliteLevel::Plain),
+        |v| v.past(PoliteLevel::Polite),
+        |v| v.te_form(),
+        |v| v.potential(PoliteLevel::Plain),
+        |v| v.volitional(PoliteLevel::Plain),
+        |v| v.imperative(),
+        |v| v.ba(),
+        |v| v.causative_passive(PoliteLevel::Plain),
+    ]);
+}
+
+#[test]
+fn asobini() {
+    VerbTest::new(
+        "あそびにくる",
+        Some("遊びに来る"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("あそびにこない", Some("遊びに来ない")),
+            AssertedResult::new("あそびにきません", Some("遊びに来ません")),
+            AssertedResult::new("あそびにきた", Some("遊びに来た")),
+            AssertedResult::new("あそびにきました", Some("遊びに来ました")),
+            AssertedResult::new("あそびにきて", Some("遊びに来て")),
+            AssertedResult::new("あそびにこられる", Some("遊びに来られる")),
+            AssertedResult::new("あそびにこよう", Some("遊びに来よう")),
+            AssertedResult::new("あそびにこい", Some("遊びに来い")),
+            AssertedResult::new("あそびにくれば", Some("遊びに来れば")),
+            AssertedResult::new("あそびにこさせられる", Some("遊びに来させられる")),
+        ],
+    )
+    .run([
+        |v| v.negative(PoliteLevel::Plain),
+        |v| v.negative(PoliteLevel::Polite),
+        |v| v.past(PoliteLevel::Plain),
+        |v| v.past(PoliteLevel::Polite),
+        |v| v.te_form(),
+        |v| v.potential(PoliteLevel::Plain),
+        |v| v.volitional(PoliteLevel::Plain),
+        |v| v.imperative(),
+        |v| v.ba(),
+        |v| v.causative_passive(PoliteLevel::Plain),
+    ]);
+}
+
+#[test]
+fn negative_past() {
+    VerbTest::new(
+        "あそびにくる",
+        Some("遊びに来る"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("あそびにこなかった", Some("遊びに来なかった")),
+            AssertedResult::new("あそびにきませんでした", Some("遊びに来ませんでした")),
+        ],
+    )
+    .run([
+        |v| v.negative_past(PoliteLevel::Plain),
+        |v| v.negative_past(PoliteLevel::Polite),
+    ]);
+
+    VerbTest::new(
+        "つれてくる",
+        None,
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("つれてこなかった", None),
+            AssertedResult::new("つれてきませんでした", None),
+        ],
+    )
+    .run([
+        |v| v.negative_past(PoliteLevel::Plain),
+        |v| v.negative_past(PoliteLevel::Polite),
+    ]);
+}
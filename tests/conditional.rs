@@ -0,0 +1,73 @@
+mod verb_test;
+
+use jp_inflections::{self, Verb, VerbType};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn ichidan() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![
+            AssertedResult::new("たべると", Some("食べると")),
+            AssertedResult::new("たべないと", Some("食べないと")),
+            AssertedResult::new("たべるなら", Some("食べるなら")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.conditional_to(),
+        |v: &Verb| v.negative_conditional_to(),
+        |v: &Verb| v.conditional_nara(),
+    ]);
+}
+
+#[test]
+fn godan() {
+    VerbTest::new(
+        "ならう",
+        Some("習う"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("ならうと", Some("習うと")),
+            AssertedResult::new("ならわないと", Some("習わないと")),
+            AssertedResult::new("ならうなら", Some("習うなら")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.conditional_to(),
+        |v: &Verb| v.negative_conditional_to(),
+        |v: &Verb| v.conditional_nara(),
+    ]);
+}
+
+#[test]
+fn exceptions() {
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("すると", None),
+            AssertedResult::new("しないと", None),
+        ],
+    )
+    .run([
+        |v: &Verb| v.conditional_to(),
+        |v: &Verb| v.negative_conditional_to(),
+    ]);
+
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("くると", Some("来ると")),
+            AssertedResult::new("こないと", Some("来ないと")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.conditional_to(),
+        |v: &Verb| v.negative_conditional_to(),
+    ]);
+}
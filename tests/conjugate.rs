@@ -0,0 +1,119 @@
+mod verb_test;
+
+use jp_inflections::{
+    self, error::Error, inflection, Inflection, PoliteLevel, Verb, VerbType, Word,
+};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn ichidan() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![
+            AssertedResult::new("たべます", Some("食べます")),
+            AssertedResult::new("たべる", Some("食べる")),
+            AssertedResult::new("たべられる", Some("食べられる")),
+            AssertedResult::new("たべよう", Some("食べよう")),
+            AssertedResult::new("たべたら", Some("食べたら")),
+            AssertedResult::new("たべれば", Some("食べれば")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.conjugate(Inflection::Polite, PoliteLevel::Plain),
+        |v: &Verb| v.conjugate(Inflection::Present, PoliteLevel::Plain),
+        |v: &Verb| v.conjugate(Inflection::Potential, PoliteLevel::Plain),
+        |v: &Verb| v.conjugate(Inflection::Volitional, PoliteLevel::Plain),
+        |v: &Verb| v.conjugate(Inflection::Tara, PoliteLevel::Polite),
+        |v: &Verb| v.conjugate(Inflection::Ba, PoliteLevel::Plain),
+    ]);
+}
+
+#[test]
+fn godan() {
+    VerbTest::new(
+        "ならう",
+        Some("習う"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("ならい", Some("習い")),
+            AssertedResult::new("ならいません", Some("習いません")),
+            AssertedResult::new("ならった", Some("習った")),
+            AssertedResult::new("ならって", Some("習って")),
+            AssertedResult::new("ならいたい", Some("習いたい")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.conjugate(Inflection::Stem, PoliteLevel::Polite),
+        |v: &Verb| v.conjugate(Inflection::Negative, PoliteLevel::Polite),
+        |v: &Verb| v.conjugate(Inflection::Past, PoliteLevel::Plain),
+        |v: &Verb| v.conjugate(Inflection::Te, PoliteLevel::Plain),
+        |v: &Verb| v.conjugate(Inflection::Desiderative, PoliteLevel::Plain),
+    ]);
+}
+
+#[test]
+fn exceptions() {
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("こさせる", Some("来させる")),
+            AssertedResult::new("こられる", Some("来られる")),
+            AssertedResult::new("こい", Some("来い")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.conjugate(Inflection::Causative, PoliteLevel::Plain),
+        |v: &Verb| v.conjugate(Inflection::Passive, PoliteLevel::Plain),
+        |v: &Verb| v.conjugate(Inflection::Imperative, PoliteLevel::Plain),
+    ]);
+}
+
+#[test]
+fn conjugate_all() {
+    let verbs = vec![
+        Verb::new(Word::new("たべる", Some("食べる")), VerbType::Ichidan),
+        Verb::new(Word::new("えいご", Some("英語")), VerbType::Godan),
+        Verb::new(Word::new("くる", Some("来る")), VerbType::Exception),
+    ];
+
+    let negative = inflection::conjugate_all(&verbs, Inflection::Negative, PoliteLevel::Plain);
+    assert_eq!(negative.len(), 3);
+    assert_eq!(negative[0].as_ref().unwrap().kana, "たべない");
+    assert_eq!(
+        negative[1].as_ref().unwrap_err(),
+        &Error::UnexpectedEnding { ending: 'ご' }
+    );
+    assert_eq!(
+        negative[2].as_ref().unwrap().kanji.as_deref(),
+        Some("来ない")
+    );
+
+    assert!(inflection::conjugate_all(&[], Inflection::Past, PoliteLevel::Polite).is_empty());
+}
+
+#[test]
+fn conjugate_pair() {
+    let verb = Word::new("くる", Some("来る"))
+        .into_verb(VerbType::Exception)
+        .unwrap();
+    assert_eq!(
+        verb.conjugate_pair(Inflection::Negative, PoliteLevel::Polite),
+        Ok((String::from("きません"), Some(String::from("来ません"))))
+    );
+
+    let verb = Word::new("する", None)
+        .into_verb(VerbType::Exception)
+        .unwrap();
+    assert_eq!(
+        verb.conjugate_pair(Inflection::Past, PoliteLevel::Plain),
+        Ok((String::from("した"), None))
+    );
+
+    let word = verb.te_form().unwrap();
+    assert_eq!(word.as_pair(), ("して", None));
+    assert_eq!(word.into_pair(), (String::from("して"), None));
+}
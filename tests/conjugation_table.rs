@@ -0,0 +1,41 @@
+use jp_inflections::{VerbType, Word};
+
+#[test]
+fn godan() {
+    let verb = Word::new("ならう", Some("習う"))
+        .into_verb(VerbType::Godan)
+        .unwrap();
+    let table = verb.conjugation_table();
+
+    assert_eq!(table.negative.unwrap().kana, "ならわない");
+    assert_eq!(table.te.unwrap().kanji.unwrap(), "習って");
+    assert_eq!(table.honorific.unwrap().kanji.unwrap(), "お習いになる");
+}
+
+#[test]
+fn iter() {
+    let verb = Word::new("たべる", Some("食べる"))
+        .into_verb(VerbType::Ichidan)
+        .unwrap();
+    let table = verb.conjugation_table();
+
+    let (label, word) = table.iter().next().unwrap();
+    assert_eq!(label, "dictionary");
+    assert_eq!(word.kana, "たべる");
+
+    let (_, past) = table.iter().find(|(label, _)| *label == "past").unwrap();
+    assert_eq!(past.kanji.as_deref(), Some("食べた"));
+}
+
+#[test]
+fn missing_forms() {
+    let verb = Word::new("くる", Some("来る"))
+        .into_verb(VerbType::Exception)
+        .unwrap();
+    let table = verb.conjugation_table();
+
+    assert!(table.honorific.is_none());
+    assert!(table.humble.is_none());
+    assert!(table.iter().all(|(label, _)| label != "honorific"));
+    assert_eq!(table.iter().count(), 23);
+}
@@ -0,0 +1,56 @@
+mod verb_test;
+
+use jp_inflections::{self, Verb, VerbType, Word};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn continuative() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![AssertedResult::new("たべ", Some("食べ"))],
+    )
+    .run([|v| v.continuative()]);
+
+    VerbTest::new(
+        "よむ",
+        Some("読む"),
+        VerbType::Godan,
+        vec![AssertedResult::new("よみ", Some("読み"))],
+    )
+    .run([|v| v.continuative()]);
+
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![AssertedResult::new("き", Some("来"))],
+    )
+    .run([|v| v.continuative()]);
+
+    VerbTest::new(
+        "くださる",
+        Some("下さる"),
+        VerbType::Godan,
+        vec![AssertedResult::new("くださり", Some("下さり"))],
+    )
+    .run([|v| v.continuative()]);
+}
+
+#[test]
+fn join() {
+    let verbs = [
+        Word::new("する", None)
+            .into_verb(VerbType::Exception)
+            .unwrap(),
+        Word::new("よむ", None).into_verb(VerbType::Godan).unwrap(),
+    ];
+
+    let joined = Verb::join_continuative(&verbs, &Word::new("ねる", None)).unwrap();
+    assert_eq!(joined.kana, "し、よみ、ねる");
+    assert_eq!(joined.kanji, None);
+
+    let joined = Verb::join_continuative(&[], &Word::new("ねる", Some("寝る"))).unwrap();
+    assert_eq!(joined, Word::new("ねる", Some("寝る")));
+}
@@ -0,0 +1,95 @@
+use jp_inflections::{Copula, Inflection, PoliteLevel, Word};
+
+fn gakusei() -> Copula {
+    Word::new("がくせい", Some("学生")).with_copula()
+}
+
+fn assert_word(word: Word, kana: &str, kanji: Option<&str>) {
+    assert_eq!(word.kana, kana);
+    assert_eq!(word.kanji.as_deref(), kanji);
+}
+
+#[test]
+fn present() {
+    let copula = gakusei();
+    assert_word(
+        copula.present(PoliteLevel::Plain),
+        "がくせいだ",
+        Some("学生だ"),
+    );
+    assert_word(
+        copula.present(PoliteLevel::Polite),
+        "がくせいです",
+        Some("学生です"),
+    );
+}
+
+#[test]
+fn past() {
+    let copula = gakusei();
+    assert_word(
+        copula.past(PoliteLevel::Plain),
+        "がくせいだった",
+        Some("学生だった"),
+    );
+    assert_word(
+        copula.past(PoliteLevel::Polite),
+        "がくせいでした",
+        Some("学生でした"),
+    );
+}
+
+#[test]
+fn negative() {
+    let copula = gakusei();
+    assert_word(
+        copula.negative(PoliteLevel::Plain, false),
+        "がくせいではない",
+        Some("学生ではない"),
+    );
+    assert_word(
+        copula.negative(PoliteLevel::Plain, true),
+        "がくせいじゃない",
+        Some("学生じゃない"),
+    );
+    assert_word(
+        copula.negative(PoliteLevel::Polite, false),
+        "がくせいではありません",
+        Some("学生ではありません"),
+    );
+    assert_word(
+        copula.negative_past(PoliteLevel::Plain, true),
+        "がくせいじゃなかった",
+        Some("学生じゃなかった"),
+    );
+    assert_word(
+        copula.negative_past(PoliteLevel::Polite, false),
+        "がくせいではありませんでした",
+        Some("学生ではありませんでした"),
+    );
+}
+
+#[test]
+fn presumptive() {
+    let copula = Copula::new(Word::new("あめ", Some("雨")));
+    assert_word(
+        copula.presumptive(PoliteLevel::Plain),
+        "あめだろう",
+        Some("雨だろう"),
+    );
+    assert_word(
+        copula.presumptive(PoliteLevel::Polite),
+        "あめでしょう",
+        Some("雨でしょう"),
+    );
+}
+
+#[test]
+fn inflections() {
+    let copula = gakusei();
+    assert_eq!(
+        copula.negative_past(PoliteLevel::Polite, false).inflections,
+        vec![Inflection::Polite, Inflection::Negative, Inflection::Past]
+    );
+    assert!(copula.present(PoliteLevel::Plain).inflections.is_empty());
+}
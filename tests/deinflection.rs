@@ -0,0 +1,161 @@
+use jp_inflections::{
+    deinflection::{deinflect, strip_masu, strip_nai, strip_ta, strip_te, Deinflection},
+    Inflection, VerbType,
+};
+
+fn has_candidate(
+    candidates: &[Deinflection],
+    word: &str,
+    verb_type: VerbType,
+    inflections: &[Inflection],
+) -> bool {
+    candidates
+        .iter()
+        .any(|i| i.word == word && i.verb_type == verb_type && i.inflections == inflections)
+}
+
+#[test]
+fn ichidan() {
+    let candidates = deinflect("食べられなかった");
+    for inflection in [Inflection::Potential, Inflection::Passive].iter() {
+        assert!(has_candidate(
+            &candidates,
+            "食べる",
+            VerbType::Ichidan,
+            &[*inflection, Inflection::Negative, Inflection::Past],
+        ));
+    }
+
+    let candidates = deinflect("たべませんでした");
+    assert!(has_candidate(
+        &candidates,
+        "たべる",
+        VerbType::Ichidan,
+        &[Inflection::Polite, Inflection::Negative, Inflection::Past],
+    ));
+}
+
+#[test]
+fn godan() {
+    let candidates = deinflect("習った");
+    assert!(has_candidate(
+        &candidates,
+        "習う",
+        VerbType::Godan,
+        &[Inflection::Past]
+    ));
+
+    let candidates = deinflect("読んで");
+    assert!(has_candidate(
+        &candidates,
+        "読む",
+        VerbType::Godan,
+        &[Inflection::Te]
+    ));
+
+    let candidates = deinflect("書かせたい");
+    assert!(has_candidate(
+        &candidates,
+        "書く",
+        VerbType::Godan,
+        &[Inflection::Causative, Inflection::Desiderative]
+    ));
+
+    let candidates = deinflect("行った");
+    assert!(has_candidate(
+        &candidates,
+        "行く",
+        VerbType::Godan,
+        &[Inflection::Past]
+    ));
+}
+
+#[test]
+fn exceptions() {
+    let candidates = deinflect("しなかった");
+    assert!(has_candidate(
+        &candidates,
+        "する",
+        VerbType::Exception,
+        &[Inflection::Negative, Inflection::Past]
+    ));
+
+    let candidates = deinflect("来られる");
+    assert!(has_candidate(
+        &candidates,
+        "来る",
+        VerbType::Exception,
+        &[Inflection::Potential]
+    ));
+
+    let candidates = deinflect("こよう");
+    assert!(has_candidate(
+        &candidates,
+        "くる",
+        VerbType::Exception,
+        &[Inflection::Volitional]
+    ));
+}
+
+#[test]
+fn invalid_candidates() {
+    let candidates = deinflect("いった");
+    assert!(!candidates.iter().any(|i| i.word == "いっる"));
+
+    let candidates = deinflect("食べさせられなかった");
+    assert!(!candidates.iter().any(|i| i.word.ends_with("っる")));
+    assert!(has_candidate(
+        &candidates,
+        "食べる",
+        VerbType::Ichidan,
+        &[
+            Inflection::CausativePassive,
+            Inflection::Negative,
+            Inflection::Past
+        ],
+    ));
+    assert!(!candidates.iter().any(|i| i.inflections[..]
+        == [
+            Inflection::Potential,
+            Inflection::Potential,
+            Inflection::Negative,
+            Inflection::Past
+        ]));
+}
+
+#[test]
+fn dictionary_form() {
+    let candidates = deinflect("食べる");
+    assert!(has_candidate(&candidates, "食べる", VerbType::Ichidan, &[]));
+}
+
+#[test]
+fn no_candidates() {
+    assert!(deinflect("").is_empty());
+    assert!(deinflect("英語").is_empty());
+}
+
+#[test]
+fn strip_single() {
+    assert!(strip_masu("たべます").contains(&String::from("たべる")));
+    assert!(strip_masu("します").contains(&String::from("する")));
+    assert!(strip_masu("たべる").is_empty());
+
+    let nai = strip_nai("かわない");
+    assert!(nai.contains(&String::from("かう")));
+    assert!(nai.contains(&String::from("かわる")));
+
+    let ta = strip_ta("いった");
+    assert!(ta.contains(&String::from("いく")));
+    assert!(ta.contains(&String::from("いう")));
+    assert!(ta.contains(&String::from("いる")));
+    assert!(ta.contains(&String::from("いつ")));
+
+    let te = strip_te("およいで");
+    assert_eq!(te, vec![String::from("およぐ")]);
+
+    let te = strip_te("かいて");
+    assert!(te.contains(&String::from("かく")));
+    assert!(te.contains(&String::from("かいる")));
+    assert!(!te.contains(&String::from("かぐ")));
+}
@@ -0,0 +1,78 @@
+mod verb_test;
+
+use jp_inflections::{self, Inflection, PoliteLevel, VerbType, Word};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn past() {
+    VerbTest::new(
+        "ならう",
+        Some("習う"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("ならわせた", Some("習わせた")),
+            AssertedResult::new("ならわれました", Some("習われました")),
+            AssertedResult::new("ならわされた", Some("習わされた")),
+            AssertedResult::new("ならえました", Some("習えました")),
+        ],
+    )
+    .run([
+        |v| v.causative_past(PoliteLevel::Plain),
+        |v| v.passive_past(PoliteLevel::Polite),
+        |v| v.causative_passive_past(PoliteLevel::Plain),
+        |v| v.potential_past(PoliteLevel::Polite),
+    ]);
+
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("こさせた", Some("来させた")),
+            AssertedResult::new("こられた", Some("来られた")),
+        ],
+    )
+    .run([
+        |v| v.causative_past(PoliteLevel::Plain),
+        |v| v.passive_past(PoliteLevel::Plain),
+    ]);
+}
+
+#[test]
+fn te() {
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("させて", None),
+            AssertedResult::new("されて", None),
+            AssertedResult::new("させられて", None),
+            AssertedResult::new("できて", None),
+        ],
+    )
+    .run([
+        |v| v.causative_te(),
+        |v| v.passive_te(),
+        |v| v.causative_passive_te(),
+        |v| v.potential_te(),
+    ]);
+}
+
+#[test]
+fn inflections() {
+    let verb = Word::new("たべる", Some("食べる"))
+        .into_verb(VerbType::Ichidan)
+        .unwrap();
+
+    assert_eq!(
+        verb.causative_past(PoliteLevel::Polite)
+            .unwrap()
+            .inflections,
+        vec![Inflection::Causative, Inflection::Polite, Inflection::Past]
+    );
+    assert_eq!(
+        verb.potential_te().unwrap().inflections,
+        vec![Inflection::Potential, Inflection::Te]
+    );
+}
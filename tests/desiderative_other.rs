@@ -0,0 +1,63 @@
+mod verb_test;
+
+use jp_inflections::{self, PoliteLevel, Verb, VerbType};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn ichidan() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![
+            AssertedResult::new("たべたがる", Some("食べたがる")),
+            AssertedResult::new("たべたがります", Some("食べたがります")),
+            AssertedResult::new("たべたがった", Some("食べたがった")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.desiderative_other(PoliteLevel::Plain),
+        |v: &Verb| v.desiderative_other(PoliteLevel::Polite),
+        |v: &Verb| {
+            v.desiderative_other(PoliteLevel::Plain)?
+                .into_verb(VerbType::Godan)?
+                .past(PoliteLevel::Plain)
+        },
+    ]);
+}
+
+#[test]
+fn godan() {
+    VerbTest::new(
+        "ならう",
+        Some("習う"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("ならいたがる", Some("習いたがる")),
+            AssertedResult::new("ならいたがります", Some("習いたがります")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.desiderative_other(PoliteLevel::Plain),
+        |v: &Verb| v.desiderative_other(PoliteLevel::Polite),
+    ]);
+}
+
+#[test]
+fn exceptions() {
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![AssertedResult::new("きたがる", Some("来たがる"))],
+    )
+    .run([|v: &Verb| v.desiderative_other(PoliteLevel::Plain)]);
+
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![AssertedResult::new("したがる", None)],
+    )
+    .run([|v: &Verb| v.desiderative_other(PoliteLevel::Plain)]);
+}
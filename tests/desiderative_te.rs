@@ -0,0 +1,45 @@
+mod verb_test;
+
+use jp_inflections::VerbType;
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn ichidan() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![AssertedResult::new("たべたくて", Some("食べたくて"))],
+    )
+    .run([|v| v.desiderative_te()]);
+}
+
+#[test]
+fn godan() {
+    VerbTest::new(
+        "ならう",
+        Some("習う"),
+        VerbType::Godan,
+        vec![AssertedResult::new("ならいたくて", Some("習いたくて"))],
+    )
+    .run([|v| v.desiderative_te()]);
+}
+
+#[test]
+fn exceptions() {
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![AssertedResult::new("きたくて", Some("来たくて"))],
+    )
+    .run([|v| v.desiderative_te()]);
+
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![AssertedResult::new("したくて", None)],
+    )
+    .run([|v| v.desiderative_te()]);
+}
@@ -0,0 +1,102 @@
+use jp_inflections::{error::Error, VerbType, Word};
+
+fn detect(kana: &str, kanji: Option<&str>) -> Option<VerbType> {
+    Word::new(kana, kanji).detect_verb_type()
+}
+
+#[test]
+fn ichidan() {
+    assert_eq!(detect("たべる", Some("食べる")), Some(VerbType::Ichidan));
+    assert_eq!(detect("みる", Some("見る")), Some(VerbType::Ichidan));
+    assert_eq!(detect("きる", Some("着る")), Some(VerbType::Ichidan));
+    assert_eq!(detect("でかける", None), Some(VerbType::Ichidan));
+}
+
+#[test]
+fn godan() {
+    assert_eq!(detect("ならう", Some("習う")), Some(VerbType::Godan));
+    assert_eq!(detect("まもる", Some("守る")), Some(VerbType::Godan));
+    assert_eq!(detect("いらっしゃる", None), Some(VerbType::Godan));
+    assert_eq!(detect("つくる", Some("作る")), Some(VerbType::Godan));
+}
+
+#[test]
+fn godan_exceptions() {
+    assert_eq!(detect("かえる", Some("帰る")), Some(VerbType::Godan));
+    assert_eq!(detect("はしる", Some("走る")), Some(VerbType::Godan));
+    assert_eq!(detect("はいる", Some("入る")), Some(VerbType::Godan));
+    assert_eq!(detect("きる", Some("切る")), Some(VerbType::Godan));
+    assert_eq!(
+        detect("おもいきる", Some("思い切る")),
+        Some(VerbType::Godan)
+    );
+    assert_eq!(
+        detect("きがえる", Some("着替える")),
+        Some(VerbType::Ichidan)
+    );
+    assert_eq!(detect("しる", None), Some(VerbType::Godan));
+    assert_eq!(detect("かえる", Some("変える")), Some(VerbType::Ichidan));
+    assert_eq!(detect("こする", Some("擦る")), Some(VerbType::Godan));
+    assert_eq!(detect("する", Some("擦る")), Some(VerbType::Godan));
+}
+
+#[test]
+fn ambiguous_kana() {
+    // The godan exceptions are only looked up by their kanji reading for these
+    assert_eq!(detect("いる", None), Some(VerbType::Ichidan));
+    assert_eq!(detect("ねる", None), Some(VerbType::Ichidan));
+    assert_eq!(detect("へる", None), Some(VerbType::Ichidan));
+    assert_eq!(detect("いる", Some("要る")), Some(VerbType::Godan));
+    assert_eq!(detect("ねる", Some("練る")), Some(VerbType::Godan));
+}
+
+#[test]
+fn exceptions() {
+    assert_eq!(detect("する", None), Some(VerbType::Exception));
+    assert_eq!(detect("する", Some("為る")), Some(VerbType::Exception));
+    assert_eq!(
+        detect("みみにする", Some("耳にする")),
+        Some(VerbType::Exception)
+    );
+    assert_eq!(detect("くる", Some("来る")), Some(VerbType::Exception));
+    assert_eq!(
+        detect("あそびにくる", Some("遊びに来る")),
+        Some(VerbType::Exception)
+    );
+}
+
+#[test]
+fn not_a_verb() {
+    assert_eq!(detect("えいご", Some("英語")), None);
+    assert_eq!(detect("たかい", Some("高い")), None);
+}
+
+#[test]
+fn into_verb_auto() {
+    let verb = Word::new("かえる", Some("帰る")).into_verb_auto().unwrap();
+    assert_eq!(verb.verb_type(), VerbType::Godan);
+
+    let verb = Word::new("べんきょうする", Some("勉強する"))
+        .into_verb_auto()
+        .unwrap();
+    assert_eq!(verb.verb_type(), VerbType::Exception);
+
+    let verb = Word::new("でかける", None).into_verb_auto().unwrap();
+    assert_eq!(verb.verb_type(), VerbType::Ichidan);
+}
+
+#[test]
+fn into_verb_auto_errors() {
+    assert_eq!(
+        Word::new("きる", None).into_verb_auto(),
+        Err(Error::AmbiguousVerbType)
+    );
+    assert_eq!(
+        Word::new("いる", None).into_verb_auto(),
+        Err(Error::AmbiguousVerbType)
+    );
+    assert_eq!(
+        Word::new("えいご", Some("英語")).into_verb_auto(),
+        Err(Error::NotAVerb)
+    );
+}
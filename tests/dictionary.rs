@@ -1,6 +1,6 @@
 mod verb_test;
 
-use jp_inflections::{self, Verb, VerbType, WordForm};
+use jp_inflections::{self, PoliteLevel, Verb, VerbType};
 use verb_test::{AssertedResult, VerbTest};
 
 #[test]
@@ -15,8 +15,8 @@ fn ichidan() {
         ],
     )
     .run([
-        |v: &Verb| v.dictionary(WordForm::Short),
-        |v: &Verb| v.dictionary(WordForm::Long),
+        |v: &Verb| v.dictionary(PoliteLevel::Plain),
+        |v: &Verb| v.dictionary(PoliteLevel::Polite),
     ]);
 }
 
@@ -32,8 +32,8 @@ fn godan() {
         ],
     )
     .run([
-        |v: &Verb| v.dictionary(WordForm::Short),
-        |v: &Verb| v.dictionary(WordForm::Long),
+        |v: &Verb| v.dictionary(PoliteLevel::Plain),
+        |v: &Verb| v.dictionary(PoliteLevel::Polite),
     ]);
 }
 
@@ -49,8 +49,8 @@ fn suru() {
         ],
     )
     .run([
-        |v: &Verb| v.dictionary(WordForm::Short),
-        |v: &Verb| v.dictionary(WordForm::Long),
+        |v: &Verb| v.dictionary(PoliteLevel::Plain),
+        |v: &Verb| v.dictionary(PoliteLevel::Polite),
     ]);
 
     VerbTest::new(
@@ -63,8 +63,8 @@ fn suru() {
         ],
     )
     .run([
-        |v: &Verb| v.dictionary(WordForm::Short),
-        |v: &Verb| v.dictionary(WordForm::Long),
+        |v: &Verb| v.dictionary(PoliteLevel::Plain),
+        |v: &Verb| v.dictionary(PoliteLevel::Polite),
     ]);
 }
 
@@ -80,8 +80,8 @@ fn kuru() {
         ],
     )
     .run([
-        |v: &Verb| v.dictionary(WordForm::Short),
-        |v: &Verb| v.dictionary(WordForm::Long),
+        |v: &Verb| v.dictionary(PoliteLevel::Plain),
+        |v: &Verb| v.dictionary(PoliteLevel::Polite),
     ]);
 }
 
@@ -97,8 +97,22 @@ fn polite_verbs() {
         ],
     )
     .run([
-        |v: &Verb| v.dictionary(WordForm::Short),
-        |v: &Verb| v.dictionary(WordForm::Long),
+        |v: &Verb| v.dictionary(PoliteLevel::Plain),
+        |v: &Verb| v.dictionary(PoliteLevel::Polite),
+    ]);
+
+    VerbTest::new(
+        "いらっしゃる",
+        Some("居らっしゃる"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("いらっしゃる", Some("居らっしゃる")),
+            AssertedResult::new("いらっしゃいます", Some("居らっしゃいます")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.dictionary(PoliteLevel::Plain),
+        |v: &Verb| v.dictionary(PoliteLevel::Polite),
     ]);
 
     VerbTest::new(
@@ -111,8 +125,8 @@ fn polite_verbs() {
         ],
     )
     .run([
-        |v: &Verb| v.dictionary(WordForm::Short),
-        |v: &Verb| v.dictionary(WordForm::Long),
+        |v: &Verb| v.dictionary(PoliteLevel::Plain),
+        |v: &Verb| v.dictionary(PoliteLevel::Polite),
     ]);
 
     VerbTest::new(
@@ -125,8 +139,8 @@ fn polite_verbs() {
         ],
     )
     .run([
-        |v: &Verb| v.dictionary(WordForm::Short),
-        |v: &Verb| v.dictionary(WordForm::Long),
+        |v: &Verb| v.dictionary(PoliteLevel::Plain),
+        |v: &Verb| v.dictionary(PoliteLevel::Polite),
     ]);
 
     VerbTest::new(
@@ -139,8 +153,8 @@ fn polite_verbs() {
         ],
     )
     .run([
-        |v: &Verb| v.dictionary(WordForm::Short),
-        |v: &Verb| v.dictionary(WordForm::Long),
+        |v: &Verb| v.dictionary(PoliteLevel::Plain),
+        |v: &Verb| v.dictionary(PoliteLevel::Polite),
     ]);
 
     VerbTest::new(
@@ -149,11 +163,59 @@ fn polite_verbs() {
         VerbType::Godan,
         vec![
             AssertedResult::new("なさる", Some("為さる")),
+            // The さ is okurigana of 為さる and not duplicated
             AssertedResult::new("なさいます", Some("為さいます")),
         ],
     )
     .run([
-        |v: &Verb| v.dictionary(WordForm::Short),
-        |v: &Verb| v.dictionary(WordForm::Long),
+        |v: &Verb| v.dictionary(PoliteLevel::Plain),
+        |v: &Verb| v.dictionary(PoliteLevel::Polite),
     ]);
 }
+
+#[test]
+fn polite_verbs_kanji() {
+    VerbTest::new(
+        "クダサル",
+        Some("下さる"),
+        VerbType::Godan,
+        vec![AssertedResult::new("クダサイます", Some("下さいます"))],
+    )
+    .run([|v: &Verb| v.dictionary(PoliteLevel::Polite)]);
+
+    VerbTest::new(
+        "オッシャル",
+        Some("仰る"),
+        VerbType::Godan,
+        vec![AssertedResult::new("オッシャイます", Some("仰います"))],
+    )
+    .run([|v: &Verb| v.dictionary(PoliteLevel::Polite)]);
+}
+
+#[test]
+fn polite_verb_compounds() {
+    VerbTest::new(
+        "べんきょうなさる",
+        Some("勉強なさる"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("べんきょうなさいます", Some("勉強なさいます")),
+            AssertedResult::new("べんきょうなさい", Some("勉強なさい")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.dictionary(PoliteLevel::Polite),
+        |v: &Verb| v.imperative(),
+    ]);
+
+    VerbTest::new(
+        "おこしくださる",
+        Some("お越し下さる"),
+        VerbType::Godan,
+        vec![AssertedResult::new(
+            "おこしくださいます",
+            Some("お越し下さいます"),
+        )],
+    )
+    .run([|v: &Verb| v.dictionary(PoliteLevel::Polite)]);
+}
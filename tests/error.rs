@@ -0,0 +1,37 @@
+use jp_inflections::{error::Error, PoliteLevel, Verb, VerbType, Word};
+
+#[test]
+fn unexpected_ending() {
+    let verb = Verb::new(Word::new("ならん", None), VerbType::Godan);
+    assert_eq!(
+        verb.negative(PoliteLevel::Plain),
+        Err(Error::UnexpectedEnding { ending: 'ん' })
+    );
+
+    let verb = Verb::new(Word::new("", None), VerbType::Godan);
+    assert_eq!(verb.negative(PoliteLevel::Plain), Err(Error::NotAVerb));
+}
+
+#[test]
+fn display() {
+    assert_eq!(
+        Error::NotAVerb.to_string(),
+        "word is not a verb in the dictionary form"
+    );
+    assert_eq!(
+        Error::UnexpectedEnding { ending: 'ん' }.to_string(),
+        "verb ends in 'ん' which isn't conjugable"
+    );
+}
+
+#[test]
+fn boxed() {
+    fn conjugate() -> Result<Word, Box<dyn std::error::Error>> {
+        let verb = Word::new("えいご", Some("英語")).into_verb(VerbType::Godan)?;
+        Ok(verb.negative(PoliteLevel::Plain)?)
+    }
+
+    let err = conjugate().unwrap_err();
+    assert_eq!(err.to_string(), Error::NotAVerb.to_string());
+    assert_eq!(err.downcast_ref::<Error>(), Some(&Error::NotAVerb));
+}
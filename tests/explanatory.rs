@@ -0,0 +1,61 @@
+mod verb_test;
+
+use jp_inflections::{self, PoliteLevel, Verb, VerbType};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn ichidan() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![
+            AssertedResult::new("たべるのだ", Some("食べるのだ")),
+            AssertedResult::new("たべるんだ", Some("食べるんだ")),
+            AssertedResult::new("たべるのです", Some("食べるのです")),
+            AssertedResult::new("たべるんです", Some("食べるんです")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.explanatory(PoliteLevel::Plain, false),
+        |v: &Verb| v.explanatory(PoliteLevel::Plain, true),
+        |v: &Verb| v.explanatory(PoliteLevel::Polite, false),
+        |v: &Verb| v.explanatory(PoliteLevel::Polite, true),
+    ]);
+}
+
+#[test]
+fn godan() {
+    VerbTest::new(
+        "ならう",
+        Some("習う"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("ならうのだ", Some("習うのだ")),
+            AssertedResult::new("ならうんです", Some("習うんです")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.explanatory(PoliteLevel::Plain, false),
+        |v: &Verb| v.explanatory(PoliteLevel::Polite, true),
+    ]);
+}
+
+#[test]
+fn exceptions() {
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![AssertedResult::new("くるんだ", Some("来るんだ"))],
+    )
+    .run([|v: &Verb| v.explanatory(PoliteLevel::Plain, true)]);
+
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![AssertedResult::new("するのです", None)],
+    )
+    .run([|v: &Verb| v.explanatory(PoliteLevel::Polite, false)]);
+}
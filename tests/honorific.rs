@@ -0,0 +1,90 @@
+mod verb_test;
+
+use jp_inflections::{self, error::Error, PoliteLevel, Verb, VerbType, Word};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn ichidan() {
+    VerbTest::new(
+        "かける",
+        Some("掛ける"),
+        VerbType::Ichidan,
+        vec![
+            AssertedResult::new("おかけになる", Some("お掛けになる")),
+            AssertedResult::new("おかけになります", Some("お掛けになります")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.honorific(PoliteLevel::Plain),
+        |v: &Verb| v.honorific(PoliteLevel::Polite),
+    ]);
+}
+
+#[test]
+fn godan() {
+    VerbTest::new(
+        "よむ",
+        Some("読む"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("およみになる", Some("お読みになる")),
+            AssertedResult::new("およみになります", Some("お読みになります")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.honorific(PoliteLevel::Plain),
+        |v: &Verb| v.honorific(PoliteLevel::Polite),
+    ]);
+
+    VerbTest::new(
+        "まつ",
+        Some("待つ"),
+        VerbType::Godan,
+        vec![AssertedResult::new(
+            "おまちになります",
+            Some("お待ちになります"),
+        )],
+    )
+    .run([|v: &Verb| v.honorific(PoliteLevel::Polite)]);
+}
+
+#[test]
+fn exceptions() {
+    let suru = Word::new("する", None)
+        .into_verb(VerbType::Exception)
+        .unwrap();
+    assert_eq!(
+        suru.honorific(PoliteLevel::Plain),
+        Err(Error::UnsupportedForm)
+    );
+
+    let kuru = Word::new("くる", Some("来る"))
+        .into_verb(VerbType::Exception)
+        .unwrap();
+    assert_eq!(
+        kuru.honorific(PoliteLevel::Polite),
+        Err(Error::UnsupportedForm)
+    );
+}
+
+#[test]
+fn polite_verbs() {
+    let nasaru = Word::new("なさる", Some("為さる"))
+        .into_verb(VerbType::Godan)
+        .unwrap();
+    assert_eq!(
+        nasaru.honorific(PoliteLevel::Plain),
+        Err(Error::UnsupportedForm)
+    );
+}
+
+#[test]
+fn one_mora_stem() {
+    let miru = Word::new("みる", Some("見る"))
+        .into_verb(VerbType::Ichidan)
+        .unwrap();
+    assert_eq!(
+        miru.honorific(PoliteLevel::Plain),
+        Err(Error::UnsupportedForm)
+    );
+}
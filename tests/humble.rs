@@ -0,0 +1,71 @@
+mod verb_test;
+
+use jp_inflections::{self, error::Error, PoliteLevel, Verb, VerbType, Word};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn ichidan() {
+    VerbTest::new(
+        "とどける",
+        Some("届ける"),
+        VerbType::Ichidan,
+        vec![
+            AssertedResult::new("おとどけする", Some("お届けする")),
+            AssertedResult::new("おとどけします", Some("お届けします")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.humble(PoliteLevel::Plain),
+        |v: &Verb| v.humble(PoliteLevel::Polite),
+    ]);
+}
+
+#[test]
+fn godan() {
+    VerbTest::new(
+        "もつ",
+        Some("持つ"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("おもちする", Some("お持ちする")),
+            AssertedResult::new("おもちします", Some("お持ちします")),
+            AssertedResult::new("おもちしない", Some("お持ちしない")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.humble(PoliteLevel::Plain),
+        |v: &Verb| v.humble(PoliteLevel::Polite),
+        |v: &Verb| {
+            v.humble(PoliteLevel::Plain)?
+                .into_verb(VerbType::Exception)?
+                .negative(PoliteLevel::Plain)
+        },
+    ]);
+}
+
+#[test]
+fn exceptions() {
+    let suru = Word::new("する", None)
+        .into_verb(VerbType::Exception)
+        .unwrap();
+    assert_eq!(suru.humble(PoliteLevel::Plain), Err(Error::UnsupportedForm));
+
+    let kuru = Word::new("くる", Some("来る"))
+        .into_verb(VerbType::Exception)
+        .unwrap();
+    assert_eq!(
+        kuru.humble(PoliteLevel::Polite),
+        Err(Error::UnsupportedForm)
+    );
+}
+
+#[test]
+fn polite_verbs() {
+    let kudasaru = Word::new("くださる", Some("下さる"))
+        .into_verb(VerbType::Godan)
+        .unwrap();
+    assert_eq!(
+        kudasaru.humble(PoliteLevel::Plain),
+        Err(Error::UnsupportedForm)
+    );
+}
@@ -59,3 +59,103 @@ fn exceptions() {
     )
     .run([|v| v.imperative()]);
 }
+
+#[test]
+fn kureru() {
+    VerbTest::new(
+        "くれる",
+        Some("呉れる"),
+        VerbType::Ichidan,
+        vec![AssertedResult::new("くれ", Some("呉れ"))],
+    )
+    .run([|v| v.imperative()]);
+
+    VerbTest::new(
+        "まってくれる",
+        Some("待ってくれる"),
+        VerbType::Ichidan,
+        vec![AssertedResult::new("まってくれ", Some("待ってくれ"))],
+    )
+    .run([|v| v.imperative()]);
+
+    VerbTest::new(
+        "かくれる",
+        Some("隠れる"),
+        VerbType::Ichidan,
+        vec![AssertedResult::new("かくれろ", Some("隠れろ"))],
+    )
+    .run([|v| v.imperative()]);
+}
+
+#[test]
+fn polite() {
+    VerbTest::new(
+        "くださる",
+        Some("下さる"),
+        VerbType::Godan,
+        vec![AssertedResult::new("ください", Some("下さい"))],
+    )
+    .run([|v| v.imperative()]);
+
+    VerbTest::new(
+        "なさる",
+        Some("為さる"),
+        VerbType::Godan,
+        vec![AssertedResult::new("なさい", Some("為さい"))],
+    )
+    .run([|v| v.imperative()]);
+
+    VerbTest::new(
+        "おっしゃる",
+        Some("仰る"),
+        VerbType::Godan,
+        vec![AssertedResult::new("おっしゃい", Some("仰い"))],
+    )
+    .run([|v| v.imperative()]);
+
+    VerbTest::new(
+        "いらっしゃる",
+        Some("居らっしゃる"),
+        VerbType::Godan,
+        vec![AssertedResult::new("いらっしゃい", Some("居らっしゃい"))],
+    )
+    .run([|v| v.imperative()]);
+}
+
+#[test]
+fn polite_masu() {
+    VerbTest::new(
+        "くださる",
+        Some("下さる"),
+        VerbType::Godan,
+        vec![AssertedResult::new("くださいませ", Some("下さいませ"))],
+    )
+    .run([|v| v.imperative_masu()]);
+
+    VerbTest::new(
+        "なさる",
+        Some("為さる"),
+        VerbType::Godan,
+        vec![AssertedResult::new("なさいませ", Some("為さいませ"))],
+    )
+    .run([|v| v.imperative_masu()]);
+
+    VerbTest::new(
+        "いらっしゃる",
+        Some("居らっしゃる"),
+        VerbType::Godan,
+        vec![AssertedResult::new(
+            "いらっしゃいませ",
+            Some("居らっしゃいませ"),
+        )],
+    )
+    .run([|v| v.imperative_masu()]);
+
+    let verb = jp_inflections::Word::new("たべる", Some("食べる"))
+        .into_verb(VerbType::Ichidan)
+        .unwrap();
+    assert_eq!(
+        verb.imperative_masu(),
+        Err(jp_inflections::error::Error::UnsupportedForm)
+    );
+}
@@ -0,0 +1,324 @@
+use jp_inflections::{Inflection, PoliteLevel, Verb, VerbType, Word};
+
+fn verb(kana: &str, kanji: Option<&str>, verb_type: VerbType) -> Verb {
+    Word::new(kana, kanji).into_verb(verb_type).unwrap()
+}
+
+#[test]
+fn ichidan() {
+    let verb = verb("たべる", Some("食べる"), VerbType::Ichidan);
+
+    assert!(verb
+        .dictionary(PoliteLevel::Plain)
+        .unwrap()
+        .inflections
+        .is_empty());
+    assert_eq!(
+        verb.dictionary(PoliteLevel::Polite).unwrap().inflections,
+        vec![Inflection::Polite]
+    );
+    assert_eq!(
+        verb.negative(PoliteLevel::Plain).unwrap().inflections,
+        vec![Inflection::Negative]
+    );
+    assert_eq!(
+        verb.negative_past(PoliteLevel::Polite).unwrap().inflections,
+        vec![Inflection::Polite, Inflection::Negative, Inflection::Past]
+    );
+    assert_eq!(
+        verb.negative_passive().unwrap().inflections,
+        vec![Inflection::Passive, Inflection::Negative]
+    );
+}
+
+#[test]
+fn godan() {
+    let verb = verb("ならう", Some("習う"), VerbType::Godan);
+
+    assert_eq!(verb.te_form().unwrap().inflections, vec![Inflection::Te]);
+    assert_eq!(
+        verb.past(PoliteLevel::Polite).unwrap().inflections,
+        vec![Inflection::Polite, Inflection::Past]
+    );
+    assert_eq!(
+        verb.potential(PoliteLevel::Polite).unwrap().inflections,
+        vec![Inflection::Potential, Inflection::Polite]
+    );
+    assert_eq!(
+        verb.causative(PoliteLevel::Plain).unwrap().inflections,
+        vec![Inflection::Causative]
+    );
+    assert_eq!(
+        verb.negative_tara().unwrap().inflections,
+        vec![Inflection::Negative, Inflection::Tara]
+    );
+    assert_eq!(
+        verb.desiderative_te().unwrap().inflections,
+        vec![Inflection::Desiderative, Inflection::Te]
+    );
+    assert_eq!(
+        verb.conjugate(Inflection::Volitional, PoliteLevel::Plain)
+            .unwrap()
+            .inflections,
+        vec![Inflection::Volitional]
+    );
+}
+
+#[test]
+fn exceptions() {
+    let suru = verb("する", None, VerbType::Exception);
+    assert_eq!(
+        suru.causative_passive(PoliteLevel::Plain)
+            .unwrap()
+            .inflections,
+        vec![Inflection::CausativePassive]
+    );
+    assert_eq!(
+        suru.imperative().unwrap().inflections,
+        vec![Inflection::Imperative]
+    );
+
+    let kuru = verb("くる", Some("来る"), VerbType::Exception);
+    assert_eq!(
+        kuru.passive(PoliteLevel::Plain).unwrap().inflections,
+        vec![Inflection::Passive]
+    );
+    assert_eq!(
+        kuru.negative(PoliteLevel::Polite).unwrap().inflections,
+        vec![Inflection::Polite, Inflection::Negative]
+    );
+}
+
+#[test]
+fn reconjugated() {
+    let verb = verb("ならう", Some("習う"), VerbType::Godan);
+    let causative = verb
+        .causative(PoliteLevel::Plain)
+        .unwrap()
+        .into_verb(VerbType::Ichidan)
+        .unwrap();
+
+    let word = causative.negative(PoliteLevel::Plain).unwrap();
+    assert_eq!(word.kana, "ならわせない");
+    assert_eq!(
+        word.inflections,
+        vec![Inflection::Causative, Inflection::Negative]
+    );
+}
+
+#[test]
+fn describe() {
+    let verb = verb("たべる", Some("食べる"), VerbType::Ichidan);
+
+    assert_eq!(verb.dictionary(PoliteLevel::Plain).unwrap().describe(), "");
+    assert_eq!(verb.te_form().unwrap().describe(), "te");
+    assert_eq!(
+        verb.negative_past(PoliteLevel::Polite).unwrap().describe(),
+        "polite, negative, past"
+    );
+    assert_eq!(
+        verb.causative_passive(PoliteLevel::Plain)
+            .unwrap()
+            .describe(),
+        "causative passive"
+    );
+    assert_eq!(Inflection::StemPotential.to_string(), "potential stem");
+}
+
+#[test]
+fn special_verb_history() {
+    let kuru = verb("くる", Some("来る"), VerbType::Exception);
+    let past = kuru.past(PoliteLevel::Plain).unwrap();
+    assert_eq!(past.kanji.as_deref(), Some("来た"));
+    assert_eq!(past.inflections, vec![Inflection::Past]);
+
+    // A derived verb keeps its history through the SpecialKuru path
+    let mut word = Word::new("もってくる", Some("持って来る"));
+    word.inflections.push(Inflection::Te);
+    let verb = Verb::new(word, VerbType::Exception);
+
+    let past = verb.past(PoliteLevel::Polite).unwrap();
+    assert_eq!(past.kanji.as_deref(), Some("持って来ました"));
+    assert_eq!(
+        past.inflections,
+        vec![Inflection::Te, Inflection::Polite, Inflection::Past]
+    );
+
+    let passive = verb.passive(PoliteLevel::Plain).unwrap();
+    assert_eq!(passive.kana, "もってこられる");
+    assert_eq!(
+        passive.inflections,
+        vec![Inflection::Te, Inflection::Passive]
+    );
+}
+
+#[test]
+fn word_helpers() {
+    let mut word = Word::new("きた", Some("来た"));
+    word.inflections.push(Inflection::Past);
+
+    let replaced = word
+        .new_with_suffix_replaced("た", Some("た"), "たら", Some("たら"))
+        .unwrap();
+    assert_eq!(replaced.inflections, vec![Inflection::Past]);
+
+    let mut stripped = word.strip_end(1);
+    stripped.push_str("て").push('も');
+    assert_eq!(stripped.inflections, vec![Inflection::Past]);
+}
+
+#[test]
+fn reading_eq() {
+    let verb = verb("ならう", Some("習う"), VerbType::Godan);
+    let negative = verb.negative(PoliteLevel::Polite).unwrap();
+
+    assert!(negative.reading_eq(&Word::new("ならいません", Some("習いません"))));
+    assert!(!negative.reading_eq(&Word::new("ならいません", None)));
+    assert_ne!(negative, Word::new("ならいません", Some("習いません")));
+}
+
+#[test]
+fn compound_forms() {
+    let verb = verb("よむ", Some("読む"), VerbType::Godan);
+
+    assert_eq!(verb.zu().unwrap().inflections, vec![Inflection::Zu]);
+    assert_eq!(
+        verb.shimau(PoliteLevel::Polite).unwrap().inflections,
+        vec![Inflection::Shimau, Inflection::Polite]
+    );
+    assert_eq!(
+        verb.teoku_contracted().unwrap().inflections,
+        vec![Inflection::Teoku]
+    );
+    assert_eq!(
+        verb.honorific(PoliteLevel::Plain).unwrap().inflections,
+        vec![Inflection::Honorific]
+    );
+    assert_eq!(
+        verb.humble(PoliteLevel::Polite).unwrap().inflections,
+        vec![Inflection::Humble, Inflection::Polite]
+    );
+    assert_eq!(
+        verb.sugiru(PoliteLevel::Plain).unwrap().describe(),
+        "excessive"
+    );
+    assert_eq!(
+        verb.sou_hearsay().unwrap().inflections,
+        vec![Inflection::Hearsay]
+    );
+
+    let cases = [
+        (verb.yasui(), vec![Inflection::Yasui]),
+        (verb.nikui(), vec![Inflection::Nikui]),
+        (verb.command_nasai(), vec![Inflection::Nasai]),
+        (verb.conditional_to(), vec![Inflection::To]),
+        (
+            verb.negative_conditional_to(),
+            vec![Inflection::Negative, Inflection::To],
+        ),
+        (verb.conditional_nara(), vec![Inflection::Nara]),
+        (verb.nakucha(), vec![Inflection::Obligation]),
+        (verb.must(false), vec![Inflection::Obligation]),
+        (verb.must(true), vec![Inflection::Obligation]),
+        (verb.must_not(false), vec![Inflection::Prohibition]),
+        (verb.must_not(true), vec![Inflection::Prohibition]),
+        (verb.tewa_contracted(), vec![Inflection::TewaContracted]),
+        (verb.temo_ii(), vec![Inflection::Permission]),
+        (
+            verb.nakutemo_ii(),
+            vec![Inflection::Negative, Inflection::Permission],
+        ),
+        (verb.volitional_to_suru(), vec![Inflection::Attempt]),
+        (verb.volitional_to_omou(), vec![Inflection::Intention]),
+        (
+            verb.desiderative_other(PoliteLevel::Polite),
+            vec![Inflection::DesiderativeOther, Inflection::Polite],
+        ),
+        (verb.negative_nu(), vec![Inflection::Nu]),
+        (verb.negative_zaru(), vec![Inflection::Zaru]),
+        (
+            verb.zaru_wo_enai(),
+            vec![Inflection::Zaru, Inflection::Obligation],
+        ),
+        (verb.zuni(), vec![Inflection::Zuni]),
+        (
+            verb.potential_koto(PoliteLevel::Plain),
+            vec![Inflection::PotentialKoto],
+        ),
+        (verb.potential_uru(), vec![Inflection::PotentialUru]),
+        (verb.negative_naidesu(), vec![Inflection::Naidesu]),
+        (verb.naide(), vec![Inflection::Naide]),
+        (verb.negative_kansai(), vec![Inflection::KansaiNegative]),
+        (
+            verb.polite_negative_question(),
+            vec![
+                Inflection::Polite,
+                Inflection::Negative,
+                Inflection::Invitation,
+            ],
+        ),
+        (
+            verb.sou_appearance_negative(),
+            vec![Inflection::Negative, Inflection::Appearance],
+        ),
+    ];
+    for (word, inflections) in cases {
+        assert_eq!(word.unwrap().inflections, inflections);
+    }
+
+    assert_eq!(verb.temo_ii().unwrap().describe(), "permission");
+    assert_eq!(verb.must_not(false).unwrap().describe(), "prohibition");
+    assert_eq!(
+        verb.tewa_contracted().unwrap().describe(),
+        "contracted tewa"
+    );
+}
+
+#[test]
+fn conjugate_compound_forms() {
+    let verb = verb("たべる", Some("食べる"), VerbType::Ichidan);
+
+    let conjugate = |inflection| {
+        verb.conjugate(inflection, PoliteLevel::Polite)
+            .unwrap()
+            .kanji
+            .unwrap()
+    };
+    assert_eq!(conjugate(Inflection::Zu), "食べず");
+    assert_eq!(conjugate(Inflection::Progressive), "食べています");
+    assert_eq!(conjugate(Inflection::Shimau), "食べてしまいます");
+    assert_eq!(conjugate(Inflection::Teoku), "食べておきます");
+    assert_eq!(conjugate(Inflection::Honorific), "お食べになります");
+    assert_eq!(conjugate(Inflection::Humble), "お食べします");
+    assert_eq!(conjugate(Inflection::Presumptive), "食べるでしょう");
+    assert_eq!(conjugate(Inflection::Explanatory), "食べるのです");
+    assert_eq!(conjugate(Inflection::Excessive), "食べすぎます");
+    assert_eq!(conjugate(Inflection::Appearance), "食べそう");
+    assert_eq!(conjugate(Inflection::Hearsay), "食べるそう");
+    assert_eq!(conjugate(Inflection::Nasai), "食べなさい");
+    assert_eq!(conjugate(Inflection::To), "食べると");
+    assert_eq!(conjugate(Inflection::Nara), "食べるなら");
+    assert_eq!(conjugate(Inflection::Attempt), "食べようとする");
+    assert_eq!(conjugate(Inflection::Intention), "食べようと思う");
+    assert_eq!(conjugate(Inflection::KansaiNegative), "食べへん");
+    assert_eq!(conjugate(Inflection::Nu), "食べぬ");
+    assert_eq!(conjugate(Inflection::Zaru), "食べざる");
+    assert_eq!(conjugate(Inflection::Zuni), "食べずに");
+    assert_eq!(conjugate(Inflection::Obligation), "食べなければならない");
+    assert_eq!(conjugate(Inflection::Prohibition), "食べてはいけない");
+    assert_eq!(conjugate(Inflection::Permission), "食べてもいい");
+    assert_eq!(conjugate(Inflection::PotentialKoto), "食べることができます");
+    assert_eq!(conjugate(Inflection::PotentialUru), "食べうる");
+    assert_eq!(conjugate(Inflection::Naidesu), "食べないです");
+    assert_eq!(conjugate(Inflection::Naide), "食べないで");
+    assert_eq!(conjugate(Inflection::Invitation), "食べませんか");
+    assert_eq!(conjugate(Inflection::Yasui), "食べやすい");
+    assert_eq!(conjugate(Inflection::Nikui), "食べにくい");
+    assert_eq!(conjugate(Inflection::DesiderativeOther), "食べたがります");
+    assert_eq!(conjugate(Inflection::TewaContracted), "食べちゃ");
+
+    assert_eq!(
+        verb.progressive(PoliteLevel::Plain).unwrap().inflections,
+        vec![Inflection::Progressive]
+    );
+}
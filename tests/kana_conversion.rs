@@ -0,0 +1,42 @@
+use jp_inflections::{PoliteLevel, Verb, VerbType, Word};
+
+const HIRAGANA: &str =
+    "あいうえおかきくけこさしすせそたちつてとなにぬねのはひふへほまみむめもやゆよらりるれろわをん\
+                        がぎぐげござじずぜぞだぢづでどばびぶべぼぱぴぷぺぽぁぃぅぇぉっゃゅょゎゔ";
+const KATAKANA: &str =
+    "アイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワヲン\
+                        ガギグゲゴザジズゼゾダヂヅデドバビブベボパピプペポァィゥェォッャュョヮヴ";
+
+#[test]
+fn to_katakana() {
+    assert_eq!(Word::new(HIRAGANA, None).to_katakana().kana, KATAKANA);
+
+    let word = Word::new("らーめん", Some("拉麺")).to_katakana();
+    assert_eq!(word.kana, "ラーメン");
+    assert_eq!(word.kanji, Some(String::from("拉麺")));
+}
+
+#[test]
+fn to_hiragana() {
+    assert_eq!(Word::new(KATAKANA, None).to_hiragana().kana, HIRAGANA);
+    assert_eq!(Word::new("コーヒー", None).to_hiragana().kana, "こーひー");
+    assert_eq!(Word::new("ABC", None).to_hiragana().kana, "ABC");
+}
+
+#[test]
+fn round_trip() {
+    let word = Word::new(HIRAGANA, None);
+    assert_eq!(word.to_katakana().to_hiragana(), word);
+
+    let word = Word::new(KATAKANA, None);
+    assert_eq!(word.to_hiragana().to_katakana(), word);
+}
+
+#[test]
+fn conjugated() {
+    let verb = Verb::new(Word::new("ならう", Some("習う")), VerbType::Godan);
+    let word = verb.negative(PoliteLevel::Polite).unwrap();
+
+    assert_eq!(word.to_katakana().kana, "ナライマセン");
+    assert_eq!(word.to_katakana().to_hiragana(), word);
+}
@@ -0,0 +1,48 @@
+use jp_inflections::{Inflection, PoliteLevel, VerbType, Word};
+
+#[test]
+fn kana_only() {
+    let word = Word::new("ならう", Some("習う")).kana_only();
+    assert_eq!(word.kana, "ならう");
+    assert_eq!(word.kanji, None);
+}
+
+#[test]
+fn conjugate_kana() {
+    let verbs = [
+        ("たべる", "食べる", VerbType::Ichidan, "たべなかった"),
+        ("ならう", "習う", VerbType::Godan, "ならわなかった"),
+        ("くる", "来る", VerbType::Exception, "こなかった"),
+        (
+            "べんきょうする",
+            "勉強する",
+            VerbType::Exception,
+            "べんきょうしなかった",
+        ),
+        (
+            "かいてある",
+            "書いてある",
+            VerbType::Godan,
+            "かいてなかった",
+        ),
+    ];
+
+    for (kana, kanji, verb_type, expected) in verbs.iter().copied() {
+        let word = Word::new(kana, Some(kanji));
+        let verb = word.clone().into_verb(verb_type).unwrap();
+        let kana_verb = word.kana_only().into_verb(verb_type).unwrap();
+
+        let past = verb.negative_past(PoliteLevel::Plain).unwrap();
+        assert_eq!(past.kana, expected);
+
+        let kana_past = kana_verb.negative_past(PoliteLevel::Plain).unwrap();
+        assert_eq!(kana_past.kana, expected);
+        assert_eq!(kana_past.kanji, None);
+
+        let negative = verb
+            .conjugate(Inflection::Negative, PoliteLevel::Polite)
+            .unwrap();
+        let kana_negative = kana_verb.conjugate_kana(Inflection::Negative, PoliteLevel::Polite);
+        assert_eq!(kana_negative.unwrap(), negative.kana);
+    }
+}
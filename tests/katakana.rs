@@ -0,0 +1,69 @@
+mod verb_test;
+
+use jp_inflections::{
+    syllable::{Info, Row, Syllable},
+    umlaut::Umlaut,
+    PoliteLevel, Verb, VerbType,
+};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn syllable_info() {
+    assert_eq!(
+        Syllable::from('カ').get_info(),
+        Some(Info {
+            row: Row::K,
+            umlaut: Umlaut::A
+        })
+    );
+    assert_eq!(
+        Syllable::from('ヲ').get_info(),
+        Some(Info {
+            row: Row::W,
+            umlaut: Umlaut::O
+        })
+    );
+}
+
+#[test]
+fn godan() {
+    VerbTest::new(
+        "サボる",
+        None,
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("サボらない", None),
+            AssertedResult::new("サボった", None),
+            AssertedResult::new("サボります", None),
+        ],
+    )
+    .run([
+        |v: &Verb| v.negative(PoliteLevel::Plain),
+        |v: &Verb| v.past(PoliteLevel::Plain),
+        |v: &Verb| v.dictionary(PoliteLevel::Polite),
+    ]);
+
+    VerbTest::new(
+        "ナラウ",
+        None,
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("ナラワない", None),
+            AssertedResult::new("ナラッて", None),
+            AssertedResult::new("ナライます", None),
+        ],
+    )
+    .run([
+        |v: &Verb| v.negative(PoliteLevel::Plain),
+        |v: &Verb| v.te_form(),
+        |v: &Verb| v.dictionary(PoliteLevel::Polite),
+    ]);
+
+    VerbTest::new(
+        "ヨム",
+        None,
+        VerbType::Godan,
+        vec![AssertedResult::new("ヨンだ", None)],
+    )
+    .run([|v: &Verb| v.past(PoliteLevel::Plain)]);
+}
@@ -0,0 +1,114 @@
+use jp_inflections::{keigo, PoliteLevel, Verb, VerbType, Word};
+
+fn verb(kana: &str, kanji: Option<&str>, verb_type: VerbType) -> Verb {
+    Word::new(kana, kanji).into_verb(verb_type).unwrap()
+}
+
+fn polite(verb: Option<Verb>) -> Word {
+    let word = verb.unwrap().dictionary(PoliteLevel::Polite).unwrap();
+    Word::new(word.kana, word.kanji)
+}
+
+#[test]
+fn honorific() {
+    let taberu = verb("たべる", Some("食べる"), VerbType::Ichidan);
+    let honorific = keigo::honorific_lexical(&taberu).unwrap();
+    assert_eq!(honorific.verb_type(), VerbType::Godan);
+    assert_eq!(honorific.word, Word::new("めしあがる", Some("召し上がる")));
+
+    assert_eq!(
+        polite(keigo::honorific_lexical(&verb(
+            "する",
+            None,
+            VerbType::Exception
+        ))),
+        Word::new("なさいます", None)
+    );
+    assert_eq!(
+        polite(keigo::honorific_lexical(&verb(
+            "いく",
+            Some("行く"),
+            VerbType::Godan
+        ))),
+        Word::new("いらっしゃいます", Some("いらっしゃいます"))
+    );
+    assert_eq!(
+        polite(keigo::honorific_lexical(&verb(
+            "みる",
+            Some("見る"),
+            VerbType::Ichidan
+        ))),
+        Word::new("ごらんになります", Some("ご覧になります"))
+    );
+}
+
+#[test]
+fn humble() {
+    assert_eq!(
+        polite(keigo::humble_lexical(&verb(
+            "する",
+            None,
+            VerbType::Exception
+        ))),
+        Word::new("いたします", None)
+    );
+    assert_eq!(
+        polite(keigo::humble_lexical(&verb(
+            "いう",
+            Some("言う"),
+            VerbType::Godan
+        ))),
+        Word::new("もうします", Some("申します"))
+    );
+    assert_eq!(
+        polite(keigo::humble_lexical(&verb(
+            "たべる",
+            Some("食べる"),
+            VerbType::Ichidan
+        ))),
+        Word::new("いただきます", Some("頂きます"))
+    );
+    assert_eq!(
+        polite(keigo::humble_lexical(&verb(
+            "いる",
+            Some("居る"),
+            VerbType::Ichidan
+        ))),
+        Word::new("おります", Some("居ります"))
+    );
+    assert_eq!(
+        polite(keigo::humble_lexical(&verb(
+            "しる",
+            Some("知る"),
+            VerbType::Godan
+        ))),
+        Word::new("ぞんじます", Some("存じます"))
+    );
+}
+
+#[test]
+fn compound_suru() {
+    let benkyou = verb("べんきょうする", Some("勉強する"), VerbType::Exception);
+    assert_eq!(
+        polite(keigo::honorific_lexical(&benkyou)),
+        Word::new("べんきょうなさいます", Some("勉強なさいます"))
+    );
+    assert_eq!(
+        polite(keigo::humble_lexical(&benkyou)),
+        Word::new("べんきょういたします", Some("勉強いたします"))
+    );
+}
+
+#[test]
+fn no_lexical_form() {
+    let yomu = verb("よむ", Some("読む"), VerbType::Godan);
+    assert!(keigo::honorific_lexical(&yomu).is_none());
+    assert!(keigo::humble_lexical(&yomu).is_none());
+
+    // 要る shares its reading with 居る but has no keigo counterpart
+    let iru = verb("いる", Some("要る"), VerbType::Godan);
+    assert!(keigo::honorific_lexical(&iru).is_none());
+
+    let kuru = verb("もってくる", Some("持って来る"), VerbType::Exception);
+    assert!(keigo::humble_lexical(&kuru).is_none());
+}
@@ -0,0 +1,71 @@
+mod verb_test;
+
+use jp_inflections::{self, Verb, VerbType};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn ichidan() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![
+            AssertedResult::new("たべなければならない", Some("食べなければならない")),
+            AssertedResult::new("たべなきゃ", Some("食べなきゃ")),
+        ],
+    )
+    .run([|v: &Verb| v.must(false), |v: &Verb| v.must(true)]);
+}
+
+#[test]
+fn godan() {
+    VerbTest::new(
+        "かう",
+        Some("買う"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("かわなければならない", Some("買わなければならない")),
+            AssertedResult::new("かわなきゃ", Some("買わなきゃ")),
+        ],
+    )
+    .run([|v: &Verb| v.must(false), |v: &Verb| v.must(true)]);
+}
+
+#[test]
+fn exception() {
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("しなければならない", None),
+            AssertedResult::new("しなきゃ", None),
+        ],
+    )
+    .run([|v: &Verb| v.must(false), |v: &Verb| v.must(true)]);
+
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("こなければならない", Some("来なければならない")),
+            AssertedResult::new("こなきゃ", Some("来なきゃ")),
+        ],
+    )
+    .run([|v: &Verb| v.must(false), |v: &Verb| v.must(true)]);
+}
+
+#[test]
+fn aru() {
+    VerbTest::new(
+        "ある",
+        Some("有る"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("なければならない", Some("なければならない")),
+            AssertedResult::new("なきゃ", Some("なきゃ")),
+        ],
+    )
+    .run([|v: &Verb| v.must(false), |v: &Verb| v.must(true)]);
+}
@@ -0,0 +1,68 @@
+mod verb_test;
+
+use jp_inflections::{self, Verb, VerbType};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn ichidan() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![
+            AssertedResult::new("たべてはいけない", Some("食べてはいけない")),
+            AssertedResult::new("たべちゃいけない", Some("食べちゃいけない")),
+        ],
+    )
+    .run([|v: &Verb| v.must_not(false), |v: &Verb| v.must_not(true)]);
+}
+
+#[test]
+fn godan() {
+    VerbTest::new(
+        "のむ",
+        Some("飲む"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("のんではいけない", Some("飲んではいけない")),
+            AssertedResult::new("のんじゃいけない", Some("飲んじゃいけない")),
+        ],
+    )
+    .run([|v: &Verb| v.must_not(false), |v: &Verb| v.must_not(true)]);
+
+    VerbTest::new(
+        "いく",
+        Some("行く"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("いってはいけない", Some("行ってはいけない")),
+            AssertedResult::new("いっちゃいけない", Some("行っちゃいけない")),
+        ],
+    )
+    .run([|v: &Verb| v.must_not(false), |v: &Verb| v.must_not(true)]);
+}
+
+#[test]
+fn exception() {
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("してはいけない", None),
+            AssertedResult::new("しちゃいけない", None),
+        ],
+    )
+    .run([|v: &Verb| v.must_not(false), |v: &Verb| v.must_not(true)]);
+
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("きてはいけない", Some("来てはいけない")),
+            AssertedResult::new("きちゃいけない", Some("来ちゃいけない")),
+        ],
+    )
+    .run([|v: &Verb| v.must_not(false), |v: &Verb| v.must_not(true)]);
+}
@@ -0,0 +1,56 @@
+mod verb_test;
+
+use jp_inflections::{self, VerbType};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn ichidan() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![
+            AssertedResult::new("たべないで", Some("食べないで")),
+            AssertedResult::new("たべなくて", Some("食べなくて")),
+        ],
+    )
+    .run([|v| v.naide(), |v| v.negative_te_form()]);
+}
+
+#[test]
+fn godan() {
+    VerbTest::new(
+        "いく",
+        Some("行く"),
+        VerbType::Godan,
+        vec![AssertedResult::new("いかないで", Some("行かないで"))],
+    )
+    .run([|v| v.naide()]);
+
+    VerbTest::new(
+        "あそぶ",
+        Some("遊ぶ"),
+        VerbType::Godan,
+        vec![AssertedResult::new("あそばないで", Some("遊ばないで"))],
+    )
+    .run([|v| v.naide()]);
+}
+
+#[test]
+fn exceptions() {
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![AssertedResult::new("しないで", None)],
+    )
+    .run([|v| v.naide()]);
+
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![AssertedResult::new("こないで", Some("来ないで"))],
+    )
+    .run([|v| v.naide()]);
+}
@@ -1,6 +1,6 @@
 mod verb_test;
 
-use jp_inflections::{self, VerbType, WordForm};
+use jp_inflections::{self, PoliteLevel, VerbType};
 use verb_test::{AssertedResult, VerbTest};
 
 #[test]
@@ -15,8 +15,8 @@ fn ichidan() {
         ],
     )
     .run([
-        |v| v.negative(WordForm::Short),
-        |v| v.negative(WordForm::Long),
+        |v| v.negative(PoliteLevel::Plain),
+        |v| v.negative(PoliteLevel::Polite),
     ]);
 }
 
@@ -32,8 +32,25 @@ fn godan() {
         ],
     )
     .run([
-        |v| v.negative(WordForm::Short),
-        |v| v.negative(WordForm::Long),
+        |v| v.negative(PoliteLevel::Plain),
+        |v| v.negative(PoliteLevel::Polite),
+    ]);
+}
+
+#[test]
+fn godan_nu() {
+    VerbTest::new(
+        "しぬ",
+        Some("死ぬ"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("しなない", Some("死なない")),
+            AssertedResult::new("しにません", Some("死にません")),
+        ],
+    )
+    .run([
+        |v| v.negative(PoliteLevel::Plain),
+        |v| v.negative(PoliteLevel::Polite),
     ]);
 }
 
@@ -49,8 +66,8 @@ fn exceptions() {
         ],
     )
     .run([
-        |v| v.negative(WordForm::Short),
-        |v| v.negative(WordForm::Long),
+        |v| v.negative(PoliteLevel::Plain),
+        |v| v.negative(PoliteLevel::Polite),
     ]);
 
     VerbTest::new(
@@ -63,8 +80,8 @@ fn exceptions() {
         ],
     )
     .run([
-        |v| v.negative(WordForm::Short),
-        |v| v.negative(WordForm::Long),
+        |v| v.negative(PoliteLevel::Plain),
+        |v| v.negative(PoliteLevel::Polite),
     ]);
 
     VerbTest::new(
@@ -77,8 +94,8 @@ fn exceptions() {
         ],
     )
     .run([
-        |v| v.negative(WordForm::Short),
-        |v| v.negative(WordForm::Long),
+        |v| v.negative(PoliteLevel::Plain),
+        |v| v.negative(PoliteLevel::Polite),
     ]);
 
     VerbTest::new(
@@ -91,8 +108,8 @@ fn exceptions() {
         ],
     )
     .run([
-        |v| v.negative(WordForm::Short),
-        |v| v.negative(WordForm::Long),
+        |v| v.negative(PoliteLevel::Plain),
+        |v| v.negative(PoliteLevel::Polite),
     ]);
 
     VerbTest::new(
@@ -105,7 +122,38 @@ fn exceptions() {
         ],
     )
     .run([
-        |v| v.negative(WordForm::Short),
-        |v| v.negative(WordForm::Long),
+        |v| v.negative(PoliteLevel::Plain),
+        |v| v.negative(PoliteLevel::Polite),
+    ]);
+}
+
+#[test]
+fn aru() {
+    VerbTest::new(
+        "ある",
+        Some("有る"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("ない", Some("ない")),
+            AssertedResult::new("ありません", Some("有りません")),
+        ],
+    )
+    .run([
+        |v| v.negative(PoliteLevel::Plain),
+        |v| v.negative(PoliteLevel::Polite),
+    ]);
+
+    VerbTest::new(
+        "かいてある",
+        Some("書いてある"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("かいてない", Some("書いてない")),
+            AssertedResult::new("かいてありません", Some("書いてありません")),
+        ],
+    )
+    .run([
+        |v| v.negative(PoliteLevel::Plain),
+        |v| v.negative(PoliteLevel::Polite),
     ]);
 }
@@ -0,0 +1,97 @@
+mod verb_test;
+
+use jp_inflections::{self, PoliteLevel, VerbType};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn ichidan() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![
+            AssertedResult::new("たべさせられない", Some("食べさせられない")),
+            AssertedResult::new("たべさせられません", Some("食べさせられません")),
+        ],
+    )
+    .run([
+        |v| v.negative_causative_passive(PoliteLevel::Plain),
+        |v| v.negative_causative_passive(PoliteLevel::Polite),
+    ]);
+}
+
+#[test]
+fn godan() {
+    VerbTest::new(
+        "ならう",
+        Some("習う"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("ならわされない", Some("習わされない")),
+            AssertedResult::new("ならわされません", Some("習わされません")),
+        ],
+    )
+    .run([
+        |v| v.negative_causative_passive(PoliteLevel::Plain),
+        |v| v.negative_causative_passive(PoliteLevel::Polite),
+    ]);
+
+    VerbTest::new(
+        "まつ",
+        Some("待つ"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("またされない", Some("待たされない")),
+            AssertedResult::new("またされません", Some("待たされません")),
+        ],
+    )
+    .run([
+        |v| v.negative_causative_passive(PoliteLevel::Plain),
+        |v| v.negative_causative_passive(PoliteLevel::Polite),
+    ]);
+}
+
+#[test]
+fn exceptions() {
+    VerbTest::new(
+        "する",
+        Some("為る"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("させられない", Some("為せられない")),
+            AssertedResult::new("させられません", Some("為せられません")),
+        ],
+    )
+    .run([
+        |v| v.negative_causative_passive(PoliteLevel::Plain),
+        |v| v.negative_causative_passive(PoliteLevel::Polite),
+    ]);
+
+    VerbTest::new(
+        "べんきょうする",
+        Some("勉強する"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("べんきょうさせられない", Some("勉強させられない")),
+            AssertedResult::new("べんきょうさせられません", Some("勉強させられません")),
+        ],
+    )
+    .run([
+        |v| v.negative_causative_passive(PoliteLevel::Plain),
+        |v| v.negative_causative_passive(PoliteLevel::Polite),
+    ]);
+
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("こさせられない", Some("来させられない")),
+            AssertedResult::new("こさせられません", Some("来させられません")),
+        ],
+    )
+    .run([
+        |v| v.negative_causative_passive(PoliteLevel::Plain),
+        |v| v.negative_causative_passive(PoliteLevel::Polite),
+    ]);
+}
@@ -0,0 +1,64 @@
+mod verb_test;
+
+use jp_inflections::{self, NegativeConnective, VerbType};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn ichidan() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![
+            AssertedResult::new("たべなくて", Some("食べなくて")),
+            AssertedResult::new("たべないで", Some("食べないで")),
+            AssertedResult::new("たべずに", Some("食べずに")),
+            AssertedResult::new("たべませんで", Some("食べませんで")),
+        ],
+    )
+    .run([
+        |v| v.negative_connective(NegativeConnective::Nakute),
+        |v| v.negative_connective(NegativeConnective::Naide),
+        |v| v.negative_connective(NegativeConnective::Zuni),
+        |v| v.negative_connective(NegativeConnective::Masende),
+    ]);
+}
+
+#[test]
+fn exceptions() {
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("しなくて", None),
+            AssertedResult::new("しないで", None),
+            AssertedResult::new("せずに", None),
+            AssertedResult::new("しませんで", None),
+        ],
+    )
+    .run([
+        |v| v.negative_connective(NegativeConnective::Nakute),
+        |v| v.negative_connective(NegativeConnective::Naide),
+        |v| v.negative_connective(NegativeConnective::Zuni),
+        |v| v.negative_connective(NegativeConnective::Masende),
+    ]);
+
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("こなくて", Some("来なくて")),
+            AssertedResult::new("こないで", Some("来ないで")),
+            AssertedResult::new("こずに", Some("来ずに")),
+            AssertedResult::new("きませんで", Some("来ませんで")),
+        ],
+    )
+    .run([
+        |v| v.negative_connective(NegativeConnective::Nakute),
+        |v| v.negative_connective(NegativeConnective::Naide),
+        |v| v.negative_connective(NegativeConnective::Zuni),
+        |v| v.negative_connective(NegativeConnective::Masende),
+    ]);
+}
@@ -0,0 +1,78 @@
+mod verb_test;
+
+use jp_inflections::{self, Verb, VerbType};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn ichidan() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![AssertedResult::new("たべへん", Some("食べへん"))],
+    )
+    .run([|v: &Verb| v.negative_kansai()]);
+}
+
+#[test]
+fn godan() {
+    VerbTest::new(
+        "いく",
+        Some("行く"),
+        VerbType::Godan,
+        vec![AssertedResult::new("いかへん", Some("行かへん"))],
+    )
+    .run([|v: &Verb| v.negative_kansai()]);
+
+    VerbTest::new(
+        "かう",
+        Some("買う"),
+        VerbType::Godan,
+        vec![AssertedResult::new("かわへん", Some("買わへん"))],
+    )
+    .run([|v: &Verb| v.negative_kansai()]);
+}
+
+#[test]
+fn suru() {
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![AssertedResult::new("せーへん", None)],
+    )
+    .run([|v: &Verb| v.negative_kansai()]);
+
+    VerbTest::new(
+        "べんきょうする",
+        Some("勉強する"),
+        VerbType::Exception,
+        vec![AssertedResult::new(
+            "べんきょうせーへん",
+            Some("勉強せーへん"),
+        )],
+    )
+    .run([|v: &Verb| v.negative_kansai()]);
+}
+
+#[test]
+fn kuru() {
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![AssertedResult::new("けーへん", Some("来ーへん"))],
+    )
+    .run([|v: &Verb| v.negative_kansai()]);
+
+    VerbTest::new(
+        "もってくる",
+        Some("持って来る"),
+        VerbType::Exception,
+        vec![AssertedResult::new(
+            "もってけーへん",
+            Some("持って来ーへん"),
+        )],
+    )
+    .run([|v: &Verb| v.negative_kansai()]);
+}
@@ -0,0 +1,65 @@
+mod verb_test;
+
+use jp_inflections::{self, Inflection, Verb, VerbType, Word};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn regular() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![AssertedResult::new("たべないです", Some("食べないです"))],
+    )
+    .run([|v: &Verb| v.negative_naidesu()]);
+
+    VerbTest::new(
+        "いく",
+        Some("行く"),
+        VerbType::Godan,
+        vec![AssertedResult::new("いかないです", Some("行かないです"))],
+    )
+    .run([|v: &Verb| v.negative_naidesu()]);
+}
+
+#[test]
+fn exception() {
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![AssertedResult::new("しないです", None)],
+    )
+    .run([|v: &Verb| v.negative_naidesu()]);
+
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![AssertedResult::new("こないです", Some("来ないです"))],
+    )
+    .run([|v: &Verb| v.negative_naidesu()]);
+}
+
+#[test]
+fn aru() {
+    VerbTest::new(
+        "ある",
+        Some("有る"),
+        VerbType::Godan,
+        vec![AssertedResult::new("ないです", Some("ないです"))],
+    )
+    .run([|v: &Verb| v.negative_naidesu()]);
+}
+
+#[test]
+fn inflections() {
+    let verb = Word::new("よむ", Some("読む"))
+        .into_verb(VerbType::Godan)
+        .unwrap();
+    assert_eq!(
+        verb.negative_naidesu().unwrap().inflections,
+        vec![Inflection::Naidesu]
+    );
+    assert_eq!(verb.negative_naidesu().unwrap().describe(), "naidesu");
+}
@@ -0,0 +1,53 @@
+mod verb_test;
+
+use jp_inflections::VerbType;
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn ichidan() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![AssertedResult::new("たべぬ", Some("食べぬ"))],
+    )
+    .run([|v| v.negative_nu()]);
+}
+
+#[test]
+fn godan() {
+    VerbTest::new(
+        "ならう",
+        Some("習う"),
+        VerbType::Godan,
+        vec![AssertedResult::new("ならわぬ", Some("習わぬ"))],
+    )
+    .run([|v| v.negative_nu()]);
+}
+
+#[test]
+fn exceptions() {
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![AssertedResult::new("せぬ", None)],
+    )
+    .run([|v| v.negative_nu()]);
+
+    VerbTest::new(
+        "みみにする",
+        Some("耳にする"),
+        VerbType::Exception,
+        vec![AssertedResult::new("みみにせぬ", Some("耳にせぬ"))],
+    )
+    .run([|v| v.negative_nu()]);
+
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![AssertedResult::new("こぬ", Some("来ぬ"))],
+    )
+    .run([|v| v.negative_nu()]);
+}
@@ -9,7 +9,7 @@ fn ichidan() {
         "でかける",
         Some("出かける"),
         VerbType::Ichidan,
-        vec![AssertedResult::new("でかけるまい", Some("出かけるまい"))],
+        vec![AssertedResult::new("でかけまい", Some("出かけまい"))],
     )
     .run([|v: &Verb| v.negative_volitional()]);
 }
@@ -31,7 +31,7 @@ fn suru() {
         "する",
         None,
         VerbType::Exception,
-        vec![AssertedResult::new("するまい", None)],
+        vec![AssertedResult::new("すまい", None)],
     )
     .run([|v: &Verb| v.negative_volitional()]);
 
@@ -39,7 +39,7 @@ fn suru() {
         "みみにする",
         Some("耳にする"),
         VerbType::Exception,
-        vec![AssertedResult::new("みみにするまい", Some("耳にするまい"))],
+        vec![AssertedResult::new("みみにすまい", Some("耳にすまい"))],
     )
     .run([|v: &Verb| v.negative_volitional()]);
 }
@@ -50,7 +50,7 @@ fn kuru() {
         "くる",
         Some("来る"),
         VerbType::Exception,
-        vec![AssertedResult::new("くるまい", Some("来るまい"))],
+        vec![AssertedResult::new("こまい", Some("来まい"))],
     )
     .run([|v: &Verb| v.negative_volitional()]);
 }
@@ -0,0 +1,57 @@
+mod verb_test;
+
+use jp_inflections::{self, Verb, VerbType};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn ichidan() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![
+            AssertedResult::new("たべざる", Some("食べざる")),
+            AssertedResult::new("たべざるをえない", Some("食べざるを得ない")),
+        ],
+    )
+    .run([|v: &Verb| v.negative_zaru(), |v: &Verb| v.zaru_wo_enai()]);
+}
+
+#[test]
+fn godan() {
+    VerbTest::new(
+        "いう",
+        Some("言う"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("いわざる", Some("言わざる")),
+            AssertedResult::new("いわざるをえない", Some("言わざるを得ない")),
+        ],
+    )
+    .run([|v: &Verb| v.negative_zaru(), |v: &Verb| v.zaru_wo_enai()]);
+}
+
+#[test]
+fn exceptions() {
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("せざる", None),
+            AssertedResult::new("せざるをえない", None),
+        ],
+    )
+    .run([|v: &Verb| v.negative_zaru(), |v: &Verb| v.zaru_wo_enai()]);
+
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("こざる", Some("来ざる")),
+            AssertedResult::new("こざるをえない", Some("来ざるを得ない")),
+        ],
+    )
+    .run([|v: &Verb| v.negative_zaru(), |v: &Verb| v.zaru_wo_enai()]);
+}
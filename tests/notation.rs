@@ -0,0 +1,64 @@
+use jp_inflections::{error::Error, PoliteLevel, Verb, VerbType, Word};
+
+#[test]
+fn from_notation() {
+    assert_eq!(
+        Word::from_notation("習う(ならう)"),
+        Ok(Word::new("ならう", Some("習う")))
+    );
+    assert_eq!(
+        Word::from_notation(" 来る（くる） "),
+        Ok(Word::new("くる", Some("来る")))
+    );
+    assert_eq!(
+        Word::from_notation("コピーする"),
+        Ok(Word::new("コピーする", None))
+    );
+    assert_eq!(
+        Word::from_notation("たべる(たべる)"),
+        Ok(Word::new("たべる", None))
+    );
+}
+
+#[test]
+fn malformed() {
+    for notation in [
+        "",
+        "食べる",
+        "習う(ならう",
+        "習う(ならう)です",
+        "(ならう)",
+        "習う()",
+        "習う(習う)",
+    ]
+    .iter()
+    {
+        assert_eq!(
+            Word::from_notation(notation),
+            Err(Error::InvalidNotation),
+            "{}",
+            notation
+        );
+    }
+}
+
+#[test]
+fn from_str_pair() {
+    let verb = Verb::from_str_pair("習う(ならう)", None, VerbType::Godan).unwrap();
+    assert_eq!(
+        verb.past(PoliteLevel::Polite).unwrap().kanji.as_deref(),
+        Some("習いました")
+    );
+
+    let verb = Verb::from_str_pair("する", Some("する"), VerbType::Exception).unwrap();
+    assert_eq!(verb.word, Word::new("する", None));
+
+    assert_eq!(
+        Verb::from_str_pair("学生(がくせい)", None, VerbType::Godan),
+        Err(Error::NotAVerb)
+    );
+    assert_eq!(
+        Verb::from_str_pair("食べる", None, VerbType::Ichidan),
+        Err(Error::InvalidNotation)
+    );
+}
@@ -0,0 +1,57 @@
+use jp_inflections::{PoliteLevel, VerbType, Word};
+
+fn split(stem: &str, tail: &str) -> Option<(String, String)> {
+    Some((stem.to_owned(), tail.to_owned()))
+}
+
+#[test]
+fn conjugated() {
+    let verb = Word::new("ならう", Some("習う"))
+        .into_verb(VerbType::Godan)
+        .unwrap();
+    let past = verb.past(PoliteLevel::Polite).unwrap();
+    assert_eq!(past.okurigana_split(), split("習", "いました"));
+
+    let verb = Word::new("くる", Some("来る"))
+        .into_verb(VerbType::Exception)
+        .unwrap();
+    let negative = verb.negative(PoliteLevel::Plain).unwrap();
+    assert_eq!(negative.okurigana_split(), split("来", "ない"));
+}
+
+#[test]
+fn inner_kana() {
+    let word = Word::new("もっていく", Some("持って行く"));
+    assert_eq!(word.okurigana_split(), split("持って行", "く"));
+}
+
+#[test]
+fn kana_only() {
+    let word = Word::new("たべる", Some("たべる"));
+    assert_eq!(word.okurigana_split(), split("", "たべる"));
+    assert_eq!(Word::new("たべる", None).okurigana_split(), None);
+}
+
+#[test]
+fn with_kanji() {
+    // The kanji reading is shorter than the kana reading
+    let verb = Word::new("わかる", None)
+        .with_kanji("分かる")
+        .into_verb(VerbType::Godan)
+        .unwrap();
+    let negative = verb.negative_past(PoliteLevel::Polite).unwrap();
+    assert_eq!(negative.kana, "わかりませんでした");
+    assert_eq!(negative.kanji.as_deref(), Some("分かりませんでした"));
+
+    let verb = Word::new("うけとる", None)
+        .with_kanji("受取る")
+        .into_verb(VerbType::Godan)
+        .unwrap();
+    let te = verb.te_form().unwrap();
+    assert_eq!(te.kana, "うけとって");
+    assert_eq!(te.kanji.as_deref(), Some("受取って"));
+    assert_eq!(te.okurigana_split(), split("受取", "って"));
+
+    let verb = Word::new("たべる", Some("食べる")).with_kanji("喰べる");
+    assert_eq!(verb.kanji.as_deref(), Some("喰べる"));
+}
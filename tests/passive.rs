@@ -1,6 +1,6 @@
 mod verb_test;
 
-use jp_inflections::{self, VerbType};
+use jp_inflections::{self, PoliteLevel, VerbType};
 use verb_test::{AssertedResult, VerbTest};
 
 #[test]
@@ -9,9 +9,15 @@ fn ichidan() {
         "たべる",
         Some("食べる"),
         VerbType::Ichidan,
-        vec![AssertedResult::new("たべられる", Some("食べられる"))],
+        vec![
+            AssertedResult::new("たべられる", Some("食べられる")),
+            AssertedResult::new("たべられます", Some("食べられます")),
+        ],
     )
-    .run([|v| v.passive()]);
+    .run([
+        |v| v.passive(PoliteLevel::Plain),
+        |v| v.passive(PoliteLevel::Polite),
+    ]);
 }
 
 #[test]
@@ -20,9 +26,15 @@ fn godan() {
         "まもる",
         Some("守る"),
         VerbType::Godan,
-        vec![AssertedResult::new("まもられる", Some("守られる"))],
+        vec![
+            AssertedResult::new("まもられる", Some("守られる")),
+            AssertedResult::new("まもられます", Some("守られます")),
+        ],
     )
-    .run([|v| v.passive()]);
+    .run([
+        |v| v.passive(PoliteLevel::Plain),
+        |v| v.passive(PoliteLevel::Polite),
+    ]);
 }
 
 #[test]
@@ -31,23 +43,83 @@ fn exceptions() {
         "くる",
         Some("来る"),
         VerbType::Exception,
-        vec![AssertedResult::new("こられる", Some("来られる"))],
+        vec![
+            AssertedResult::new("こられる", Some("来られる")),
+            AssertedResult::new("こられます", Some("来られます")),
+        ],
     )
-    .run([|v| v.passive()]);
+    .run([
+        |v| v.passive(PoliteLevel::Plain),
+        |v| v.passive(PoliteLevel::Polite),
+    ]);
 
     VerbTest::new(
         "する",
         None,
         VerbType::Exception,
-        vec![AssertedResult::new("される", None)],
+        vec![
+            AssertedResult::new("される", None),
+            AssertedResult::new("されます", None),
+        ],
     )
-    .run([|v| v.passive()]);
+    .run([
+        |v| v.passive(PoliteLevel::Plain),
+        |v| v.passive(PoliteLevel::Polite),
+    ]);
 
     VerbTest::new(
         "みみにする",
         Some("耳にする"),
         VerbType::Exception,
-        vec![AssertedResult::new("みみにされる", Some("耳にされる"))],
+        vec![
+            AssertedResult::new("みみにされる", Some("耳にされる")),
+            AssertedResult::new("みみにされます", Some("耳にされます")),
+        ],
     )
-    .run([|v| v.passive()]);
+    .run([
+        |v| v.passive(PoliteLevel::Plain),
+        |v| v.passive(PoliteLevel::Polite),
+    ]);
+}
+
+#[test]
+fn suru_kanji() {
+    VerbTest::new(
+        "する",
+        Some("為る"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("される", Some("為れる")),
+            AssertedResult::new("されます", Some("為れます")),
+        ],
+    )
+    .run([
+        |v| v.passive(PoliteLevel::Plain),
+        |v| v.passive(PoliteLevel::Polite),
+    ]);
+
+    VerbTest::new(
+        "べんきょうする",
+        Some("勉強する"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("べんきょうされる", Some("勉強される")),
+            AssertedResult::new("べんきょうされます", Some("勉強されます")),
+        ],
+    )
+    .run([
+        |v| v.passive(PoliteLevel::Plain),
+        |v| v.passive(PoliteLevel::Polite),
+    ]);
+
+    VerbTest::new(
+        "あいする",
+        Some("愛する"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("あいされる", Some("愛される")),
+            AssertedResult::new("あいされない", Some("愛されない")),
+        ],
+    )
+    .run([|v| v.passive(PoliteLevel::Plain), |v| v.negative_passive()]);
 }
@@ -1,6 +1,6 @@
 mod verb_test;
 
-use jp_inflections::{self, VerbType, WordForm};
+use jp_inflections::{self, PoliteLevel, VerbType};
 use verb_test::{AssertedResult, VerbTest};
 
 #[test]
@@ -14,7 +14,10 @@ fn ichidan() {
             AssertedResult::new("たべました", Some("食べました")),
         ],
     )
-    .run([|v| v.past(WordForm::Short), |v| v.past(WordForm::Long)]);
+    .run([
+        |v| v.past(PoliteLevel::Plain),
+        |v| v.past(PoliteLevel::Polite),
+    ]);
 }
 
 #[test]
@@ -28,7 +31,27 @@ fn godan() {
             AssertedResult::new("まもりました", Some("守りました")),
         ],
     )
-    .run([|v| v.past(WordForm::Short), |v| v.past(WordForm::Long)]);
+    .run([
+        |v| v.past(PoliteLevel::Plain),
+        |v| v.past(PoliteLevel::Polite),
+    ]);
+}
+
+#[test]
+fn godan_nu() {
+    VerbTest::new(
+        "しぬ",
+        Some("死ぬ"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("しんだ", Some("死んだ")),
+            AssertedResult::new("しにました", Some("死にました")),
+        ],
+    )
+    .run([
+        |v| v.past(PoliteLevel::Plain),
+        |v| v.past(PoliteLevel::Polite),
+    ]);
 }
 
 #[test]
@@ -42,7 +65,24 @@ fn exceptions() {
             AssertedResult::new("いきました", Some("行きました")),
         ],
     )
-    .run([|v| v.past(WordForm::Short), |v| v.past(WordForm::Long)]);
+    .run([
+        |v| v.past(PoliteLevel::Plain),
+        |v| v.past(PoliteLevel::Polite),
+    ]);
+
+    VerbTest::new(
+        "もっていく",
+        Some("持って行く"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("もっていった", Some("持って行った")),
+            AssertedResult::new("もっていきました", Some("持って行きました")),
+        ],
+    )
+    .run([
+        |v| v.past(PoliteLevel::Plain),
+        |v| v.past(PoliteLevel::Polite),
+    ]);
 
     VerbTest::new(
         "くる",
@@ -53,7 +93,10 @@ fn exceptions() {
             AssertedResult::new("きました", Some("来ました")),
         ],
     )
-    .run([|v| v.past(WordForm::Short), |v| v.past(WordForm::Long)]);
+    .run([
+        |v| v.past(PoliteLevel::Plain),
+        |v| v.past(PoliteLevel::Polite),
+    ]);
 
     VerbTest::new(
         "する",
@@ -64,7 +107,10 @@ fn exceptions() {
             AssertedResult::new("しました", None),
         ],
     )
-    .run([|v| v.past(WordForm::Short), |v| v.past(WordForm::Long)]);
+    .run([
+        |v| v.past(PoliteLevel::Plain),
+        |v| v.past(PoliteLevel::Polite),
+    ]);
 
     VerbTest::new(
         "みみにする",
@@ -75,7 +121,10 @@ fn exceptions() {
             AssertedResult::new("みみにしました", Some("耳にしました")),
         ],
     )
-    .run([|v| v.past(WordForm::Short), |v| v.past(WordForm::Long)]);
+    .run([
+        |v| v.past(PoliteLevel::Plain),
+        |v| v.past(PoliteLevel::Polite),
+    ]);
 
     VerbTest::new(
         "いらっしゃる",
@@ -86,5 +135,39 @@ fn exceptions() {
             AssertedResult::new("いらっしゃいました", None),
         ],
     )
-    .run([|v| v.past(WordForm::Short), |v| v.past(WordForm::Long)]);
+    .run([
+        |v| v.past(PoliteLevel::Plain),
+        |v| v.past(PoliteLevel::Polite),
+    ]);
+}
+
+#[test]
+fn godan_tou() {
+    VerbTest::new(
+        "とう",
+        Some("問う"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("とうた", Some("問うた")),
+            AssertedResult::new("といました", Some("問いました")),
+        ],
+    )
+    .run([
+        |v| v.past(PoliteLevel::Plain),
+        |v| v.past(PoliteLevel::Polite),
+    ]);
+
+    VerbTest::new(
+        "こう",
+        Some("乞う"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("こうた", Some("乞うた")),
+            AssertedResult::new("こいました", Some("乞いました")),
+        ],
+    )
+    .run([
+        |v| v.past(PoliteLevel::Plain),
+        |v| v.past(PoliteLevel::Polite),
+    ]);
 }
@@ -1,6 +1,6 @@
 mod verb_test;
 
-use jp_inflections::{self, VerbType, WordForm};
+use jp_inflections::{self, PoliteLevel, VerbType};
 use verb_test::{AssertedResult, VerbTest};
 
 #[test]
@@ -15,8 +15,8 @@ fn ichidan() {
         ],
     )
     .run([
-        |v| v.negative_past(WordForm::Short),
-        |v| v.negative_past(WordForm::Long),
+        |v| v.negative_past(PoliteLevel::Plain),
+        |v| v.negative_past(PoliteLevel::Polite),
     ]);
 }
 
@@ -32,8 +32,8 @@ fn godan() {
         ],
     )
     .run([
-        |v| v.negative_past(WordForm::Short),
-        |v| v.negative_past(WordForm::Long),
+        |v| v.negative_past(PoliteLevel::Plain),
+        |v| v.negative_past(PoliteLevel::Polite),
     ]);
 }
 
@@ -49,8 +49,8 @@ fn exceptions() {
         ],
     )
     .run([
-        |v| v.negative_past(WordForm::Short),
-        |v| v.negative_past(WordForm::Long),
+        |v| v.negative_past(PoliteLevel::Plain),
+        |v| v.negative_past(PoliteLevel::Polite),
     ]);
 
     VerbTest::new(
@@ -63,8 +63,8 @@ fn exceptions() {
         ],
     )
     .run([
-        |v| v.negative_past(WordForm::Short),
-        |v| v.negative_past(WordForm::Long),
+        |v| v.negative_past(PoliteLevel::Plain),
+        |v| v.negative_past(PoliteLevel::Polite),
     ]);
 
     VerbTest::new(
@@ -77,8 +77,8 @@ fn exceptions() {
         ],
     )
     .run([
-        |v| v.negative_past(WordForm::Short),
-        |v| v.negative_past(WordForm::Long),
+        |v| v.negative_past(PoliteLevel::Plain),
+        |v| v.negative_past(PoliteLevel::Polite),
     ]);
 
     VerbTest::new(
@@ -91,7 +91,69 @@ fn exceptions() {
         ],
     )
     .run([
-        |v| v.negative_past(WordForm::Short),
-        |v| v.negative_past(WordForm::Long),
+        |v| v.negative_past(PoliteLevel::Plain),
+        |v| v.negative_past(PoliteLevel::Polite),
+    ]);
+}
+
+#[test]
+fn aru() {
+    VerbTest::new(
+        "ある",
+        Some("有る"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("なかった", Some("なかった")),
+            AssertedResult::new("ありませんでした", Some("有りませんでした")),
+        ],
+    )
+    .run([
+        |v| v.negative_past(PoliteLevel::Plain),
+        |v| v.negative_past(PoliteLevel::Polite),
+    ]);
+
+    VerbTest::new(
+        "かいてある",
+        Some("書いてある"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("かいてなかった", Some("書いてなかった")),
+            AssertedResult::new("かいてありませんでした", Some("書いてありませんでした")),
+        ],
+    )
+    .run([
+        |v| v.negative_past(PoliteLevel::Plain),
+        |v| v.negative_past(PoliteLevel::Polite),
+    ]);
+}
+
+#[test]
+fn iku() {
+    VerbTest::new(
+        "いく",
+        Some("行く"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("いかなかった", Some("行かなかった")),
+            AssertedResult::new("いきませんでした", Some("行きませんでした")),
+        ],
+    )
+    .run([
+        |v| v.negative_past(PoliteLevel::Plain),
+        |v| v.negative_past(PoliteLevel::Polite),
+    ]);
+
+    VerbTest::new(
+        "もっていく",
+        Some("持って行く"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("もっていかなかった", Some("持って行かなかった")),
+            AssertedResult::new("もっていきませんでした", Some("持って行きませんでした")),
+        ],
+    )
+    .run([
+        |v| v.negative_past(PoliteLevel::Plain),
+        |v| v.negative_past(PoliteLevel::Polite),
     ]);
 }
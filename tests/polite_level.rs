@@ -0,0 +1,25 @@
+#![allow(deprecated)]
+
+use jp_inflections::{PoliteLevel, VerbType, Word, WordForm};
+
+#[test]
+fn word_form_alias() {
+    assert_eq!(WordForm::Short, PoliteLevel::Plain);
+    assert_eq!(WordForm::Long, PoliteLevel::Polite);
+
+    let verb = Word::new("たべる", Some("食べる"))
+        .into_verb(VerbType::Ichidan)
+        .unwrap();
+    let form: WordForm = WordForm::Long;
+    assert_eq!(
+        verb.negative(form).unwrap(),
+        verb.negative(PoliteLevel::Polite).unwrap()
+    );
+
+    let label = |form: WordForm| match form {
+        WordForm::Short => "plain",
+        WordForm::Long => "polite",
+    };
+    assert_eq!(label(PoliteLevel::Plain), "plain");
+    assert_eq!(label(PoliteLevel::Polite), "polite");
+}
@@ -0,0 +1,150 @@
+mod verb_test;
+
+use jp_inflections::{self, PoliteLevel, VerbType};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn negative() {
+    VerbTest::new(
+        "なさる",
+        Some("為さる"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("なさらない", Some("為さらない")),
+            AssertedResult::new("なさいません", Some("為さいません")),
+        ],
+    )
+    .run([
+        |v| v.negative(PoliteLevel::Plain),
+        |v| v.negative(PoliteLevel::Polite),
+    ]);
+
+    VerbTest::new(
+        "くださる",
+        Some("下さる"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("くださらない", Some("下さらない")),
+            AssertedResult::new("くださいません", Some("下さいません")),
+        ],
+    )
+    .run([
+        |v| v.negative(PoliteLevel::Plain),
+        |v| v.negative(PoliteLevel::Polite),
+    ]);
+
+    VerbTest::new(
+        "おっしゃる",
+        Some("仰る"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("おっしゃらない", Some("仰らない")),
+            AssertedResult::new("おっしゃいません", Some("仰いません")),
+        ],
+    )
+    .run([
+        |v| v.negative(PoliteLevel::Plain),
+        |v| v.negative(PoliteLevel::Polite),
+    ]);
+
+    VerbTest::new(
+        "いらっしゃる",
+        Some("居らっしゃる"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("いらっしゃらない", Some("居らっしゃらない")),
+            AssertedResult::new("いらっしゃいません", Some("居らっしゃいません")),
+        ],
+    )
+    .run([
+        |v| v.negative(PoliteLevel::Plain),
+        |v| v.negative(PoliteLevel::Polite),
+    ]);
+
+    VerbTest::new(
+        "ござる",
+        Some("御座る"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("ござらない", Some("御座らない")),
+            AssertedResult::new("ございません", Some("御座いません")),
+        ],
+    )
+    .run([
+        |v| v.negative(PoliteLevel::Plain),
+        |v| v.negative(PoliteLevel::Polite),
+    ]);
+}
+
+#[test]
+fn negative_past() {
+    VerbTest::new(
+        "なさる",
+        Some("為さる"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("なさらなかった", Some("為さらなかった")),
+            AssertedResult::new("なさいませんでした", Some("為さいませんでした")),
+        ],
+    )
+    .run([
+        |v| v.negative_past(PoliteLevel::Plain),
+        |v| v.negative_past(PoliteLevel::Polite),
+    ]);
+
+    VerbTest::new(
+        "くださる",
+        Some("下さる"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("くださらなかった", Some("下さらなかった")),
+            AssertedResult::new("くださいませんでした", Some("下さいませんでした")),
+        ],
+    )
+    .run([
+        |v| v.negative_past(PoliteLevel::Plain),
+        |v| v.negative_past(PoliteLevel::Polite),
+    ]);
+
+    VerbTest::new(
+        "おっしゃる",
+        Some("仰る"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("おっしゃらなかった", Some("仰らなかった")),
+            AssertedResult::new("おっしゃいませんでした", Some("仰いませんでした")),
+        ],
+    )
+    .run([
+        |v| v.negative_past(PoliteLevel::Plain),
+        |v| v.negative_past(PoliteLevel::Polite),
+    ]);
+
+    VerbTest::new(
+        "いらっしゃる",
+        Some("居らっしゃる"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("いらっしゃらなかった", Some("居らっしゃらなかった")),
+            AssertedResult::new("いらっしゃいませんでした", Some("居らっしゃいませんでした")),
+        ],
+    )
+    .run([
+        |v| v.negative_past(PoliteLevel::Plain),
+        |v| v.negative_past(PoliteLevel::Polite),
+    ]);
+
+    VerbTest::new(
+        "ござる",
+        Some("御座る"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("ござらなかった", Some("御座らなかった")),
+            AssertedResult::new("ございませんでした", Some("御座いませんでした")),
+        ],
+    )
+    .run([
+        |v| v.negative_past(PoliteLevel::Plain),
+        |v| v.negative_past(PoliteLevel::Polite),
+    ]);
+}
@@ -0,0 +1,113 @@
+mod verb_test;
+
+use jp_inflections::{self, Inflection, PoliteLevel, VerbType, Word};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn ichidan() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![
+            AssertedResult::new("たべません", Some("食べません")),
+            AssertedResult::new("たべませんでした", Some("食べませんでした")),
+            AssertedResult::new("たべませんか", Some("食べませんか")),
+        ],
+    )
+    .run([
+        |v| v.negative(PoliteLevel::Polite),
+        |v| v.polite_past_negative(),
+        |v| v.polite_negative_question(),
+    ]);
+}
+
+#[test]
+fn godan() {
+    VerbTest::new(
+        "いく",
+        Some("行く"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("いきません", Some("行きません")),
+            AssertedResult::new("いきませんでした", Some("行きませんでした")),
+            AssertedResult::new("いきませんか", Some("行きませんか")),
+        ],
+    )
+    .run([
+        |v| v.negative(PoliteLevel::Polite),
+        |v| v.polite_past_negative(),
+        |v| v.polite_negative_question(),
+    ]);
+}
+
+#[test]
+fn exceptions() {
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("しません", None),
+            AssertedResult::new("しませんでした", None),
+            AssertedResult::new("しませんか", None),
+        ],
+    )
+    .run([
+        |v| v.negative(PoliteLevel::Polite),
+        |v| v.polite_past_negative(),
+        |v| v.polite_negative_question(),
+    ]);
+
+    VerbTest::new(
+        "べんきょうする",
+        Some("勉強する"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("べんきょうしません", Some("勉強しません")),
+            AssertedResult::new("べんきょうしませんでした", Some("勉強しませんでした")),
+            AssertedResult::new("べんきょうしませんか", Some("勉強しませんか")),
+        ],
+    )
+    .run([
+        |v| v.negative(PoliteLevel::Polite),
+        |v| v.polite_past_negative(),
+        |v| v.polite_negative_question(),
+    ]);
+
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("きません", Some("来ません")),
+            AssertedResult::new("きませんでした", Some("来ませんでした")),
+            AssertedResult::new("きませんか", Some("来ませんか")),
+        ],
+    )
+    .run([
+        |v| v.negative(PoliteLevel::Polite),
+        |v| v.polite_past_negative(),
+        |v| v.polite_negative_question(),
+    ]);
+}
+
+#[test]
+fn inflections() {
+    let verb = Word::new("たべる", Some("食べる"))
+        .into_verb(VerbType::Ichidan)
+        .unwrap();
+
+    assert_eq!(
+        verb.polite_past_negative().unwrap(),
+        verb.negative_past(PoliteLevel::Polite).unwrap()
+    );
+    assert_eq!(
+        verb.polite_negative_question().unwrap().inflections,
+        vec![
+            Inflection::Polite,
+            Inflection::Negative,
+            Inflection::Invitation
+        ]
+    );
+}
@@ -1,6 +1,6 @@
 mod verb_test;
 
-use jp_inflections::{self, VerbType, WordForm};
+use jp_inflections::{self, PoliteLevel, VerbType};
 use verb_test::{AssertedResult, VerbTest};
 
 #[test]
@@ -15,8 +15,8 @@ fn ichidan() {
         ],
     )
     .run([
-        |v| v.potential(WordForm::Short),
-        |v| v.potential(WordForm::Long),
+        |v| v.potential(PoliteLevel::Plain),
+        |v| v.potential(PoliteLevel::Polite),
     ]);
 }
 
@@ -32,8 +32,8 @@ fn godan() {
         ],
     )
     .run([
-        |v| v.potential(WordForm::Short),
-        |v| v.potential(WordForm::Long),
+        |v| v.potential(PoliteLevel::Plain),
+        |v| v.potential(PoliteLevel::Polite),
     ]);
 }
 
@@ -49,8 +49,8 @@ fn exceptions() {
         ],
     )
     .run([
-        |v| v.potential(WordForm::Short),
-        |v| v.potential(WordForm::Long),
+        |v| v.potential(PoliteLevel::Plain),
+        |v| v.potential(PoliteLevel::Polite),
     ]);
 
     VerbTest::new(
@@ -63,8 +63,8 @@ fn exceptions() {
         ],
     )
     .run([
-        |v| v.potential(WordForm::Short),
-        |v| v.potential(WordForm::Long),
+        |v| v.potential(PoliteLevel::Plain),
+        |v| v.potential(PoliteLevel::Polite),
     ]);
 
     VerbTest::new(
@@ -77,7 +77,45 @@ fn exceptions() {
         ],
     )
     .run([
-        |v| v.potential(WordForm::Short),
-        |v| v.potential(WordForm::Long),
+        |v| v.potential(PoliteLevel::Plain),
+        |v| v.potential(PoliteLevel::Polite),
     ]);
 }
+
+#[test]
+fn literary_uru() {
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("しうる", None),
+            AssertedResult::new("できる", None),
+        ],
+    )
+    .run([|v| v.potential_uru(), |v| v.potential(PoliteLevel::Plain)]);
+
+    VerbTest::new(
+        "りかいする",
+        Some("理解する"),
+        VerbType::Exception,
+        vec![AssertedResult::new("りかいしうる", Some("理解しうる"))],
+    )
+    .run([|v| v.potential_uru()]);
+
+    VerbTest::new(
+        "おこる",
+        Some("起こる"),
+        VerbType::Godan,
+        vec![AssertedResult::new("おこりうる", Some("起こりうる"))],
+    )
+    .run([|v| v.potential_uru()]);
+
+    VerbTest::new(
+        "かんがえる",
+        Some("考える"),
+        VerbType::Ichidan,
+        vec![AssertedResult::new("かんがえうる", Some("考えうる"))],
+    )
+    .run([|v| v.potential_uru()]);
+}
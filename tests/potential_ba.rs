@@ -0,0 +1,53 @@
+mod verb_test;
+
+use jp_inflections::{self, Inflection, VerbType, Word};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn regular() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![AssertedResult::new("たべられれば", Some("食べられれば"))],
+    )
+    .run([|v| v.potential_ba()]);
+
+    VerbTest::new(
+        "ならう",
+        Some("習う"),
+        VerbType::Godan,
+        vec![AssertedResult::new("ならえれば", Some("習えれば"))],
+    )
+    .run([|v| v.potential_ba()]);
+}
+
+#[test]
+fn exceptions() {
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![AssertedResult::new("できれば", None)],
+    )
+    .run([|v| v.potential_ba()]);
+
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![AssertedResult::new("こられれば", Some("来られれば"))],
+    )
+    .run([|v| v.potential_ba()]);
+}
+
+#[test]
+fn inflections() {
+    let verb = Word::new("よむ", Some("読む"))
+        .into_verb(VerbType::Godan)
+        .unwrap();
+    assert_eq!(
+        verb.potential_ba().unwrap().inflections,
+        vec![Inflection::Potential, Inflection::Ba]
+    );
+}
@@ -0,0 +1,50 @@
+mod verb_test;
+
+use jp_inflections::{self, PoliteLevel, Verb, VerbType, Word};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn potential_koto() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![
+            AssertedResult::new("たべることができる", Some("食べることができる")),
+            AssertedResult::new("たべることができます", Some("食べることができます")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.potential_koto(PoliteLevel::Plain),
+        |v: &Verb| v.potential_koto(PoliteLevel::Polite),
+    ]);
+
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("することができる", None),
+            AssertedResult::new("することができます", None),
+        ],
+    )
+    .run([
+        |v: &Verb| v.potential_koto(PoliteLevel::Plain),
+        |v: &Verb| v.potential_koto(PoliteLevel::Polite),
+    ]);
+}
+
+#[test]
+fn negative() {
+    let verb = Word::new("よむ", Some("読む"))
+        .into_verb(VerbType::Godan)
+        .unwrap();
+    let potential = verb.potential_koto(PoliteLevel::Plain).unwrap();
+    let negative = potential
+        .reconjugate()
+        .unwrap()
+        .negative(PoliteLevel::Plain)
+        .unwrap();
+    assert_eq!(negative.kana, "よむことができない");
+    assert_eq!(negative.kanji.as_deref(), Some("読むことができない"));
+}
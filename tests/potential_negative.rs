@@ -1,6 +1,6 @@
 mod verb_test;
 
-use jp_inflections::{self, VerbType, WordForm};
+use jp_inflections::{self, PoliteLevel, VerbType};
 use verb_test::{AssertedResult, VerbTest};
 
 #[test]
@@ -15,8 +15,8 @@ fn ichidan() {
         ],
     )
     .run([
-        |v| v.negative_potential(WordForm::Short),
-        |v| v.negative_potential(WordForm::Long),
+        |v| v.negative_potential(PoliteLevel::Plain),
+        |v| v.negative_potential(PoliteLevel::Polite),
     ]);
 }
 
@@ -32,8 +32,8 @@ fn godan() {
         ],
     )
     .run([
-        |v| v.negative_potential(WordForm::Short),
-        |v| v.negative_potential(WordForm::Long),
+        |v| v.negative_potential(PoliteLevel::Plain),
+        |v| v.negative_potential(PoliteLevel::Polite),
     ]);
 }
 
@@ -49,8 +49,8 @@ fn exceptions() {
         ],
     )
     .run([
-        |v| v.negative_potential(WordForm::Short),
-        |v| v.negative_potential(WordForm::Long),
+        |v| v.negative_potential(PoliteLevel::Plain),
+        |v| v.negative_potential(PoliteLevel::Polite),
     ]);
 
     VerbTest::new(
@@ -63,8 +63,8 @@ fn exceptions() {
         ],
     )
     .run([
-        |v| v.negative_potential(WordForm::Short),
-        |v| v.negative_potential(WordForm::Long),
+        |v| v.negative_potential(PoliteLevel::Plain),
+        |v| v.negative_potential(PoliteLevel::Polite),
     ]);
 
     VerbTest::new(
@@ -77,7 +77,7 @@ fn exceptions() {
         ],
     )
     .run([
-        |v| v.negative_potential(WordForm::Short),
-        |v| v.negative_potential(WordForm::Long),
+        |v| v.negative_potential(PoliteLevel::Plain),
+        |v| v.negative_potential(PoliteLevel::Polite),
     ]);
 }
@@ -0,0 +1,57 @@
+mod verb_test;
+
+use jp_inflections::{self, PoliteLevel, Verb, VerbType};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn ichidan() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![
+            AssertedResult::new("たべるだろう", Some("食べるだろう")),
+            AssertedResult::new("たべるでしょう", Some("食べるでしょう")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.presumptive(PoliteLevel::Plain),
+        |v: &Verb| v.presumptive(PoliteLevel::Polite),
+    ]);
+}
+
+#[test]
+fn godan() {
+    VerbTest::new(
+        "ならう",
+        Some("習う"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("ならうだろう", Some("習うだろう")),
+            AssertedResult::new("ならうでしょう", Some("習うでしょう")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.presumptive(PoliteLevel::Plain),
+        |v: &Verb| v.presumptive(PoliteLevel::Polite),
+    ]);
+}
+
+#[test]
+fn exceptions() {
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![AssertedResult::new("くるでしょう", Some("来るでしょう"))],
+    )
+    .run([|v: &Verb| v.presumptive(PoliteLevel::Polite)]);
+
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![AssertedResult::new("するだろう", None)],
+    )
+    .run([|v: &Verb| v.presumptive(PoliteLevel::Plain)]);
+}
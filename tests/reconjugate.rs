@@ -0,0 +1,55 @@
+use jp_inflections::{error::Error, Inflection, PoliteLevel, VerbType, Word};
+
+#[test]
+fn derived() {
+    let verb = Word::new("ならう", Some("習う"))
+        .into_verb(VerbType::Godan)
+        .unwrap();
+
+    let causative = verb
+        .causative(PoliteLevel::Plain)
+        .unwrap()
+        .reconjugate()
+        .unwrap();
+    assert_eq!(causative.verb_type(), VerbType::Ichidan);
+
+    let negative = causative.negative(PoliteLevel::Polite).unwrap();
+    assert_eq!(negative.kana, "ならわせません");
+    assert_eq!(negative.kanji.as_deref(), Some("習わせません"));
+    assert_eq!(
+        negative.inflections,
+        vec![
+            Inflection::Causative,
+            Inflection::Polite,
+            Inflection::Negative
+        ]
+    );
+}
+
+#[test]
+fn godan() {
+    let verb = Word::new("たべる", Some("食べる"))
+        .into_verb(VerbType::Ichidan)
+        .unwrap();
+
+    let shimau = verb
+        .shimau(PoliteLevel::Plain)
+        .unwrap()
+        .reconjugate()
+        .unwrap();
+    assert_eq!(shimau.verb_type(), VerbType::Godan);
+    assert_eq!(
+        shimau.past(PoliteLevel::Plain).unwrap().kana,
+        "たべてしまった"
+    );
+}
+
+#[test]
+fn not_a_verb() {
+    let verb = Word::new("たべる", Some("食べる"))
+        .into_verb(VerbType::Ichidan)
+        .unwrap();
+
+    let past = verb.past(PoliteLevel::Plain).unwrap();
+    assert_eq!(past.reconjugate(), Err(Error::NotAVerb));
+}
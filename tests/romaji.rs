@@ -0,0 +1,130 @@
+use jp_inflections::{
+    romaji::{from_romaji, to_romaji},
+    PoliteLevel, Verb, VerbType, Word,
+};
+
+#[test]
+fn syllables() {
+    let cases = [
+        ("あいうえお", "aiueo"),
+        ("しちつふじ", "shichitsufuji"),
+        ("ぢづを", "jizuo"),
+        ("きゃきゅきょ", "kyakyukyo"),
+        ("しゃしゅしょ", "shashusho"),
+        ("ちゃちゅちょ", "chachucho"),
+        ("じゃじゅじょ", "jajujo"),
+        ("りょこう", "ryokou"),
+        ("パーティー", "paatii"),
+    ];
+
+    for (kana, romaji) in cases.iter() {
+        assert_eq!(to_romaji(kana), *romaji);
+    }
+}
+
+#[test]
+fn sokuon() {
+    let cases = [
+        ("ならった", "naratta"),
+        ("いっしょ", "issho"),
+        ("まっちゃ", "matcha"),
+        ("きって", "kitte"),
+        ("がっこう", "gakkou"),
+        ("ざっし", "zasshi"),
+        ("あっ", "a'"),
+        ("えっあ", "e'a"),
+    ];
+
+    for (kana, romaji) in cases.iter() {
+        assert_eq!(to_romaji(kana), *romaji);
+    }
+}
+
+#[test]
+fn syllabic_n() {
+    let cases = [
+        ("ほん", "hon"),
+        ("しんぶん", "shimbun"),
+        ("さんぽ", "sampo"),
+        ("うんめい", "ummei"),
+        ("きんえん", "kin'en"),
+        ("ほんや", "hon'ya"),
+        ("かんじ", "kanji"),
+    ];
+
+    for (kana, romaji) in cases.iter() {
+        assert_eq!(to_romaji(kana), *romaji);
+    }
+}
+
+#[test]
+fn word() {
+    let verb = Verb::new(Word::new("ならう", Some("習う")), VerbType::Godan);
+
+    assert_eq!(verb.past(PoliteLevel::Plain).unwrap().romaji(), "naratta");
+    assert_eq!(
+        verb.negative(PoliteLevel::Polite).unwrap().romaji(),
+        "naraimasen"
+    );
+    assert_eq!(Word::new("ヨム", None).romaji(), "yomu");
+}
+
+#[test]
+fn parse() {
+    let cases = [
+        ("narau", "ならう"),
+        ("Taberu", "たべる"),
+        ("shichitsufuji", "しちつふじ"),
+        ("kyakyukyo", "きゃきゅきょ"),
+        ("jajujo", "じゃじゅじょ"),
+        ("konnichiwa", "こんにちわ"),
+        ("hon", "ほん"),
+        ("kin'en", "きんえん"),
+        ("hon'ya", "ほんや"),
+        ("wo", "を"),
+        ("kaowoarau", "かおをあらう"),
+        ("o", "お"),
+        ("shimbun", "しんぶん"),
+    ];
+
+    for (romaji, kana) in cases.iter() {
+        assert_eq!(from_romaji(romaji).as_deref(), Some(*kana));
+    }
+}
+
+#[test]
+fn parse_sokuon() {
+    let cases = [
+        ("naratta", "ならった"),
+        ("issho", "いっしょ"),
+        ("matcha", "まっちゃ"),
+        ("gakkou", "がっこう"),
+        ("kitte", "きって"),
+    ];
+
+    for (romaji, kana) in cases.iter() {
+        assert_eq!(from_romaji(romaji).as_deref(), Some(*kana));
+    }
+}
+
+#[test]
+fn parse_invalid() {
+    assert_eq!(from_romaji("xyz"), None);
+    assert_eq!(from_romaji("tabe ru"), None);
+    assert_eq!(from_romaji("kq"), None);
+    assert_eq!(Word::from_romaji("l", None), None);
+}
+
+#[test]
+fn round_trip() {
+    for kana in ["ならった", "しんぶん", "きんえん", "まっちゃ", "りょこう"].iter()
+    {
+        assert_eq!(from_romaji(&to_romaji(kana)).as_deref(), Some(*kana));
+    }
+
+    let verb = Word::from_romaji("narau", Some("習う"))
+        .unwrap()
+        .into_verb(VerbType::Godan)
+        .unwrap();
+    assert_eq!(verb.past(PoliteLevel::Plain).unwrap().romaji(), "naratta");
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "serde")]
+
+use jp_inflections::{Inflection, PoliteLevel, Verb, VerbType, Word};
+
+#[test]
+fn word_fields() {
+    let word = Word::new("ならう", Some("習う"));
+    let json = serde_json::to_value(&word).unwrap();
+
+    assert_eq!(json["kana"], "ならう");
+    assert_eq!(json["kanji"], "習う");
+    assert!(json["inflections"].as_array().unwrap().is_empty());
+}
+
+#[test]
+fn verb_round_trip() {
+    let verb = Word::new("たべる", Some("食べる"))
+        .into_verb(VerbType::Ichidan)
+        .unwrap()
+        .potential(PoliteLevel::Plain)
+        .unwrap()
+        .into_verb(VerbType::Ichidan)
+        .unwrap();
+
+    let json = serde_json::to_string(&verb).unwrap();
+    let deserialized: Verb = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, verb);
+
+    let past = verb.past(PoliteLevel::Plain).unwrap();
+    let json = serde_json::to_string(&past).unwrap();
+    let deserialized: Word = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, past);
+}
+
+#[test]
+fn enums_round_trip() {
+    let json = serde_json::to_string(&(Inflection::Past, PoliteLevel::Polite)).unwrap();
+    let deserialized: (Inflection, PoliteLevel) = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, (Inflection::Past, PoliteLevel::Polite));
+}
+
+#[test]
+fn polite_level_accepts_old_names() {
+    let plain: PoliteLevel = serde_json::from_str("\"Short\"").unwrap();
+    let polite: PoliteLevel = serde_json::from_str("\"Long\"").unwrap();
+    assert_eq!(plain, PoliteLevel::Plain);
+    assert_eq!(polite, PoliteLevel::Polite);
+}
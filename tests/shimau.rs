@@ -0,0 +1,99 @@
+mod verb_test;
+
+use jp_inflections::{self, PoliteLevel, Verb, VerbType};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn ichidan() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![
+            AssertedResult::new("たべてしまう", Some("食べてしまう")),
+            AssertedResult::new("たべてしまいます", Some("食べてしまいます")),
+            AssertedResult::new("たべちゃう", Some("食べちゃう")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.shimau(PoliteLevel::Plain),
+        |v: &Verb| v.shimau(PoliteLevel::Polite),
+        |v: &Verb| v.shimau_contracted(),
+    ]);
+}
+
+#[test]
+fn godan() {
+    VerbTest::new(
+        "はなす",
+        Some("話す"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("はなしてしまう", Some("話してしまう")),
+            AssertedResult::new("はなしちゃう", Some("話しちゃう")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.shimau(PoliteLevel::Plain),
+        |v: &Verb| v.shimau_contracted(),
+    ]);
+
+    VerbTest::new(
+        "およぐ",
+        Some("泳ぐ"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("およいでしまう", Some("泳いでしまう")),
+            AssertedResult::new("およいじゃう", Some("泳いじゃう")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.shimau(PoliteLevel::Plain),
+        |v: &Verb| v.shimau_contracted(),
+    ]);
+
+    VerbTest::new(
+        "よむ",
+        Some("読む"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("よんでしまう", Some("読んでしまう")),
+            AssertedResult::new("よんじゃう", Some("読んじゃう")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.shimau(PoliteLevel::Plain),
+        |v: &Verb| v.shimau_contracted(),
+    ]);
+}
+
+#[test]
+fn exceptions() {
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("してしまう", None),
+            AssertedResult::new("しちゃう", None),
+        ],
+    )
+    .run([
+        |v: &Verb| v.shimau(PoliteLevel::Plain),
+        |v: &Verb| v.shimau_contracted(),
+    ]);
+
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("きてしまいます", Some("来てしまいます")),
+            AssertedResult::new("きちゃう", Some("来ちゃう")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.shimau(PoliteLevel::Polite),
+        |v: &Verb| v.shimau_contracted(),
+    ]);
+}
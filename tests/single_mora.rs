@@ -0,0 +1,100 @@
+mod verb_test;
+
+use jp_inflections::{self, PoliteLevel, VerbType, Word};
+use verb_test::{AssertedResult, VerbTest};
+
+fn run(kana: &str, kanji: &str, results: [(&str, &str); 4]) {
+    let results = results
+        .iter()
+        .map(|(kana, kanji)| AssertedResult::new(kana, Some(kanji)))
+        .collect();
+
+    VerbTest::new(kana, Some(kanji), VerbType::Ichidan, results).run([
+        |v| v.negative(PoliteLevel::Plain),
+        |v| v.negative(PoliteLevel::Polite),
+        |v| v.past(PoliteLevel::Plain),
+        |v| v.imperative(),
+    ]);
+}
+
+#[test]
+fn neru() {
+    run(
+        "ねる",
+        "寝る",
+        [
+            ("ねない", "寝ない"),
+            ("ねません", "寝ません"),
+            ("ねた", "寝た"),
+            ("ねろ", "寝ろ"),
+        ],
+    );
+}
+
+#[test]
+fn kiru() {
+    run(
+        "きる",
+        "着る",
+        [
+            ("きない", "着ない"),
+            ("きません", "着ません"),
+            ("きた", "着た"),
+            ("きろ", "着ろ"),
+        ],
+    );
+}
+
+#[test]
+fn miru() {
+    run(
+        "みる",
+        "見る",
+        [
+            ("みない", "見ない"),
+            ("みません", "見ません"),
+            ("みた", "見た"),
+            ("みろ", "見ろ"),
+        ],
+    );
+}
+
+#[test]
+fn eru() {
+    run(
+        "える",
+        "得る",
+        [
+            ("えない", "得ない"),
+            ("えません", "得ません"),
+            ("えた", "得た"),
+            ("えろ", "得ろ"),
+        ],
+    );
+}
+
+#[test]
+fn uru() {
+    run(
+        "うる",
+        "得る",
+        [
+            ("えない", "得ない"),
+            ("えません", "得ません"),
+            ("えた", "得た"),
+            ("えろ", "得ろ"),
+        ],
+    );
+
+    let verb = Word::new("うる", Some("得る")).into_verb_auto().unwrap();
+    assert_eq!(verb.verb_type(), VerbType::Ichidan);
+    assert_eq!(
+        verb.dictionary(PoliteLevel::Plain).unwrap(),
+        Word::new("うる", Some("得る"))
+    );
+    assert_eq!(verb.te_form().unwrap().kana, "えて");
+
+    // 売る shares the reading but is a godan verb
+    let verb = Word::new("うる", Some("売る")).into_verb_auto().unwrap();
+    assert_eq!(verb.negative(PoliteLevel::Plain).unwrap().kana, "うらない");
+}
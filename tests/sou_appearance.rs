@@ -0,0 +1,77 @@
+mod verb_test;
+
+use jp_inflections::{self, Verb, VerbType};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn ichidan() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![
+            AssertedResult::new("たべそう", Some("食べそう")),
+            AssertedResult::new("たべなさそう", Some("食べなさそう")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.sou_appearance(),
+        |v: &Verb| v.sou_appearance_negative(),
+    ]);
+}
+
+#[test]
+fn godan() {
+    VerbTest::new(
+        "ふる",
+        Some("降る"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("ふりそう", Some("降りそう")),
+            AssertedResult::new("ふらなさそう", Some("降らなさそう")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.sou_appearance(),
+        |v: &Verb| v.sou_appearance_negative(),
+    ]);
+}
+
+#[test]
+fn exceptions() {
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("きそう", Some("来そう")),
+            AssertedResult::new("こなさそう", Some("来なさそう")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.sou_appearance(),
+        |v: &Verb| v.sou_appearance_negative(),
+    ]);
+
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("しそう", None),
+            AssertedResult::new("しなさそう", None),
+        ],
+    )
+    .run([
+        |v: &Verb| v.sou_appearance(),
+        |v: &Verb| v.sou_appearance_negative(),
+    ]);
+
+    VerbTest::new(
+        "ある",
+        None,
+        VerbType::Godan,
+        vec![AssertedResult::new("なさそう", None)],
+    )
+    .run([|v: &Verb| v.sou_appearance_negative()]);
+}
@@ -0,0 +1,45 @@
+mod verb_test;
+
+use jp_inflections::{self, VerbType};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn ichidan() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![AssertedResult::new("たべるそう", Some("食べるそう"))],
+    )
+    .run([|v| v.sou_hearsay()]);
+}
+
+#[test]
+fn godan() {
+    VerbTest::new(
+        "ならう",
+        Some("習う"),
+        VerbType::Godan,
+        vec![AssertedResult::new("ならうそう", Some("習うそう"))],
+    )
+    .run([|v| v.sou_hearsay()]);
+}
+
+#[test]
+fn exceptions() {
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![AssertedResult::new("くるそう", Some("来るそう"))],
+    )
+    .run([|v| v.sou_hearsay()]);
+
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![AssertedResult::new("するそう", None)],
+    )
+    .run([|v| v.sou_hearsay()]);
+}
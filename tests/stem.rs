@@ -1,6 +1,6 @@
 mod verb_test;
 
-use jp_inflections::{self, VerbType, WordForm};
+use jp_inflections::{self, PoliteLevel, VerbType};
 use verb_test::{AssertedResult, VerbTest};
 
 #[test]
@@ -12,7 +12,7 @@ fn exceptions() {
         VerbType::Exception,
         vec![AssertedResult::new("いき", Some("行き"))],
     )
-    .run([|v| v.get_stem(WordForm::Long)]);
+    .run([|v| v.get_stem(PoliteLevel::Polite)]);
 
     // する
     VerbTest::new(
@@ -21,7 +21,7 @@ fn exceptions() {
         VerbType::Exception,
         vec![AssertedResult::new("し", None)],
     )
-    .run([|v| v.get_stem(WordForm::Long)]);
+    .run([|v| v.get_stem(PoliteLevel::Polite)]);
 
     VerbTest::new(
         "みみにする",
@@ -29,7 +29,7 @@ fn exceptions() {
         VerbType::Exception,
         vec![AssertedResult::new("みみにし", Some("耳にし"))],
     )
-    .run([|v| v.get_stem(WordForm::Long)]);
+    .run([|v| v.get_stem(PoliteLevel::Polite)]);
 
     // 来る
     VerbTest::new(
@@ -38,7 +38,7 @@ fn exceptions() {
         VerbType::Exception,
         vec![AssertedResult::new("き", Some("来"))],
     )
-    .run([|v| v.get_stem(WordForm::Long)]);
+    .run([|v| v.get_stem(PoliteLevel::Polite)]);
 
     VerbTest::new(
         "あそびにくる",
@@ -46,5 +46,68 @@ fn exceptions() {
         VerbType::Exception,
         vec![AssertedResult::new("あそびにき", Some("遊びに来"))],
     )
-    .run([|v| v.get_stem(WordForm::Long)]);
+    .run([|v| v.get_stem(PoliteLevel::Polite)]);
+}
+
+#[test]
+fn public_stems() {
+    VerbTest::new(
+        "かく",
+        Some("書く"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("かか", Some("書か")),
+            AssertedResult::new("かき", Some("書き")),
+            AssertedResult::new("かけ", Some("書け")),
+            AssertedResult::new("かけ", Some("書け")),
+            AssertedResult::new("かこ", Some("書こ")),
+        ],
+    )
+    .run([
+        |v| v.negative_stem(),
+        |v| v.masu_stem(),
+        |v| v.potential_stem(),
+        |v| v.ba_stem(),
+        |v| v.volitional_stem(),
+    ]);
+
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("し", None),
+            AssertedResult::new("し", None),
+            AssertedResult::new("でき", None),
+            AssertedResult::new("すれ", None),
+            AssertedResult::new("しよ", None),
+        ],
+    )
+    .run([
+        |v| v.negative_stem(),
+        |v| v.masu_stem(),
+        |v| v.potential_stem(),
+        |v| v.ba_stem(),
+        |v| v.volitional_stem(),
+    ]);
+
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("こ", Some("来")),
+            AssertedResult::new("き", Some("来")),
+            AssertedResult::new("こられ", Some("来られ")),
+            AssertedResult::new("くれ", Some("来れ")),
+            AssertedResult::new("こよ", Some("来よ")),
+        ],
+    )
+    .run([
+        |v| v.negative_stem(),
+        |v| v.masu_stem(),
+        |v| v.potential_stem(),
+        |v| v.ba_stem(),
+        |v| v.volitional_stem(),
+    ]);
 }
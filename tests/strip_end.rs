@@ -0,0 +1,67 @@
+use jp_inflections::{Inflection, Word};
+
+#[test]
+fn strip_end() {
+    let word = Word::new("かえる", Some("帰る"));
+    assert_eq!(word.clone().strip_end(0), word);
+    assert_eq!(word.clone().strip_end(1), Word::new("かえ", Some("帰")));
+    assert_eq!(word.strip_end(2), Word::new("か", Some("")));
+}
+
+#[test]
+fn strip_more_than_present() {
+    let mut word = Word::new("る", Some("る"));
+    word.inflections.push(Inflection::Past);
+
+    let stripped = word.strip_end(5);
+    assert_eq!(stripped.kana, "");
+    assert_eq!(stripped.kanji.as_deref(), Some(""));
+    assert_eq!(stripped.inflections, vec![Inflection::Past]);
+
+    assert_eq!(Word::new("", None).strip_end(2), Word::new("", None));
+}
+
+#[test]
+fn char_len() {
+    assert_eq!(Word::new("", None).char_len(), 0);
+    assert_eq!(Word::new("ならう", Some("習う")).char_len(), 3);
+    assert_eq!(Word::new("コーヒー", None).char_len(), 4);
+}
+
+#[test]
+fn strip_mixed_lengths() {
+    let words = [
+        Word::new("ならう", Some("習う")),
+        Word::new("うけたまわる", Some("承る")),
+        Word::new("きょう", Some("今日")),
+        Word::new("べんきょうする", Some("勉強する")),
+        Word::new("ぱそこんをつかう", Some("PCを使う")),
+        Word::new("コーヒーをのむ", Some("coffeeを飲む")),
+        Word::new("する", Some("為る")),
+        Word::new("", Some("𠮷")),
+        Word::new("a", None),
+    ];
+
+    for word in words.iter() {
+        let kanji_len = word.kanji.as_ref().map(|i| i.chars().count());
+        let max = word.char_len().max(kanji_len.unwrap_or_default()) + 2;
+
+        for n in 0..=max {
+            let stripped = word.clone().strip_end(n);
+
+            assert_eq!(stripped.char_len(), word.char_len().saturating_sub(n));
+            assert!(word.kana.starts_with(&stripped.kana));
+
+            let kanji = stripped.kanji.as_deref();
+            assert_eq!(
+                kanji.map(|i| i.chars().count()),
+                kanji_len.map(|i| i.saturating_sub(n))
+            );
+            assert!(word
+                .kanji
+                .as_deref()
+                .unwrap_or_default()
+                .starts_with(kanji.unwrap_or_default()));
+        }
+    }
+}
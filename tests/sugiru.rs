@@ -0,0 +1,69 @@
+mod verb_test;
+
+use jp_inflections::{self, PoliteLevel, Verb, VerbType};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn ichidan() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![
+            AssertedResult::new("たべすぎる", Some("食べすぎる")),
+            AssertedResult::new("たべすぎます", Some("食べすぎます")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.sugiru(PoliteLevel::Plain),
+        |v: &Verb| v.sugiru(PoliteLevel::Polite),
+    ]);
+}
+
+#[test]
+fn godan() {
+    VerbTest::new(
+        "のむ",
+        Some("飲む"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("のみすぎる", Some("飲みすぎる")),
+            AssertedResult::new("のみすぎます", Some("飲みすぎます")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.sugiru(PoliteLevel::Plain),
+        |v: &Verb| v.sugiru(PoliteLevel::Polite),
+    ]);
+}
+
+#[test]
+fn exceptions() {
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("きすぎる", Some("来すぎる")),
+            AssertedResult::new("きすぎます", Some("来すぎます")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.sugiru(PoliteLevel::Plain),
+        |v: &Verb| v.sugiru(PoliteLevel::Polite),
+    ]);
+
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("しすぎる", None),
+            AssertedResult::new("しすぎます", None),
+        ],
+    )
+    .run([
+        |v: &Verb| v.sugiru(PoliteLevel::Plain),
+        |v: &Verb| v.sugiru(PoliteLevel::Polite),
+    ]);
+}
@@ -0,0 +1,136 @@
+use jp_inflections::{
+    syllable::{Info, Row, Syllable},
+    umlaut::Umlaut,
+    VerbType, Word,
+};
+
+#[test]
+fn w_row() {
+    assert_eq!(
+        Syllable::from('わ').get_info(),
+        Some(Info {
+            row: Row::W,
+            umlaut: Umlaut::A
+        })
+    );
+    assert_eq!(
+        Syllable::from('を').get_info(),
+        Some(Info {
+            row: Row::W,
+            umlaut: Umlaut::O
+        })
+    );
+}
+
+#[test]
+fn handakuten() {
+    let pairs = [
+        ('は', 'ぱ'),
+        ('ひ', 'ぴ'),
+        ('ふ', 'ぷ'),
+        ('へ', 'ぺ'),
+        ('ほ', 'ぽ'),
+    ];
+    for (src, dst) in pairs.iter().copied() {
+        assert_eq!(Syllable::from(src).to_handakuten(), Syllable::from(dst));
+        assert_eq!(Syllable::from(dst).to_seion(), Syllable::from(src));
+    }
+
+    assert_eq!(Syllable::from('ば').to_handakuten(), Syllable::from('ば'));
+    assert_eq!(Syllable::from('ホ').to_handakuten(), Syllable::from('ポ'));
+}
+
+#[test]
+fn seion() {
+    for c in "かきくけこさしすせそたちつてとはひふへほ".chars() {
+        let syllable = Syllable::from(c);
+        assert_eq!(syllable.to_dakuten().to_seion(), syllable);
+    }
+
+    assert_eq!(Syllable::from('ゲ').to_seion(), Syllable::from('ケ'));
+    assert_eq!(Syllable::from('ん').to_seion(), Syllable::from('ん'));
+}
+
+#[test]
+fn small() {
+    let pairs = [
+        ('っ', 'つ'),
+        ('ゃ', 'や'),
+        ('ゅ', 'ゆ'),
+        ('ょ', 'よ'),
+        ('ッ', 'ツ'),
+    ];
+    for (small, large) in pairs.iter().copied() {
+        let syllable = Syllable::from(small);
+        assert!(syllable.is_small());
+        assert!(syllable.get_info().is_some());
+        assert_eq!(syllable.to_large(), Syllable::from(large));
+        assert_eq!(syllable.get_info(), Syllable::from(large).get_info());
+        assert!(!Syllable::from(large).is_small());
+    }
+}
+
+#[test]
+fn scan_conjugated() {
+    let word = Word::new("いく", Some("行く"))
+        .into_verb(VerbType::Godan)
+        .unwrap()
+        .te_form()
+        .unwrap();
+
+    assert_eq!(word.ending_syllable(), Some(Syllable::from('て')));
+    assert!(word
+        .kana
+        .chars()
+        .all(|i| Syllable::from(i).get_info().is_some()));
+    assert!(!Word::new("いっ", None).is_verb());
+}
+
+#[test]
+fn word_syllables() {
+    let word = Word::new("ならいました", Some("習いました"));
+    let syllables: String = word.syllables().map(|i| i.get_char()).collect();
+    assert_eq!(syllables, "ならいました");
+
+    let word = Word::new("ちょっと ケーキ!", None);
+    let syllables: Vec<Syllable> = word.syllables().collect();
+    assert_eq!(syllables.len(), 7);
+    assert!(syllables[1].is_small());
+    assert!(syllables[2].is_small());
+    assert_eq!(syllables[5].get_char(), 'ー');
+}
+
+#[test]
+fn mora_count() {
+    let verb = Word::new("ならう", Some("習う"))
+        .into_verb(VerbType::Godan)
+        .unwrap();
+    let polite_past = verb.past(jp_inflections::PoliteLevel::Polite).unwrap();
+    assert_eq!(polite_past.kana, "ならいました");
+    assert_eq!(polite_past.mora_count(), 6);
+
+    assert_eq!(
+        Word::new("いっちゃった", Some("行っちゃった")).mora_count(),
+        5
+    );
+    assert_eq!(Word::new("しんぶん", Some("新聞")).mora_count(), 4);
+    assert_eq!(Word::new("ファイル", None).mora_count(), 3);
+    assert_eq!(Word::new("", None).mora_count(), 0);
+}
+
+#[test]
+fn matches_loose() {
+    let verb = Word::new("よむ", Some("読む"))
+        .into_verb(VerbType::Godan)
+        .unwrap();
+    let te = verb.te_form().unwrap();
+    assert!(te.matches_loose("よんで"));
+    assert!(te.matches_loose("よんて"));
+    assert!(te.matches_loose("読んて"));
+    assert!(!te.matches_loose("よんでる"));
+
+    let word = Word::new("パン", None);
+    assert!(word.matches_loose("ハン"));
+    assert!(word.matches_loose("バン"));
+    assert!(!word.matches_loose("はん"));
+}
@@ -58,6 +58,20 @@ fn godan_gu() {
     .run([|v| v.te_form()]);
 }
 
+#[test]
+fn godan_nu() {
+    VerbTest::new(
+        "しぬ",
+        Some("死ぬ"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("しんで", Some("死んで")),
+            AssertedResult::new("しななくて", Some("死ななくて")),
+        ],
+    )
+    .run([|v| v.te_form(), |v| v.negative_te_form()]);
+}
+
 #[test]
 fn exceptions() {
     // 行く
@@ -69,6 +83,22 @@ fn exceptions() {
     )
     .run([|v| v.te_form()]);
 
+    VerbTest::new(
+        "もっていく",
+        Some("持って行く"),
+        VerbType::Godan,
+        vec![AssertedResult::new("もっていって", Some("持って行って"))],
+    )
+    .run([|v| v.te_form()]);
+
+    VerbTest::new(
+        "もっていく",
+        Some("持っていく"),
+        VerbType::Godan,
+        vec![AssertedResult::new("もっていって", Some("持っていって"))],
+    )
+    .run([|v| v.te_form()]);
+
     // する
     VerbTest::new(
         "する",
@@ -112,3 +142,65 @@ fn exceptions() {
     )
     .run([|v| v.te_form()]);
 }
+
+#[test]
+fn aru() {
+    VerbTest::new(
+        "ある",
+        Some("在る"),
+        VerbType::Godan,
+        vec![AssertedResult::new("あって", Some("在って"))],
+    )
+    .run([|v| v.te_form()]);
+
+    VerbTest::new(
+        "かいてある",
+        Some("書いてある"),
+        VerbType::Godan,
+        vec![AssertedResult::new("かいてあって", Some("書いてあって"))],
+    )
+    .run([|v| v.te_form()]);
+}
+
+#[test]
+fn godan_tou() {
+    VerbTest::new(
+        "とう",
+        Some("問う"),
+        VerbType::Godan,
+        vec![AssertedResult::new("とうて", Some("問うて"))],
+    )
+    .run([|v| v.te_form()]);
+
+    VerbTest::new(
+        "こう",
+        Some("請う"),
+        VerbType::Godan,
+        vec![AssertedResult::new("こうて", Some("請うて"))],
+    )
+    .run([|v| v.te_form()]);
+
+    VerbTest::new(
+        "いう",
+        Some("言う"),
+        VerbType::Godan,
+        vec![AssertedResult::new("いって", Some("言って"))],
+    )
+    .run([|v| v.te_form()]);
+    // Kana only verbs ending in こう or とう aren't special
+    VerbTest::new(
+        "いこう",
+        None,
+        VerbType::Godan,
+        vec![AssertedResult::new("いこって", None)],
+    )
+    .run([|v| v.te_form()]);
+
+    VerbTest::new(
+        "もとう",
+        None,
+        VerbType::Godan,
+        vec![AssertedResult::new("もとって", None)],
+    )
+    .run([|v| v.te_form()]);
+}
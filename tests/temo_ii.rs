@@ -0,0 +1,68 @@
+mod verb_test;
+
+use jp_inflections::{self, Verb, VerbType};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn ichidan() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![
+            AssertedResult::new("たべてもいい", Some("食べてもいい")),
+            AssertedResult::new("たべなくてもいい", Some("食べなくてもいい")),
+        ],
+    )
+    .run([|v: &Verb| v.temo_ii(), |v: &Verb| v.nakutemo_ii()]);
+}
+
+#[test]
+fn godan() {
+    VerbTest::new(
+        "よむ",
+        Some("読む"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("よんでもいい", Some("読んでもいい")),
+            AssertedResult::new("よまなくてもいい", Some("読まなくてもいい")),
+        ],
+    )
+    .run([|v: &Verb| v.temo_ii(), |v: &Verb| v.nakutemo_ii()]);
+
+    VerbTest::new(
+        "いく",
+        Some("行く"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("いってもいい", Some("行ってもいい")),
+            AssertedResult::new("いかなくてもいい", Some("行かなくてもいい")),
+        ],
+    )
+    .run([|v: &Verb| v.temo_ii(), |v: &Verb| v.nakutemo_ii()]);
+}
+
+#[test]
+fn exception() {
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("してもいい", None),
+            AssertedResult::new("しなくてもいい", None),
+        ],
+    )
+    .run([|v: &Verb| v.temo_ii(), |v: &Verb| v.nakutemo_ii()]);
+
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("きてもいい", Some("来てもいい")),
+            AssertedResult::new("こなくてもいい", Some("来なくてもいい")),
+        ],
+    )
+    .run([|v: &Verb| v.temo_ii(), |v: &Verb| v.nakutemo_ii()]);
+}
@@ -0,0 +1,85 @@
+mod verb_test;
+
+use jp_inflections::{self, PoliteLevel, Verb, VerbType};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn ichidan() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![
+            AssertedResult::new("たべておく", Some("食べておく")),
+            AssertedResult::new("たべておきます", Some("食べておきます")),
+            AssertedResult::new("たべとく", Some("食べとく")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.teoku(PoliteLevel::Plain),
+        |v: &Verb| v.teoku(PoliteLevel::Polite),
+        |v: &Verb| v.teoku_contracted(),
+    ]);
+}
+
+#[test]
+fn godan() {
+    VerbTest::new(
+        "かく",
+        Some("書く"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("かいておく", Some("書いておく")),
+            AssertedResult::new("かいとく", Some("書いとく")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.teoku(PoliteLevel::Plain),
+        |v: &Verb| v.teoku_contracted(),
+    ]);
+
+    VerbTest::new(
+        "あそぶ",
+        Some("遊ぶ"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("あそんでおく", Some("遊んでおく")),
+            AssertedResult::new("あそんどく", Some("遊んどく")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.teoku(PoliteLevel::Plain),
+        |v: &Verb| v.teoku_contracted(),
+    ]);
+}
+
+#[test]
+fn exceptions() {
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("きておく", Some("来ておく")),
+            AssertedResult::new("きとく", Some("来とく")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.teoku(PoliteLevel::Plain),
+        |v: &Verb| v.teoku_contracted(),
+    ]);
+
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("しておきます", None),
+            AssertedResult::new("しとく", None),
+        ],
+    )
+    .run([
+        |v: &Verb| v.teoku(PoliteLevel::Polite),
+        |v: &Verb| v.teoku_contracted(),
+    ]);
+}
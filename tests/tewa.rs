@@ -0,0 +1,65 @@
+mod verb_test;
+
+use jp_inflections::{self, Verb, VerbType};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn ichidan() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![
+            AssertedResult::new("たべちゃ", Some("食べちゃ")),
+            AssertedResult::new("たべなくちゃ", Some("食べなくちゃ")),
+        ],
+    )
+    .run([|v: &Verb| v.tewa_contracted(), |v: &Verb| v.nakucha()]);
+}
+
+#[test]
+fn godan() {
+    VerbTest::new(
+        "あそぶ",
+        Some("遊ぶ"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("あそんじゃ", Some("遊んじゃ")),
+            AssertedResult::new("あそばなくちゃ", Some("遊ばなくちゃ")),
+        ],
+    )
+    .run([|v: &Verb| v.tewa_contracted(), |v: &Verb| v.nakucha()]);
+
+    VerbTest::new(
+        "かく",
+        Some("書く"),
+        VerbType::Godan,
+        vec![AssertedResult::new("かいちゃ", Some("書いちゃ"))],
+    )
+    .run([|v: &Verb| v.tewa_contracted()]);
+}
+
+#[test]
+fn exceptions() {
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("しちゃ", None),
+            AssertedResult::new("しなくちゃ", None),
+        ],
+    )
+    .run([|v: &Verb| v.tewa_contracted(), |v: &Verb| v.nakucha()]);
+
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("きちゃ", Some("来ちゃ")),
+            AssertedResult::new("こなくちゃ", Some("来なくちゃ")),
+        ],
+    )
+    .run([|v: &Verb| v.tewa_contracted(), |v: &Verb| v.nakucha()]);
+}
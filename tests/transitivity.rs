@@ -0,0 +1,55 @@
+use jp_inflections::{transitivity, PoliteLevel, Verb, VerbType, Word};
+
+fn verb(kana: &str, kanji: Option<&str>, verb_type: VerbType) -> Verb {
+    Word::new(kana, kanji).into_verb(verb_type).unwrap()
+}
+
+#[test]
+fn pair_of() {
+    let hajimeru = verb("はじめる", Some("始める"), VerbType::Ichidan);
+    let hajimaru = transitivity::pair_of(&hajimeru).unwrap();
+    assert_eq!(hajimaru.verb_type(), VerbType::Godan);
+    assert!(hajimaru
+        .word
+        .reading_eq(&Word::new("はじまる", Some("始まる"))));
+    assert!(transitivity::pair_of(&hajimaru)
+        .unwrap()
+        .word
+        .reading_eq(&hajimeru.word));
+
+    let kesu = verb("けす", None, VerbType::Godan);
+    let kieru = transitivity::pair_of(&kesu).unwrap();
+    assert_eq!(kieru.verb_type(), VerbType::Ichidan);
+    assert!(kieru.word.reading_eq(&Word::new("きえる", None)));
+    assert!(kieru
+        .past(PoliteLevel::Plain)
+        .unwrap()
+        .reading_eq(&Word::new("きえた", None)));
+}
+
+#[test]
+fn is_transitive() {
+    assert_eq!(
+        transitivity::is_transitive(&verb("あける", Some("開ける"), VerbType::Ichidan)),
+        Some(true)
+    );
+    assert_eq!(
+        transitivity::is_transitive(&verb("あく", Some("開く"), VerbType::Godan)),
+        Some(false)
+    );
+    assert_eq!(
+        transitivity::is_transitive(&verb("よむ", Some("読む"), VerbType::Godan)),
+        None
+    );
+}
+
+#[test]
+fn homophones() {
+    // 着る shares its reading with 切る but isn't part of a pair
+    let kiru = verb("きる", Some("着る"), VerbType::Ichidan);
+    assert_eq!(transitivity::is_transitive(&kiru), None);
+    assert!(transitivity::pair_of(&kiru).is_none());
+
+    let kiru = verb("きる", Some("切る"), VerbType::Godan);
+    assert_eq!(transitivity::is_transitive(&kiru), Some(true));
+}
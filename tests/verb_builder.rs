@@ -0,0 +1,43 @@
+use jp_inflections::{error::Error, Verb, VerbType, Word};
+
+#[test]
+fn build() {
+    let verb = Verb::builder()
+        .kana("ならう")
+        .kanji("習う")
+        .verb_type(VerbType::Godan)
+        .build()
+        .unwrap();
+    assert_eq!(
+        verb,
+        Word::new("ならう", Some("習う"))
+            .into_verb(VerbType::Godan)
+            .unwrap()
+    );
+}
+
+#[test]
+fn detect_type() {
+    let verb = Verb::builder().kana("する").build().unwrap();
+    assert_eq!(verb.verb_type(), VerbType::Exception);
+    assert_eq!(verb.word.kanji, None);
+
+    let verb = Verb::builder().kana("きる").kanji("着る").build().unwrap();
+    assert_eq!(verb.verb_type(), VerbType::Ichidan);
+}
+
+#[test]
+fn invalid() {
+    assert_eq!(Verb::builder().build(), Err(Error::NotAVerb));
+    assert_eq!(
+        Verb::builder()
+            .kana("えいご")
+            .verb_type(VerbType::Godan)
+            .build(),
+        Err(Error::NotAVerb)
+    );
+    assert_eq!(
+        Verb::builder().kana("きる").build(),
+        Err(Error::AmbiguousVerbType)
+    );
+}
@@ -1,5 +1,7 @@
 use jp_inflections::{self, error, Verb, VerbType, Word};
 
+type ConjugationFn = fn(&Verb) -> Result<Word, error::Error>;
+
 #[derive(Debug, Clone)]
 pub struct VerbTest<'a> {
     kana: &'a str,
@@ -35,7 +37,7 @@ impl<'a> VerbTest<'a> {
         }
     }
 
-    pub fn run<const N: usize>(self, f: [fn(&Verb) -> Result<Word, error::Error>; N]) {
+    pub fn run<const N: usize>(self, f: [ConjugationFn; N]) {
         let verb = self.get_verb();
 
         for (exp_result, fun) in self.results.into_iter().zip(f.iter()) {
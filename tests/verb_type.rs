@@ -0,0 +1,76 @@
+use jp_inflections::{error::Error, PoliteLevel, VerbType, Word};
+
+#[test]
+fn from_str() {
+    assert_eq!("godan".parse(), Ok(VerbType::Godan));
+    assert_eq!("ichidan".parse(), Ok(VerbType::Ichidan));
+    assert_eq!("exception".parse(), Ok(VerbType::Exception));
+    assert_eq!("irregular".parse(), Ok(VerbType::Exception));
+    assert_eq!("う".parse(), Ok(VerbType::Godan));
+    assert_eq!("る".parse(), Ok(VerbType::Ichidan));
+}
+
+#[test]
+fn from_str_case_insensitive() {
+    assert_eq!("GODAN".parse(), Ok(VerbType::Godan));
+    assert_eq!("Ichidan".parse(), Ok(VerbType::Ichidan));
+    assert_eq!(" ExCePtIoN ".parse(), Ok(VerbType::Exception));
+}
+
+#[test]
+fn from_str_invalid() {
+    assert_eq!("".parse::<VerbType>(), Err(Error::InvalidVerbType));
+    assert_eq!("yodan".parse::<VerbType>(), Err(Error::InvalidVerbType));
+}
+
+#[test]
+fn set_verb_type() {
+    let mut verb = Word::new("きる", None).into_verb(VerbType::Godan).unwrap();
+    verb.set_verb_type(VerbType::Ichidan);
+    assert_eq!(verb.verb_type(), VerbType::Ichidan);
+    assert_eq!(verb.negative(PoliteLevel::Plain).unwrap().kana, "きない");
+}
+
+#[test]
+fn try_set_verb_type() {
+    let mut verb = Word::new("たべる", Some("食べる"))
+        .into_verb(VerbType::Ichidan)
+        .unwrap();
+    assert!(verb.try_set_verb_type(VerbType::Godan).is_ok());
+    assert_eq!(
+        verb.try_set_verb_type(VerbType::Exception),
+        Err(Error::IncompatibleVerbType)
+    );
+    assert_eq!(verb.verb_type(), VerbType::Godan);
+
+    let mut verb = Word::new("よむ", Some("読む"))
+        .into_verb(VerbType::Godan)
+        .unwrap();
+    assert_eq!(
+        verb.try_set_verb_type(VerbType::Ichidan),
+        Err(Error::IncompatibleVerbType)
+    );
+
+    let mut verb = Word::new("する", None)
+        .into_verb(VerbType::Exception)
+        .unwrap();
+    assert_eq!(
+        verb.try_set_verb_type(VerbType::Godan),
+        Err(Error::IncompatibleVerbType)
+    );
+
+    // 擦る is a godan verb which reads like する
+    let mut verb = Word::new("する", Some("擦る"))
+        .into_verb(VerbType::Exception)
+        .unwrap();
+    assert!(verb.try_set_verb_type(VerbType::Godan).is_ok());
+
+    let mut verb = Word::new("もってくる", Some("持って来る"))
+        .into_verb(VerbType::Godan)
+        .unwrap();
+    assert!(verb.try_set_verb_type(VerbType::Exception).is_ok());
+    assert_eq!(
+        verb.try_set_verb_type(VerbType::Godan),
+        Err(Error::IncompatibleVerbType)
+    );
+}
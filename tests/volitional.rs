@@ -1,6 +1,6 @@
 mod verb_test;
 
-use jp_inflections::{self, VerbType, WordForm};
+use jp_inflections::{self, PoliteLevel, VerbType};
 use verb_test::{AssertedResult, VerbTest};
 
 #[test]
@@ -15,8 +15,8 @@ fn ichidan() {
         ],
     )
     .run([
-        |v| v.volitional(WordForm::Short),
-        |v| v.volitional(WordForm::Long),
+        |v| v.volitional(PoliteLevel::Plain),
+        |v| v.volitional(PoliteLevel::Polite),
     ]);
 }
 
@@ -32,8 +32,25 @@ fn godan() {
         ],
     )
     .run([
-        |v| v.volitional(WordForm::Short),
-        |v| v.volitional(WordForm::Long),
+        |v| v.volitional(PoliteLevel::Plain),
+        |v| v.volitional(PoliteLevel::Polite),
+    ]);
+}
+
+#[test]
+fn godan_nu() {
+    VerbTest::new(
+        "しぬ",
+        Some("死ぬ"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("しのう", Some("死のう")),
+            AssertedResult::new("しにましょう", Some("死にましょう")),
+        ],
+    )
+    .run([
+        |v| v.volitional(PoliteLevel::Plain),
+        |v| v.volitional(PoliteLevel::Polite),
     ]);
 }
 
@@ -49,8 +66,22 @@ fn exceptions() {
         ],
     )
     .run([
-        |v| v.volitional(WordForm::Short),
-        |v| v.volitional(WordForm::Long),
+        |v| v.volitional(PoliteLevel::Plain),
+        |v| v.volitional(PoliteLevel::Polite),
+    ]);
+
+    VerbTest::new(
+        "あそびにくる",
+        Some("遊びに来る"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("あそびにこよう", Some("遊びに来よう")),
+            AssertedResult::new("あそびにきましょう", Some("遊びに来ましょう")),
+        ],
+    )
+    .run([
+        |v| v.volitional(PoliteLevel::Plain),
+        |v| v.volitional(PoliteLevel::Polite),
     ]);
 
     VerbTest::new(
@@ -63,8 +94,8 @@ fn exceptions() {
         ],
     )
     .run([
-        |v| v.volitional(WordForm::Short),
-        |v| v.volitional(WordForm::Long),
+        |v| v.volitional(PoliteLevel::Plain),
+        |v| v.volitional(PoliteLevel::Polite),
     ]);
 
     VerbTest::new(
@@ -77,7 +108,7 @@ fn exceptions() {
         ],
     )
     .run([
-        |v| v.volitional(WordForm::Short),
-        |v| v.volitional(WordForm::Long),
+        |v| v.volitional(PoliteLevel::Plain),
+        |v| v.volitional(PoliteLevel::Polite),
     ]);
 }
@@ -0,0 +1,52 @@
+mod verb_test;
+
+use jp_inflections::{self, Verb, VerbType};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn godan() {
+    VerbTest::new(
+        "いく",
+        Some("行く"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("いこうとする", Some("行こうとする")),
+            AssertedResult::new("いこうとおもう", Some("行こうと思う")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.volitional_to_suru(),
+        |v: &Verb| v.volitional_to_omou(),
+    ]);
+}
+
+#[test]
+fn exception() {
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("こようとする", Some("来ようとする")),
+            AssertedResult::new("こようとおもう", Some("来ようと思う")),
+        ],
+    )
+    .run([
+        |v: &Verb| v.volitional_to_suru(),
+        |v: &Verb| v.volitional_to_omou(),
+    ]);
+
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("しようとする", None),
+            AssertedResult::new("しようとおもう", None),
+        ],
+    )
+    .run([
+        |v: &Verb| v.volitional_to_suru(),
+        |v: &Verb| v.volitional_to_omou(),
+    ]);
+}
@@ -0,0 +1,57 @@
+mod verb_test;
+
+use jp_inflections::{self, Verb, VerbType};
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn ichidan() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![
+            AssertedResult::new("たべやすい", Some("食べやすい")),
+            AssertedResult::new("たべにくい", Some("食べにくい")),
+        ],
+    )
+    .run([|v: &Verb| v.yasui(), |v: &Verb| v.nikui()]);
+}
+
+#[test]
+fn godan() {
+    VerbTest::new(
+        "よむ",
+        Some("読む"),
+        VerbType::Godan,
+        vec![
+            AssertedResult::new("よみやすい", Some("読みやすい")),
+            AssertedResult::new("よみにくい", Some("読みにくい")),
+        ],
+    )
+    .run([|v: &Verb| v.yasui(), |v: &Verb| v.nikui()]);
+}
+
+#[test]
+fn exceptions() {
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("しやすい", None),
+            AssertedResult::new("しにくい", None),
+        ],
+    )
+    .run([|v: &Verb| v.yasui(), |v: &Verb| v.nikui()]);
+
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![
+            AssertedResult::new("きやすい", Some("来やすい")),
+            AssertedResult::new("きにくい", Some("来にくい")),
+        ],
+    )
+    .run([|v: &Verb| v.yasui(), |v: &Verb| v.nikui()]);
+}
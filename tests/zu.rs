@@ -51,3 +51,22 @@ fn exceptions() {
     )
     .run([|v| v.zu()]);
 }
+
+#[test]
+fn aru() {
+    VerbTest::new(
+        "ある",
+        Some("有る"),
+        VerbType::Godan,
+        vec![AssertedResult::new("あらず", Some("有らず"))],
+    )
+    .run([|v| v.zu()]);
+
+    VerbTest::new(
+        "かいてある",
+        Some("書いてある"),
+        VerbType::Godan,
+        vec![AssertedResult::new("かいてあらず", Some("書いてあらず"))],
+    )
+    .run([|v| v.zu()]);
+}
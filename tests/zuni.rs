@@ -0,0 +1,75 @@
+mod verb_test;
+
+use jp_inflections::VerbType;
+use verb_test::{AssertedResult, VerbTest};
+
+#[test]
+fn ichidan() {
+    VerbTest::new(
+        "たべる",
+        Some("食べる"),
+        VerbType::Ichidan,
+        vec![AssertedResult::new("たべずに", Some("食べずに"))],
+    )
+    .run([|v| v.zuni()]);
+}
+
+#[test]
+fn godan() {
+    VerbTest::new(
+        "ならう",
+        Some("習う"),
+        VerbType::Godan,
+        vec![AssertedResult::new("ならわずに", Some("習わずに"))],
+    )
+    .run([|v| v.zuni()]);
+}
+
+#[test]
+fn exceptions() {
+    VerbTest::new(
+        "する",
+        None,
+        VerbType::Exception,
+        vec![AssertedResult::new("せずに", None)],
+    )
+    .run([|v| v.zuni()]);
+
+    VerbTest::new(
+        "みみにする",
+        Some("耳にする"),
+        VerbType::Exception,
+        vec![AssertedResult::new("みみにせずに", Some("耳にせずに"))],
+    )
+    .run([|v| v.zuni()]);
+
+    VerbTest::new(
+        "くる",
+        Some("来る"),
+        VerbType::Exception,
+        vec![AssertedResult::new("こずに", Some("来ずに"))],
+    )
+    .run([|v| v.zuni()]);
+}
+
+#[test]
+fn aru() {
+    VerbTest::new(
+        "ある",
+        Some("有る"),
+        VerbType::Godan,
+        vec![AssertedResult::new("あらずに", Some("有らずに"))],
+    )
+    .run([|v| v.zuni()]);
+
+    VerbTest::new(
+        "かいてある",
+        Some("書いてある"),
+        VerbType::Godan,
+        vec![AssertedResult::new(
+            "かいてあらずに",
+            Some("書いてあらずに"),
+        )],
+    )
+    .run([|v| v.zuni()]);
+}